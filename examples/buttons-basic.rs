@@ -19,3 +19,46 @@ fn main() -> cushy::Result {
         .run()
 }
 // end rustme snippet
+
+#[test]
+fn clicking_increments() {
+    use cushy::figures::Point;
+    use cushy::kludgine::app::winit::event::{ElementState, MouseButton};
+    use cushy::window::DeviceId;
+
+    let count = Dynamic::new(0_isize);
+    let button = count
+        .to_label()
+        .into_button()
+        .on_click({
+            let count = count.clone();
+            move |_| count.set(count.get() + 1)
+        })
+        .make_widget();
+    let button_id = button.id();
+    let mut recorder = button.build_recorder().finish().unwrap();
+
+    let layout = recorder
+        .window
+        .widget_layout(button_id)
+        .expect("button not laid out");
+    recorder.window.cursor_moved(
+        DeviceId::Virtual(0),
+        Point::new(
+            layout.origin.x + layout.size.width / 2,
+            layout.origin.y + layout.size.height / 2,
+        ),
+    );
+    let _handled = recorder.window.mouse_input(
+        DeviceId::Virtual(0),
+        ElementState::Pressed,
+        MouseButton::Left,
+    );
+    assert_eq!(count.get(), 0);
+    let _handled = recorder.window.mouse_input(
+        DeviceId::Virtual(0),
+        ElementState::Released,
+        MouseButton::Left,
+    );
+    assert_eq!(count.get(), 1);
+}
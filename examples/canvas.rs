@@ -1,12 +1,27 @@
+use cushy::figures::units::Px;
 use cushy::figures::{Angle, IntoSigned, Point, Px2D, Rect, Size};
 use cushy::kludgine::shapes::Shape;
 use cushy::kludgine::text::{Text, TextOrigin};
-use cushy::kludgine::{Color, DrawableExt};
+use cushy::kludgine::{Color, DrawableExt, Origin};
+use cushy::reactive::value::{Destination, Dynamic, Source};
 use cushy::widgets::Canvas;
 use cushy::{Run, Tick};
 
 fn main() -> cushy::Result<()> {
     let mut angle = Angle::degrees(0);
+    // The tick reports the cursor's location relative to the canvas.
+    let cursor = Dynamic::new(None::<(Point<Px>, bool)>);
+    let tick = Tick::times_per_second(60, {
+        let cursor = cursor.clone();
+        move |_elapsed, input| {
+            cursor.set(
+                input
+                    .mouse
+                    .as_ref()
+                    .map(|mouse| (mouse.position, !mouse.buttons.is_empty())),
+            );
+        }
+    });
     Canvas::new(move |context| {
         angle += Angle::degrees(1);
 
@@ -23,8 +38,15 @@ fn main() -> cushy::Result<()> {
             )
             .translate_by(center)
             .rotate_by(angle),
-        )
+        );
+
+        if let Some((location, pressed)) = cursor.get() {
+            let color = if pressed { Color::YELLOW } else { Color::WHITE };
+            context.gfx.draw_shape(
+                Shape::filled_circle(Px::new(10), color, Origin::Center).translate_by(location),
+            );
+        }
     })
-    .tick(Tick::redraws_per_second(60))
+    .tick(tick)
     .run()
 }
@@ -30,3 +30,57 @@ fn main() -> cushy::Result {
         .into_rows()
         .run()
 }
+
+#[test]
+fn keyboard_focus() {
+    use cushy::kludgine::app::winit::event::{ElementState, Modifiers};
+    use cushy::kludgine::app::winit::keyboard::{
+        Key, KeyCode, KeyLocation, ModifiersState, NamedKey, PhysicalKey,
+    };
+    use cushy::window::{DeviceId, KeyEvent, VirtualWindow};
+
+    fn press(window: &mut VirtualWindow, physical_key: KeyCode, logical_key: Key, text: &str) {
+        let modifiers = window.state().modifiers;
+        let mut event = KeyEvent {
+            physical_key: PhysicalKey::Code(physical_key),
+            logical_key,
+            text: Some(text.into()),
+            location: KeyLocation::Standard,
+            state: ElementState::Pressed,
+            repeat: false,
+            modifiers,
+        };
+        let _handled = window.keyboard_input(DeviceId::Virtual(0), event.clone(), false);
+        event.state = ElementState::Released;
+        let _handled = window.keyboard_input(DeviceId::Virtual(0), event, false);
+    }
+
+    let first_value = Dynamic::<String>::default();
+    let second_value = Dynamic::<String>::default();
+    let first = first_value.clone().into_input().make_widget();
+    let second = second_value.clone().into_input().make_widget();
+    let (first_id, second_id) = (first.id(), second.id());
+    let mut recorder = first
+        .and(second)
+        .into_rows()
+        .build_recorder()
+        .finish()
+        .unwrap();
+    let window = &mut recorder.window;
+
+    // Tab focuses the first input, and again moves focus to the second input.
+    press(window, KeyCode::Tab, Key::Named(NamedKey::Tab), "\t");
+    assert_eq!(window.focused_widget(), Some(first_id));
+    press(window, KeyCode::Tab, Key::Named(NamedKey::Tab), "\t");
+    assert_eq!(window.focused_widget(), Some(second_id));
+
+    // Keyboard input is delivered to the focused widget.
+    press(window, KeyCode::KeyA, Key::Character("a".into()), "a");
+    assert_eq!(first_value.get(), "");
+    assert_eq!(second_value.get(), "a");
+
+    // Shift+Tab returns focus to the first input.
+    window.set_modifiers(Modifiers::from(ModifiersState::SHIFT));
+    press(window, KeyCode::Tab, Key::Named(NamedKey::Tab), "\t");
+    assert_eq!(window.focused_widget(), Some(first_id));
+}
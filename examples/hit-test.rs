@@ -0,0 +1,87 @@
+//! This example demonstrates how [`Widget::hit_test`] allows the cursor to
+//! pass through portions of a widget.
+//!
+//! The top layer only accepts the cursor on its left half. When hovering the
+//! right half, the layer beneath it receives the hover events instead.
+//!
+//! [`Widget::hit_test`]: cushy::widget::Widget::hit_test
+
+use cushy::figures::units::Px;
+use cushy::kludgine::Color;
+use cushy::reactive::value::{Destination, Dynamic, Source};
+use cushy::widget::MakeWidget;
+use cushy::widgets::Custom;
+use cushy::Run;
+
+fn main() -> cushy::Result {
+    let hovered = Dynamic::new(None);
+    let label = hovered.map_each(|hovered: &Option<&'static str>| {
+        format!("Hovered layer: {}", hovered.unwrap_or("none"))
+    });
+    label
+        .and(
+            half_hit_test_layers(&hovered)
+                .width(Px::new(200))
+                .height(Px::new(100)),
+        )
+        .into_rows()
+        .centered()
+        .run()
+}
+
+fn half_hit_test_layers(hovered: &Dynamic<Option<&'static str>>) -> impl MakeWidget {
+    let back = Custom::empty()
+        .background_color(Color::BLUE)
+        .on_hit_test(|_, _| true)
+        .on_hover({
+            let hovered = hovered.clone();
+            move |_, _| {
+                hovered.set(Some("back"));
+                None
+            }
+        })
+        .expand();
+    let front = Custom::empty()
+        .background_color(Color::RED.with_alpha(128))
+        .on_hit_test(|location, context| {
+            // Only accept the cursor when it is over the left half of this
+            // widget's bounds.
+            context
+                .last_layout()
+                .is_some_and(|layout| location.x < layout.size.width / 2)
+        })
+        .on_hover({
+            let hovered = hovered.clone();
+            move |_, _| {
+                hovered.set(Some("front"));
+                None
+            }
+        })
+        .expand();
+
+    back.and(front).into_layers()
+}
+
+#[test]
+fn hover_passes_through() {
+    use cushy::figures::{Point, Px2D, Size};
+    use cushy::window::DeviceId;
+
+    let hovered = Dynamic::new(None);
+    let mut recorder = half_hit_test_layers(&hovered)
+        .build_recorder()
+        .size(Size::new(200_u32, 100_u32))
+        .finish()
+        .unwrap();
+    recorder.refresh().unwrap();
+
+    recorder
+        .window
+        .cursor_moved(DeviceId::Virtual(0), Point::px(50, 50));
+    assert_eq!(hovered.get(), Some("front"));
+
+    recorder
+        .window
+        .cursor_moved(DeviceId::Virtual(0), Point::px(150, 50));
+    assert_eq!(hovered.get(), Some("back"));
+}
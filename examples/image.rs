@@ -27,6 +27,7 @@ fn main() -> cushy::Result {
             },
             ScalingMode::Stretch => ImageScaling::Stretch,
             ScalingMode::Scale => ImageScaling::Scale(scale),
+            ScalingMode::Tile => ImageScaling::Tile,
         },
     );
     let hide_scale_editor = mode.map_each(|scale| !matches!(scale, ScalingMode::Scale));
@@ -100,6 +101,7 @@ fn main() -> cushy::Result {
                 .into_rows(),
         )
         .and(mode.new_radio(ScalingMode::Stretch).labelled_by("Stretch"))
+        .and(mode.new_radio(ScalingMode::Tile).labelled_by("Tile"))
         .and(filter_select)
         .into_rows();
 
@@ -115,6 +117,7 @@ fn main() -> cushy::Result {
 enum ScalingMode {
     Aspect,
     Stretch,
+    Tile,
     #[default]
     Scale,
 }
@@ -9,11 +9,14 @@ use cushy::Run;
 fn main() -> cushy::Result {
     let contents = Dynamic::from("Hello World");
     let password = Dynamic::new(MaskedString::default());
+    let notes = Dynamic::from("Press Enter to\nadd more lines.");
 
     "Text Input Field:"
         .and(contents.into_input())
         .and("Masked Input Field:")
         .and(password.into_input())
+        .and("Multi-line Input Field:")
+        .and(notes.into_input().multiline())
         .into_rows()
         .width(Px::new(100)..Px::new(800))
         .with_local(&HorizontalAlignment, HorizontalAlign::Center)
@@ -23,3 +26,50 @@ fn main() -> cushy::Result {
         .centered()
         .run()
 }
+
+#[test]
+fn multiline_enter() {
+    use cushy::kludgine::app::winit::event::{ElementState, Modifiers};
+    use cushy::kludgine::app::winit::keyboard::{
+        Key, KeyCode, KeyLocation, ModifiersState, NamedKey, PhysicalKey,
+    };
+    use cushy::reactive::value::Source;
+    use cushy::window::{DeviceId, KeyEvent, VirtualWindow};
+    use cushy::ModifiersStateExt;
+
+    fn press_enter(window: &mut VirtualWindow, modifiers: Modifiers) {
+        window.set_modifiers(modifiers);
+        let mut event = KeyEvent {
+            physical_key: PhysicalKey::Code(KeyCode::Enter),
+            logical_key: Key::Named(NamedKey::Enter),
+            text: Some("\r".into()),
+            location: KeyLocation::Standard,
+            state: ElementState::Pressed,
+            repeat: false,
+            modifiers,
+        };
+        let _handled = window.keyboard_input(DeviceId::Virtual(0), event.clone(), false);
+        event.state = ElementState::Released;
+        let _handled = window.keyboard_input(DeviceId::Virtual(0), event, false);
+    }
+
+    let notes = Dynamic::<String>::default();
+    let mut recorder = notes
+        .clone()
+        .into_input()
+        .multiline()
+        .build_recorder()
+        .finish()
+        .unwrap();
+
+    // Enter inserts a new line into a multi-line input.
+    press_enter(&mut recorder.window, Modifiers::default());
+    assert_eq!(notes.get(), "\n");
+
+    // Primary+Enter is left for the window, such as to submit a form.
+    press_enter(
+        &mut recorder.window,
+        Modifiers::from(ModifiersState::PRIMARY),
+    );
+    assert_eq!(notes.get(), "\n");
+}
@@ -28,6 +28,8 @@ fn menu_example() -> impl MakeWidget {
         })
         .centered()
         .expand()
+        // Right-clicking anywhere else shows the same menu.
+        .context_menu(&overlay, menu(true))
         .and(overlay)
         .into_layers()
 }
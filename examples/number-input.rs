@@ -0,0 +1,42 @@
+use cushy::figures::units::Lp;
+use cushy::reactive::value::{Dynamic, Source};
+use cushy::widget::MakeWidget;
+use cushy::widgets::label::Displayable;
+use cushy::widgets::NumberInput;
+use cushy::Run;
+
+fn main() -> cushy::Result {
+    let volume = Dynamic::new(50_u8);
+    let scale = Dynamic::new(1.0_f32);
+
+    "Volume (0-100)"
+        .and(
+            NumberInput::new(volume.clone())
+                .minimum(0)
+                .maximum(100)
+                .step(5)
+                .spinner_buttons(),
+        )
+        .and(
+            volume
+                .map_each(|volume| format!("Volume: {volume}"))
+                .into_label(),
+        )
+        .and("Scale (0.5-4.0)")
+        .and(
+            NumberInput::new(scale.clone())
+                .minimum(0.5)
+                .maximum(4.0)
+                .step(0.25),
+        )
+        .and(
+            scale
+                .map_each(|scale| format!("Scale: {scale}"))
+                .into_label(),
+        )
+        .into_rows()
+        .width(..Lp::points(400))
+        .pad()
+        .centered()
+        .run()
+}
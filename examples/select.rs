@@ -1,5 +1,7 @@
-use cushy::reactive::value::Dynamic;
+use cushy::reactive::value::{Dynamic, Source};
 use cushy::widget::MakeWidget;
+use cushy::widgets::layers::OverlayLayer;
+use cushy::widgets::SelectGroup;
 use cushy::Run;
 
 #[derive(Default, Eq, PartialEq, Debug, Clone, Copy)]
@@ -10,14 +12,53 @@ pub enum Choice {
     C,
 }
 
+#[derive(Default, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Fruit {
+    #[default]
+    Apple,
+    Apricot,
+    Banana,
+    Blueberry,
+    Cherry,
+}
+
 fn main() -> cushy::Result {
     let option = Dynamic::default();
+    let fruit = Dynamic::<Fruit>::default();
+    let overlay = OverlayLayer::default();
 
     option
         .new_select(Choice::A, "A")
         .and(option.new_select(Choice::B, "B"))
         .and(option.new_select(Choice::C, "C"))
         .into_rows()
+        .and(
+            // Focus an option in this group and use the arrow keys or type to
+            // change the selection.
+            SelectGroup::new(fruit.clone())
+                .with(Fruit::Apple, "Apple")
+                .with(Fruit::Apricot, "Apricot")
+                .with(Fruit::Banana, "Banana")
+                .with(Fruit::Blueberry, "Blueberry")
+                .with(Fruit::Cherry, "Cherry")
+                .and(fruit.map_each(|fruit| format!("Selected: {fruit:?}")))
+                .into_rows(),
+        )
+        .and(
+            // The same options presented as a dropdown that opens a menu in
+            // `overlay` when clicked.
+            SelectGroup::new(fruit.clone())
+                .with(Fruit::Apple, "Apple")
+                .with(Fruit::Apricot, "Apricot")
+                .with(Fruit::Banana, "Banana")
+                .with(Fruit::Blueberry, "Blueberry")
+                .with(Fruit::Cherry, "Cherry")
+                .dropdown_in(&overlay),
+        )
+        .into_columns()
         .centered()
+        .expand()
+        .and(overlay)
+        .into_layers()
         .run()
 }
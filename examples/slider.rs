@@ -13,6 +13,7 @@ fn main() -> cushy::Result {
     let enabled = Dynamic::new(true);
     u8_slider()
         .and(u8_range_slider())
+        .and(stepped_slider())
         .and(enum_slider())
         .into_rows()
         .with_enabled(enabled.clone())
@@ -78,6 +79,15 @@ fn u8_range_slider() -> impl MakeWidget {
         .into_rows()
 }
 
+fn stepped_slider() -> impl MakeWidget {
+    let value = Dynamic::new(5_u8);
+
+    "Stepped"
+        .and(value.clone().slider_between(0, 10).steps(10).ticks(11))
+        .and(value.into_label().centered())
+        .into_rows()
+}
+
 #[derive(LinearInterpolate, Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
 enum SlidableEnum {
     A,
@@ -1,5 +1,8 @@
+use std::time::Duration;
+
 use cushy::reactive::value::{Destination, Dynamic, Switchable};
 use cushy::widget::{MakeWidget, WidgetInstance};
+use cushy::widgets::SwitcherTransition;
 use cushy::Run;
 
 #[derive(Debug, Eq, PartialEq)]
@@ -16,6 +19,7 @@ fn main() -> cushy::Result {
             ActiveContent::Intro => intro(active.clone()),
             ActiveContent::Success => success(active.clone()),
         })
+        .transition(SwitcherTransition::CrossFade, Duration::from_millis(250))
         .contain()
         .centered()
         .run()
@@ -0,0 +1,33 @@
+use cushy::figures::units::Lp;
+use cushy::figures::Angle;
+use cushy::reactive::value::{Destination, Dynamic, Source};
+use cushy::widget::MakeWidget;
+use cushy::widgets::Transform;
+use cushy::Run;
+
+fn main() -> cushy::Result {
+    let degrees = Dynamic::new(0_f32);
+    let scale = Dynamic::new(1_f32);
+    let clicks = Dynamic::new(0_usize);
+
+    let button = clicks
+        .map_each(|clicks| format!("Clicked {clicks} times"))
+        .into_button()
+        .on_click(move |_| clicks.set(clicks.get() + 1));
+
+    "Rotation"
+        .and(degrees.clone().slider_between(0., 360.))
+        .and("Scale")
+        .and(scale.clone().slider_between(0.25, 2.))
+        .into_rows()
+        .and(
+            Transform::new(button)
+                .rotation(degrees.map_each(|degrees| Angle::degrees_f(*degrees)))
+                .scale(scale)
+                .pad_by(Lp::inches(1))
+                .expand(),
+        )
+        .into_columns()
+        .expand()
+        .run()
+}
@@ -11,3 +11,23 @@ fn main() -> cushy::Result {
         .on_close_requested(move |()| !has_unsaved_changes.get())
         .run()
 }
+
+#[test]
+fn close_after_rejecting() {
+    let has_unsaved_changes = Dynamic::new(true);
+    let mut recorder = "Prevent Closing"
+        .into_checkbox(has_unsaved_changes.clone())
+        .build_recorder()
+        .on_close_requested(move |()| !has_unsaved_changes.get())
+        .finish()
+        .unwrap();
+
+    // The request is rejected while there are unsaved changes.
+    assert!(!recorder.window.request_close());
+    assert!(!recorder.window.closed());
+
+    // Once the user has confirmed discarding the changes, the window can be
+    // closed without asking again.
+    recorder.window.handle().close();
+    assert!(recorder.window.closed());
+}
@@ -22,6 +22,12 @@ fn main(app: &mut App) {
         .and(occluded.map_each(|v| format!("occluded: {:?}", v)))
         .and(maximized.map_each(|v| format!("maximized: {:?}", v)))
         .and(minimized.map_each(|v| format!("minimized: {:?}", v)))
+        .and("Toggle Maximized".into_button().on_click({
+            let maximized = maximized.clone();
+            move |_| {
+                maximized.toggle();
+            }
+        }))
         .and(inner_position.map_each(|v| format!("inner_position: {:?}", v)))
         .and(outer_position.map_each(|v| format!("outer_position: {:?}", v)))
         .and(inner_size.map_each(|v| format!("inner_size: {:?}", v)))
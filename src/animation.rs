@@ -63,6 +63,79 @@ use crate::Cushy;
 
 static ANIMATIONS: Mutex<Animating> = Mutex::new(Animating::new());
 static NEW_ANIMATIONS: Condvar = Condvar::new();
+static MANUAL_CLOCK: Mutex<ManualClockState> = Mutex::new(ManualClockState {
+    guards: 0,
+    now: None,
+});
+
+struct ManualClockState {
+    guards: usize,
+    now: Option<Instant>,
+}
+
+/// Prevents animations from advancing using the system clock until the
+/// returned guard is dropped.
+///
+/// While a [`ManualClock`] exists, running animations only advance when
+/// [`advance_animations()`] is called, and [`now()`] only advances by the
+/// durations passed to it. This allows tests to step through animations
+/// deterministically rather than sleeping.
+///
+/// The clock is shared by the entire process, so tests that run in parallel
+/// within the same process will observe each other's manual clocks.
+pub fn use_manual_clock() -> ManualClock {
+    let mut clock = MANUAL_CLOCK.lock();
+    clock.guards += 1;
+    clock.now.get_or_insert_with(Instant::now);
+    ManualClock(())
+}
+
+/// A guard that keeps animations on a manual clock.
+///
+/// See [`use_manual_clock()`] for more information. When the last guard is
+/// dropped, animations resume advancing using the system clock.
+#[must_use = "the manual clock is only used until this guard is dropped"]
+pub struct ManualClock(());
+
+impl Drop for ManualClock {
+    fn drop(&mut self) {
+        let mut clock = MANUAL_CLOCK.lock();
+        clock.guards -= 1;
+        if clock.guards == 0 {
+            clock.now = None;
+            drop(clock);
+            // Holding the animation lock ensures the animation thread is
+            // either waiting or has not yet checked the clock.
+            let _animations = ANIMATIONS.lock();
+            NEW_ANIMATIONS.notify_one();
+        }
+    }
+}
+
+/// Returns the current time used for animations and time-based widget
+/// behaviors.
+///
+/// This is [`Instant::now()`] unless a [`ManualClock`] is active.
+#[must_use]
+pub fn now() -> Instant {
+    MANUAL_CLOCK.lock().now.unwrap_or_else(Instant::now)
+}
+
+fn manual_clock_active() -> bool {
+    MANUAL_CLOCK.lock().now.is_some()
+}
+
+/// Advances all running animations by `elapsed`.
+///
+/// This is intended to be used with [`use_manual_clock()`], and also advances
+/// the manual clock's [`now()`]. When the system clock is being used, the
+/// animations will also continue to advance on their own.
+pub fn advance_animations(elapsed: Duration) {
+    if let Some(now) = &mut MANUAL_CLOCK.lock().now {
+        *now += elapsed;
+    }
+    ANIMATIONS.lock().advance(elapsed);
+}
 
 pub(crate) fn spawn(app: Cushy) {
     let _ignored = thread_state(Some(app));
@@ -80,7 +153,7 @@ fn animation_thread(app: Option<&Cushy>) {
     let _guard = app.as_ref().map(|app| app.enter_runtime());
     let mut state = thread_state(None);
     loop {
-        if state.running.is_empty() {
+        if state.running.is_empty() || manual_clock_active() {
             state.last_updated = None;
             NEW_ANIMATIONS.wait(&mut state);
         } else {
@@ -89,19 +162,7 @@ fn animation_thread(app: Option<&Cushy>) {
             let elapsed = start - last_tick;
             state.last_updated = Some(start);
 
-            let mut index = 0;
-            while index < state.running.len() {
-                let animation_id = *state.running.member(index).expect("index in bounds");
-                let animation_state = &mut state.animations[animation_id];
-                if animation_state.animation.animate(elapsed).is_break() {
-                    if !animation_state.handle_attached {
-                        state.animations.remove(animation_id);
-                    }
-                    state.running.remove_member(index);
-                } else {
-                    index += 1;
-                }
-            }
+            state.advance(elapsed);
 
             drop(state);
             let next_tick = last_tick + Duration::from_millis(16);
@@ -150,6 +211,22 @@ impl Animating {
         AnimationHandle(Some(id))
     }
 
+    fn advance(&mut self, elapsed: Duration) {
+        let mut index = 0;
+        while index < self.running.len() {
+            let animation_id = *self.running.member(index).expect("index in bounds");
+            let animation_state = &mut self.animations[animation_id];
+            if animation_state.animation.animate(elapsed).is_break() {
+                if !animation_state.handle_attached {
+                    self.animations.remove(animation_id);
+                }
+                self.running.remove_member(index);
+            } else {
+                index += 1;
+            }
+        }
+    }
+
     fn remove_animation(&mut self, id: LotId) {
         self.animations.remove(id);
         self.running.remove(&id);
@@ -1250,6 +1327,28 @@ impl PercentBetween for Fraction {
     }
 }
 
+#[test]
+fn manual_clock() {
+    let clock = use_manual_clock();
+    let started = now();
+    let value = Dynamic::new(0);
+    let _animation = value
+        .transition_to(100)
+        .over(Duration::from_millis(100))
+        .spawn();
+
+    advance_animations(Duration::from_millis(25));
+    assert_eq!(value.get(), 25);
+    assert_eq!(now() - started, Duration::from_millis(25));
+
+    advance_animations(Duration::from_millis(100));
+    assert_eq!(value.get(), 100);
+    assert_eq!(now() - started, Duration::from_millis(125));
+
+    drop(clock);
+    assert!(!manual_clock_active());
+}
+
 #[test]
 fn int_percent_between() {
     assert_eq!(1_u8.percent_between(&1_u8, &2_u8), ZeroToOne::ZERO);
@@ -448,6 +448,39 @@ impl Cushy {
         self.data.clipboard.as_ref().map(|mutex| mutex.lock())
     }
 
+    /// Returns the text contained in the OS's clipboard.
+    ///
+    /// Returns `None` if the clipboard is unavailable or does not currently
+    /// contain text.
+    #[must_use]
+    pub fn clipboard_text(&self) -> Option<String> {
+        match self.clipboard_guard()?.get_text() {
+            Ok(text) => Some(text),
+            Err(arboard::Error::ContentNotAvailable | arboard::Error::ConversionFailure) => None,
+            Err(err) => {
+                tracing::error!("error retrieving clipboard contents: {err}");
+                None
+            }
+        }
+    }
+
+    /// Replaces the contents of the OS's clipboard with `text`.
+    ///
+    /// Returns true if the clipboard was updated successfully.
+    #[must_use]
+    pub fn set_clipboard_text(&self, text: &str) -> bool {
+        let Some(mut clipboard) = self.clipboard_guard() else {
+            return false;
+        };
+        match clipboard.set_text(text) {
+            Ok(()) => true,
+            Err(err) => {
+                tracing::error!("error copying to clipboard: {err}");
+                false
+            }
+        }
+    }
+
     /// Returns the font collection that will be loaded in all Cushy windows.
     #[must_use]
     pub fn fonts(&self) -> &FontCollection {
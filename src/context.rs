@@ -1,11 +1,12 @@
 //! Types that provide access to the Cushy runtime.
 use std::borrow::Cow;
 use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
 
 use figures::units::{Lp, Px, UPx};
-use figures::{IntoSigned, Point, Rect, Round, ScreenScale, Size, Zero};
+use figures::{Fraction, IntoSigned, Point, Rect, Round, ScreenScale, Size, Zero};
 use kludgine::app::winit::event::{Ime, MouseButton, MouseScrollDelta, TouchPhase};
-use kludgine::app::winit::window::Cursor;
+use kludgine::app::winit::window::{Cursor, CursorIcon};
 use kludgine::cosmic_text::{FamilyOwned, Style, Weight};
 use kludgine::shapes::{Shape, StrokeOptions};
 use kludgine::{Color, Kludgine, KludgineId};
@@ -19,14 +20,15 @@ use crate::graphics::{FontState, Graphics};
 use crate::localization::Localizations;
 use crate::reactive::value::{IntoValue, Source, Value};
 use crate::styles::components::{
-    CornerRadius, FontFamily, FontStyle, FontWeight, HighlightColor, LayoutOrder, LineHeight,
-    Opacity, OutlineWidth, TextSize, WidgetBackground,
+    CornerRadius, FocusColor, FocusWidth, FontFamily, FontStyle, FontWeight, LayoutOrder,
+    LineHeight, Opacity, TextSize, WidgetBackground, ZIndex,
 };
 use crate::styles::{ComponentDefinition, Dimension, FontFamilyList, Styles, Theme, ThemePair};
 use crate::tree::Tree;
 use crate::widget::{EventHandling, MountedWidget, RootBehavior, WidgetId, WidgetInstance};
+use crate::widgets::layers::FocusTrap;
 use crate::window::{
-    CursorState, DeviceId, KeyEvent, PlatformWindow, ThemeMode, WidgetCursorState,
+    CursorState, DeviceId, KeyEvent, PlatformWindow, ThemeMode, Touch, WidgetCursorState,
 };
 use crate::ConstraintLimit;
 
@@ -158,19 +160,90 @@ impl<'context> EventContext<'context> {
             .mouse_wheel(device_id, delta, phase, self)
     }
 
+    /// Invokes [`Widget::touch()`](crate::widget::Widget::touch) on this
+    /// context's widget and returns the result.
+    pub fn touch(&mut self, touch: Touch) -> EventHandling {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .touch(touch, self)
+    }
+
+    /// Invokes [`Widget::hovered_file()`](crate::widget::Widget::hovered_file)
+    /// on this context's widget and returns the result.
+    pub fn hovered_file(&mut self, path: PathBuf) -> EventHandling {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .hovered_file(path, self)
+    }
+
+    /// Invokes
+    /// [`Widget::hovered_file_cancelled()`](crate::widget::Widget::hovered_file_cancelled)
+    /// on this context's widget.
+    pub fn hovered_file_cancelled(&mut self) {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .hovered_file_cancelled(self);
+    }
+
+    /// Invokes [`Widget::dropped_file()`](crate::widget::Widget::dropped_file)
+    /// on this context's widget and returns the result.
+    pub fn dropped_file(&mut self, path: PathBuf) -> EventHandling {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .dropped_file(path, self)
+    }
+
+    /// Invokes
+    /// [`Widget::window_resized()`](crate::widget::Widget::window_resized) on
+    /// this context's widget.
+    pub fn window_resized(&mut self, new_size: Size<UPx>) {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .window_resized(new_size, self);
+    }
+
+    /// Invokes
+    /// [`Widget::scale_factor_changed()`](crate::widget::Widget::scale_factor_changed)
+    /// on this context's widget.
+    pub fn scale_factor_changed(&mut self, scale: Fraction) {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .scale_factor_changed(scale, self);
+    }
+
+    /// Invokes
+    /// [`Widget::theme_changed()`](crate::widget::Widget::theme_changed) on
+    /// this context's widget.
+    pub fn theme_changed(&mut self) {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .theme_changed(self);
+    }
+
     pub(crate) fn hover(&mut self, location: Point<Px>) {
         let changes = self.tree.hover(Some(&self.current_node));
 
         let mut cursor = None;
         for hover in changes.hovered.into_iter().rev() {
             let mut context = self.for_other(&hover);
-            let Some(last_layout) = context.last_layout() else {
+            let Some(relative) = context.location_in_widget(location) else {
                 continue;
             };
-            let widget_cursor = hover
-                .lock()
-                .as_widget()
-                .hover(location - last_layout.origin, &mut context);
+            let widget_cursor = hover.lock().as_widget().hover(relative, &mut context);
 
             if cursor.is_none() {
                 cursor = widget_cursor;
@@ -312,33 +385,39 @@ impl<'context> EventContext<'context> {
         }
     }
 
+    /// Returns the top-most widget beneath `location` whose
+    /// [`hit_test`](Self::hit_test) returns true.
+    pub(crate) fn hit_test_widget_at(&mut self, location: Point<Px>) -> Option<MountedWidget> {
+        for widget in self.tree.widgets_under_point(location) {
+            let mut widget_context = self.for_other(&widget);
+            let Some(relative) = widget_context.location_in_widget(location) else {
+                continue;
+            };
+            if widget_context.hit_test(relative) {
+                drop(widget_context);
+                return Some(widget);
+            }
+        }
+        None
+    }
+
     pub(crate) fn update_hovered_widget(&mut self) {
         let current_hover = self.cursor.widget.take();
 
-        if let Some(location) = self.cursor.location {
-            for widget in self.tree.widgets_under_point(location) {
-                let mut widget_context = self.for_other(&widget);
-                let Some(widget_layout) = widget_context.last_layout() else {
-                    continue;
-                };
-                let relative = location - widget_layout.origin;
-                let widget_hover = Some(WidgetCursorState {
-                    id: widget.id(),
-                    last_hovered: location,
-                });
-
-                if widget_context.hit_test(relative) {
-                    if current_hover != widget_hover {
-                        widget_context.hover(location);
-                    }
-                    drop(widget_context);
-                    self.cursor.widget = widget_hover;
-                    break;
-                }
+        let hovered = self
+            .cursor
+            .location
+            .and_then(|location| Some((self.hit_test_widget_at(location)?, location)));
+        if let Some((widget, location)) = hovered {
+            let widget_hover = Some(WidgetCursorState {
+                id: widget.id(),
+                last_hovered: location,
+            });
+            if current_hover != widget_hover {
+                self.for_other(&widget).hover(location);
             }
-        }
-
-        if self.cursor.widget.is_none() {
+            self.cursor.widget = widget_hover;
+        } else {
             self.clear_hover();
         }
     }
@@ -362,6 +441,10 @@ impl<'context> EventContext<'context> {
             let Some(parent) = focus.parent() else {
                 break focus;
             };
+            // Focus cycles within a focus trap rather than leaving it.
+            if parent.lock().downcast_ref::<FocusTrap>().is_some() {
+                break parent;
+            }
             // If we're reversing focus, we need to consider the parent itself
             // as a focus target.
             let accept_focus = !advance
@@ -702,14 +785,19 @@ impl<'clip, 'gfx, 'pass> GraphicsContext<'_, 'clip, 'gfx, 'pass> {
     }
 
     /// Renders the default focus ring for this widget.
+    ///
+    /// The ring is drawn using [`FocusColor`] and [`FocusWidth`]. This function
+    /// always draws the ring. Widgets that only want to show the ring when
+    /// navigating with the keyboard should also check
+    /// [`focus_is_visible()`](WidgetContext::focus_is_visible).
     pub fn draw_focus_ring(&mut self) {
         // If this is the root widget, don't draw a focus ring. It's redundant.
         if !self.current_node.has_parent() {
             return;
         }
 
-        let color = self.get(&HighlightColor);
-        let width = self.get(&OutlineWidth).into_px(self.gfx.scale()).ceil();
+        let color = self.get(&FocusColor);
+        let width = self.get(&FocusWidth).into_px(self.gfx.scale()).ceil();
         self.stroke_outline(color, StrokeOptions::px_wide(width));
     }
 
@@ -738,6 +826,26 @@ impl<'clip, 'gfx, 'pass> GraphicsContext<'_, 'clip, 'gfx, 'pass> {
         self.current_font_settings().apply(self);
     }
 
+    /// Invokes [`Widget::redraw()`](crate::widget::Widget::redraw) on each of
+    /// `children`, in order of their [`ZIndex`].
+    ///
+    /// Children with the same z-index are drawn in the order they are
+    /// provided. Because hit testing follows the order widgets are drawn, the
+    /// child with the highest z-index also receives events first.
+    pub fn redraw_children<'child>(
+        &mut self,
+        children: impl IntoIterator<Item = &'child MountedWidget>,
+    ) {
+        let mut children = children
+            .into_iter()
+            .map(|child| (self.for_other(child).get(&ZIndex), child))
+            .collect::<Vec<_>>();
+        children.sort_by_key(|(z_index, _)| *z_index);
+        for (_, child) in children {
+            self.for_other(child).redraw();
+        }
+    }
+
     /// Invokes [`Widget::redraw()`](crate::widget::Widget::redraw) on this
     /// context's widget.
     pub fn redraw(&mut self) {
@@ -745,7 +853,8 @@ impl<'clip, 'gfx, 'pass> GraphicsContext<'_, 'clip, 'gfx, 'pass> {
             return;
         }
 
-        self.tree.note_widget_rendered(self.current_node.node_id);
+        self.tree
+            .note_widget_rendered(self.current_node.node_id, self.gfx.transform);
         let widget = self.current_node.clone();
         let mut widget = widget.lock();
         if !widget.as_widget().full_control_redraw() {
@@ -918,10 +1027,11 @@ pub trait AsEventContext {
     fn push_child(&mut self, child: WidgetInstance) -> MountedWidget {
         let mut context = self.as_event_context();
         let pushed_widget = context.tree.push_boxed(child, Some(&context.current_node));
-        pushed_widget
-            .lock()
-            .as_widget()
-            .mounted(&mut context.for_other(&pushed_widget));
+        let mut pushed_context = context.for_other(&pushed_widget);
+        let mut widget = pushed_widget.lock();
+        widget.as_widget().mounted(&mut pushed_context);
+        widget.as_widget().theme_changed(&mut pushed_context);
+        drop(widget);
         pushed_widget
     }
 
@@ -1147,6 +1257,38 @@ impl<'context> WidgetContext<'context> {
         self.current_node.last_layout()
     }
 
+    /// Returns the number of consecutive clicks of the mouse button most
+    /// recently pressed in this window, including the latest press.
+    ///
+    /// Clicks are consecutive when each click is at nearly the same location
+    /// and occurs within
+    /// [`Cushy::multi_click_threshold()`](crate::Cushy::multi_click_threshold)
+    /// of the previous click. When called from
+    /// [`Widget::mouse_down()`](crate::widget::Widget::mouse_down), this
+    /// returns 2 for a double click and 3 for a triple click.
+    #[must_use]
+    pub fn click_count(&self) -> usize {
+        self.cursor.click_count
+    }
+
+    /// Returns the last layout of the widget with `id`, if it is mounted in
+    /// the same window as this widget and has been laid out.
+    #[must_use]
+    pub fn widget_layout(&self, id: WidgetId) -> Option<Rect<Px>> {
+        self.tree.widget(id)?.last_layout()
+    }
+
+    /// Converts `location` from window coordinates into coordinates relative
+    /// to this widget's last layout.
+    ///
+    /// Any [`Transform`](crate::widgets::Transform) this widget was last
+    /// rendered with is reversed before the location is converted.
+    pub(crate) fn location_in_widget(&self, location: Point<Px>) -> Option<Point<Px>> {
+        let layout = self.last_layout()?;
+        let location = self.tree.untransform(self.current_node.node_id, location);
+        Some(location - layout.origin)
+    }
+
     /// Sets the currently focused widget to this widget.
     ///
     /// Widget events relating to focus changes are deferred until after the all
@@ -1175,6 +1317,22 @@ impl<'context> WidgetContext<'context> {
         }
     }
 
+    /// Returns true if the focused widget should visibly indicate that it has
+    /// focus.
+    ///
+    /// Similar to CSS's `:focus-visible`, this returns false after the user
+    /// interacts with the window using the mouse, and true again once the
+    /// keyboard is used. This allows widgets to omit their focus ring when
+    /// they are focused by being clicked, while still showing it when
+    /// navigating using the keyboard.
+    ///
+    /// This function only reports the window's current interaction mode. To
+    /// check whether this widget is focused, use [`Self::focused()`].
+    #[must_use]
+    pub fn focus_is_visible(&self) -> bool {
+        self.tree.focus_is_visible()
+    }
+
     /// Returns true if the last focus event was an advancing motion, not a
     /// returning motion.
     ///
@@ -1356,6 +1514,17 @@ impl<'context> WidgetContext<'context> {
         self.window
     }
 
+    /// Sets the window's cursor to `icon`.
+    ///
+    /// The cursor is recomputed each time the hovered widget changes or the
+    /// cursor moves, using the value returned from
+    /// [`Widget::hover()`](crate::widget::Widget::hover) by the innermost
+    /// hovered widget. This function is useful for changing the cursor outside
+    /// of hover events, such as while a drag operation is in progress.
+    pub fn set_cursor_icon(&mut self, icon: CursorIcon) {
+        self.window.set_cursor(icon.into());
+    }
+
     /// Returns the theme pair for the window.
     #[must_use]
     pub fn theme_pair(&self) -> &ThemePair {
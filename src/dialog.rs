@@ -1,5 +1,6 @@
 //! Modal dialogs such as message boxes and file pickers.
 
+use std::future::Future;
 use std::marker::PhantomData;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -9,7 +10,7 @@ use std::{env, fs};
 use figures::units::Lp;
 use parking_lot::Mutex;
 
-use crate::reactive::value::{Destination, Dynamic, Source};
+use crate::reactive::value::{Destination, Dynamic, DynamicReader, Source};
 use crate::styles::components::{PrimaryColor, WidgetBackground};
 use crate::styles::DynamicComponent;
 use crate::widget::{MakeWidget, OnceCallback, SharedCallback, WidgetList};
@@ -277,6 +278,79 @@ impl MessageBox {
         Self::build(message).finish()
     }
 
+    /// Returns a dialog displaying `title` and `explanation` with an `OK`
+    /// button that dismisses the dialog.
+    #[must_use]
+    pub fn info(title: impl Into<String>, explanation: impl Into<String>) -> Self {
+        Self::message(title).with_explanation(explanation)
+    }
+
+    /// Opens a dialog in `open_in` asking the user to confirm `message`, and
+    /// returns a future that yields the user's choice.
+    ///
+    /// The future yields true if the `OK` button is chosen, and false if the
+    /// `Cancel` button is chosen or the dialog is dismissed any other way.
+    pub fn confirm(
+        message: impl Into<String>,
+        explanation: impl Into<String>,
+        open_in: &impl OpenMessageBox,
+    ) -> impl Future<Output = bool> + Send + 'static {
+        let response = Dynamic::new(None);
+        let result = response.create_reader();
+        Self::build(message)
+            .with_explanation(explanation)
+            .with_ok({
+                let response = response.clone();
+                move || {
+                    response.set(Some(true));
+                    ShouldClose::Close
+                }
+            })
+            .with_cancel(move || {
+                response.set(Some(false));
+                ShouldClose::Close
+            })
+            .finish()
+            .open(open_in);
+        dialog_response(result, false)
+    }
+
+    /// Opens a dialog in `open_in` asking the user to enter text in response
+    /// to `message`, and returns a future that yields the entered text.
+    ///
+    /// The text field initially contains `initial_value`. The future yields
+    /// `None` if the `Cancel` button is chosen or the dialog is dismissed
+    /// without choosing the `OK` button.
+    ///
+    /// Native dialogs do not support text entry, so this dialog can only be
+    /// shown in a [`Modal`] layer.
+    pub fn prompt(
+        message: impl Into<String>,
+        initial_value: impl Into<String>,
+        open_in: &impl ModalTarget,
+    ) -> impl Future<Output = Option<String>> + Send + 'static {
+        let message = message.into();
+        let text = Dynamic::new(initial_value.into());
+        let response = Dynamic::new(None);
+        let result = response.create_reader();
+        open_in
+            .new_handle()
+            .build_dialog(message.h5().and(text.clone().into_input()).into_rows())
+            .with_default_button("OK", {
+                let response = response.clone();
+                move || {
+                    response.set(Some(Some(text.get())));
+                    ShouldClose::Close
+                }
+            })
+            .with_cancel_button("Cancel", move || {
+                response.set(Some(None));
+                ShouldClose::Close
+            })
+            .show();
+        dialog_response(result, None)
+    }
+
     /// Sets the explanation text and returns self.
     #[must_use]
     pub fn with_explanation(mut self, explanation: impl Into<String>) -> Self {
@@ -319,6 +393,23 @@ impl MessageBox {
     }
 }
 
+/// Waits for a dialog's buttons to store a response in `response`, yielding
+/// `dismissed` if the dialog is closed without storing a response.
+async fn dialog_response<T>(response: DynamicReader<Option<T>>, dismissed: T) -> T
+where
+    T: Clone,
+{
+    loop {
+        if let Some(response) = response.get() {
+            return response;
+        }
+        if !response.wait_until_updated().await {
+            // Every button holding the response has been dropped.
+            return response.get().unwrap_or(dismissed);
+        }
+    }
+}
+
 /// A type that can open a [`MessageBox`] as a modal dialog.
 pub trait OpenMessageBox {
     /// Opens `message` as a modal dialog.
@@ -493,6 +584,78 @@ impl FilePicker {
     {
         pick_in.pick_folders(self, on_dismiss);
     }
+
+    /// Shows a picker that selects a single file and returns a future that
+    /// yields the selection when the dialog is dismissed.
+    pub fn pick_file_async(
+        &self,
+        pick_in: &impl PickFile,
+    ) -> impl Future<Output = Option<PathBuf>> + Send + 'static {
+        let (on_dismiss, picked) = picker_response();
+        pick_in.pick_file(self, on_dismiss);
+        picked
+    }
+
+    /// Shows a picker that creates a new file and returns a future that yields
+    /// the chosen path when the dialog is dismissed.
+    pub fn save_file_async(
+        &self,
+        pick_in: &impl PickFile,
+    ) -> impl Future<Output = Option<PathBuf>> + Send + 'static {
+        let (on_dismiss, picked) = picker_response();
+        pick_in.save_file(self, on_dismiss);
+        picked
+    }
+
+    /// Shows a picker that selects one or more files and returns a future that
+    /// yields the selection when the dialog is dismissed.
+    pub fn pick_files_async(
+        &self,
+        pick_in: &impl PickFile,
+    ) -> impl Future<Output = Option<Vec<PathBuf>>> + Send + 'static {
+        let (on_dismiss, picked) = picker_response();
+        pick_in.pick_files(self, on_dismiss);
+        picked
+    }
+
+    /// Shows a picker that selects a single folder/directory and returns a
+    /// future that yields the selection when the dialog is dismissed.
+    pub fn pick_folder_async(
+        &self,
+        pick_in: &impl PickFile,
+    ) -> impl Future<Output = Option<PathBuf>> + Send + 'static {
+        let (on_dismiss, picked) = picker_response();
+        pick_in.pick_folder(self, on_dismiss);
+        picked
+    }
+
+    /// Shows a picker that selects one or more folders/directories and returns
+    /// a future that yields the selection when the dialog is dismissed.
+    pub fn pick_folders_async(
+        &self,
+        pick_in: &impl PickFile,
+    ) -> impl Future<Output = Option<Vec<PathBuf>>> + Send + 'static {
+        let (on_dismiss, picked) = picker_response();
+        pick_in.pick_folders(self, on_dismiss);
+        picked
+    }
+}
+
+/// Returns a callback that stores the result of a [`FilePicker`] and a future
+/// that yields the stored result.
+fn picker_response<T>() -> (
+    impl FnOnce(Option<T>) + Send + 'static,
+    impl Future<Output = Option<T>> + Send + 'static,
+)
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let response = Dynamic::new(None);
+    let result = response.create_reader();
+    (
+        move |picked| response.set(Some(picked)),
+        dialog_response(result, None),
+    )
 }
 
 /// A file type filter used in a [`FilePicker`].
@@ -4,8 +4,8 @@ use std::ops::{Deref, DerefMut};
 
 use figures::units::{Px, UPx};
 use figures::{
-    self, Fraction, IntoSigned, IntoUnsigned, Point, Rect, Round, ScreenScale, ScreenUnit, Size,
-    Zero,
+    self, Angle, FloatConversion, Fraction, IntoSigned, IntoUnsigned, Point, Rect, Round,
+    ScreenScale, ScreenUnit, Size, Zero,
 };
 use intentional::Assert;
 use kempt::{map, Map};
@@ -28,6 +28,7 @@ pub struct Graphics<'clip, 'gfx, 'pass> {
     renderer: RenderContext<'clip, 'gfx, 'pass>,
     region: Rect<Px>,
     pub(crate) opacity: ZeroToOne,
+    pub(crate) transform: Option<DrawTransform>,
 }
 
 enum RenderContext<'clip, 'gfx, 'pass> {
@@ -43,6 +44,7 @@ impl<'gfx, 'pass> Graphics<'_, 'gfx, 'pass> {
             region: renderer.clip_rect().into_signed(),
             renderer: RenderContext::Renderer(renderer),
             opacity: ZeroToOne::ONE,
+            transform: None,
         }
     }
 
@@ -82,16 +84,21 @@ impl<'gfx, 'pass> Graphics<'_, 'gfx, 'pass> {
     /// operations will be relative to the origin of `clip`.
     pub fn clipped_to(&mut self, clip: Rect<Px>) -> Graphics<'_, 'gfx, 'pass> {
         let region = clip + self.region.origin;
+        // When transformed, the clip must contain everything drawn within
+        // `region` after the transformation is applied.
+        let clip_region = self
+            .transform
+            .map_or(region, |transform| transform.bounding_box(region));
 
         // If the current region has a negative component, we need to adjust the
         // clipped rect before we perform an intersection in unsigned space.
-        let mut effective_region = region;
-        if region.origin.x < 0 {
-            effective_region.size.width += region.origin.x;
+        let mut effective_region = clip_region;
+        if clip_region.origin.x < 0 {
+            effective_region.size.width += clip_region.origin.x;
             effective_region.origin.x = Px::ZERO;
         }
-        if region.origin.y < 0 {
-            effective_region.size.height += region.origin.y;
+        if clip_region.origin.y < 0 {
+            effective_region.size.height += clip_region.origin.y;
             effective_region.origin.y = Px::ZERO;
         }
         let new_clip = self
@@ -105,6 +112,7 @@ impl<'gfx, 'pass> Graphics<'_, 'gfx, 'pass> {
             renderer: RenderContext::Clipped(self.renderer.clipped_to(new_clip)),
             region,
             opacity: self.opacity,
+            transform: self.transform,
         }
     }
 
@@ -171,11 +179,16 @@ impl<'gfx, 'pass> Graphics<'_, 'gfx, 'pass> {
                 .opacity
                 .map_or(*self.opacity, |opacity| opacity * *self.opacity),
         );
-        shape.translation += Point::<Unit>::from_px(self.translation(), self.scale());
+        self.position(&mut shape);
         self.renderer.draw_shape(shape);
     }
 
     /// Draws `texture` at `destination`, scaling as necessary.
+    ///
+    /// When drawn inside of a [`Transform`](crate::widgets::Transform), the
+    /// texture is scaled and translated but not rotated. Use
+    /// [`draw_textured_shape()`](Self::draw_textured_shape) to draw textures
+    /// that can be rotated.
     pub fn draw_texture<Unit>(
         &mut self,
         texture: &impl TextureSource,
@@ -185,9 +198,50 @@ impl<'gfx, 'pass> Graphics<'_, 'gfx, 'pass> {
         Unit: figures::ScreenUnit + ShaderScalable,
         i32: From<<Unit as IntoSigned>::Signed>,
     {
-        let translate = Point::<Unit>::from_px(self.translation(), self.scale());
+        let destination = if let Some(transform) = self.transform {
+            let scale = self.scale();
+            let destination = destination.into_px(scale) + self.region.origin;
+            let origin = transform.apply(destination.origin);
+            Rect::<Unit>::from_px(
+                Rect::new(
+                    origin - self.renderer.clip_rect().origin.into_signed(),
+                    Size::new(
+                        destination.size.width * transform.scale,
+                        destination.size.height * transform.scale,
+                    ),
+                ),
+                scale,
+            )
+        } else {
+            destination + Point::<Unit>::from_px(self.translation(), self.scale())
+        };
         self.renderer
-            .draw_texture(texture, destination + translate, *(self.opacity * opacity));
+            .draw_texture(texture, destination, *(self.opacity * opacity));
+    }
+
+    /// Offsets `drawable` by [`self.translation()`](Self::translation) and
+    /// applies the current transformation, if any.
+    fn position<T, Unit>(&self, drawable: &mut Drawable<T, Unit>)
+    where
+        Unit: ScreenUnit,
+    {
+        let scale = self.scale();
+        if let Some(transform) = self.transform {
+            let absolute = drawable.translation.into_px(scale) + self.region.origin;
+            let clip_origin = self.renderer.clip_rect().origin.into_signed();
+            drawable.translation =
+                Point::<Unit>::from_px(transform.apply(absolute) - clip_origin, scale);
+            drawable.rotation = Some(drawable.rotation.map_or(transform.rotation, |rotation| {
+                add_angles(rotation, transform.rotation)
+            }));
+            drawable.scale = Some(
+                drawable
+                    .scale
+                    .map_or(transform.scale, |amount| amount * transform.scale),
+            );
+        } else {
+            drawable.translation += Point::<Unit>::from_px(self.translation(), scale);
+        }
     }
 
     /// Draws a shape that was created with texture coordinates, applying the
@@ -209,7 +263,7 @@ impl<'gfx, 'pass> Graphics<'_, 'gfx, 'pass> {
                 .opacity
                 .map_or(*effective_opacity, |opacity| opacity * *effective_opacity),
         );
-        shape.translation += Point::<Unit>::from_px(self.translation(), self.scale());
+        self.position(&mut shape);
         self.renderer.draw_textured_shape(shape, texture);
     }
 
@@ -233,7 +287,7 @@ impl<'gfx, 'pass> Graphics<'_, 'gfx, 'pass> {
             text.opacity
                 .map_or(*self.opacity, |opacity| opacity * *self.opacity),
         );
-        text.translation += Point::<Unit>::from_px(self.translation(), self.scale());
+        self.position(&mut text);
         self.renderer.draw_text(text);
     }
 
@@ -258,7 +312,7 @@ impl<'gfx, 'pass> Graphics<'_, 'gfx, 'pass> {
                 .opacity
                 .map_or(*self.opacity, |opacity| opacity * *self.opacity),
         );
-        buffer.translation += Point::<Unit>::from_px(self.translation(), self.scale());
+        self.position(&mut buffer);
         self.renderer
             .draw_text_buffer(buffer, default_color, origin);
     }
@@ -295,7 +349,7 @@ impl<'gfx, 'pass> Graphics<'_, 'gfx, 'pass> {
             text.opacity
                 .map_or(*self.opacity, |opacity| opacity * *self.opacity),
         );
-        text.translation += Point::<Unit>::from_px(self.translation(), self.scale());
+        self.position(&mut text);
         self.renderer.draw_measured_text(text, origin);
     }
 
@@ -339,6 +393,102 @@ impl<'gfx, 'pass> Graphics<'_, 'gfx, 'pass> {
     }
 }
 
+/// A rotation, uniform scale, and translation applied to the drawing
+/// operations of a [`Graphics`] context.
+///
+/// Transformations operate on window coordinates: a point `p` is transformed
+/// into `offset + scale * rotate(p, rotation)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DrawTransform {
+    offset: Point<f32>,
+    scale: f32,
+    rotation: Angle,
+}
+
+impl DrawTransform {
+    /// Returns a transformation that scales and rotates around `pivot` before
+    /// offsetting by `translation`.
+    pub fn around(pivot: Point<Px>, translation: Point<Px>, rotation: Angle, scale: f32) -> Self {
+        let linear = Self {
+            offset: Point::new(0., 0.),
+            scale,
+            rotation,
+        };
+        let pivot = Point::new(pivot.x.into_float(), pivot.y.into_float());
+        let translation = Point::new(translation.x.into_float(), translation.y.into_float());
+        Self {
+            offset: pivot + translation - linear.apply_f32(pivot),
+            scale,
+            rotation,
+        }
+    }
+
+    /// Returns a transformation that applies `self` followed by `outer`.
+    pub fn then(self, outer: Self) -> Self {
+        Self {
+            offset: outer.apply_f32(self.offset),
+            scale: self.scale * outer.scale,
+            rotation: add_angles(self.rotation, outer.rotation),
+        }
+    }
+
+    /// Returns the transformation that reverses `self`, if it can be
+    /// reversed.
+    pub fn inverse(self) -> Option<Self> {
+        if self.scale <= 0. || !self.scale.is_finite() {
+            return None;
+        }
+
+        let linear = Self {
+            offset: Point::new(0., 0.),
+            scale: 1. / self.scale,
+            rotation: Angle::degrees_f(-self.rotation.into_degrees::<f32>()),
+        };
+        let offset = linear.apply_f32(self.offset);
+        Some(Self {
+            offset: Point::new(-offset.x, -offset.y),
+            ..linear
+        })
+    }
+
+    /// Returns the result of transforming `point`.
+    pub fn apply(&self, point: Point<Px>) -> Point<Px> {
+        let point = self.apply_f32(Point::new(point.x.into_float(), point.y.into_float()));
+        Point::new(Px::from(point.x), Px::from(point.y))
+    }
+
+    fn apply_f32(&self, point: Point<f32>) -> Point<f32> {
+        let (sin, cos) = self.rotation.into_degrees::<f32>().to_radians().sin_cos();
+        Point::new(
+            self.offset.x + self.scale * (point.x * cos - point.y * sin),
+            self.offset.y + self.scale * (point.x * sin + point.y * cos),
+        )
+    }
+
+    /// Returns the smallest rectangle that contains `rect` after being
+    /// transformed.
+    pub fn bounding_box(&self, rect: Rect<Px>) -> Rect<Px> {
+        let (min, max) = rect.extents();
+        let corners = [
+            self.apply(min),
+            self.apply(Point::new(max.x, min.y)),
+            self.apply(max),
+            self.apply(Point::new(min.x, max.y)),
+        ];
+        let mut min = corners[0];
+        let mut max = corners[0];
+        for corner in &corners[1..] {
+            min = Point::new(min.x.min(corner.x), min.y.min(corner.y));
+            max = Point::new(max.x.max(corner.x), max.y.max(corner.y));
+        }
+        Rect::from_extents(min, max)
+    }
+}
+
+fn add_angles(a: Angle, b: Angle) -> Angle {
+    Angle::degrees_f(a.into_degrees::<f32>() + b.into_degrees::<f32>())
+}
+
 impl Deref for Graphics<'_, '_, '_> {
     type Target = Kludgine;
 
@@ -783,3 +933,95 @@ where
         self.render(region, opacity, graphics);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use figures::units::Px;
+    use figures::{Angle, FloatConversion, Point, Rect, Size};
+
+    use super::DrawTransform;
+
+    #[track_caller]
+    fn assert_near(actual: Point<Px>, expected: Point<Px>) {
+        // Points are rounded to whole pixels after being transformed.
+        assert!(
+            (actual.x - expected.x).get().abs() <= 1 && (actual.y - expected.y).get().abs() <= 1,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn point(x: i32, y: i32) -> Point<Px> {
+        Point::new(Px::new(x), Px::new(y))
+    }
+
+    #[test]
+    fn inverse_round_trips() {
+        let transforms = [
+            DrawTransform::around(point(0, 0), point(0, 0), Angle::degrees_f(30.), 1.),
+            DrawTransform::around(point(50, 20), point(0, 0), Angle::degrees_f(0.), 2.5),
+            DrawTransform::around(point(0, 0), point(-30, 12), Angle::degrees_f(0.), 1.),
+            DrawTransform::around(point(50, 20), point(10, -5), Angle::degrees_f(135.), 0.5),
+            DrawTransform::around(point(8, 8), point(3, 4), Angle::degrees_f(-60.), 3.).then(
+                DrawTransform::around(point(100, 0), point(0, 7), Angle::degrees_f(45.), 0.75),
+            ),
+        ];
+        let points = [point(0, 0), point(100, 0), point(-40, 75), point(13, -200)];
+        for transform in transforms {
+            let inverse = transform.inverse().expect("invertible");
+            for original in points {
+                let exact = Point::new(original.x.into_float(), original.y.into_float());
+                let round_trip = inverse.apply_f32(transform.apply_f32(exact));
+                assert!(
+                    (round_trip.x - exact.x).abs() < 0.01 && (round_trip.y - exact.y).abs() < 0.01,
+                    "{round_trip:?} != {exact:?}"
+                );
+                assert_near(transform.then(inverse).apply(original), original);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_requires_scale() {
+        let zero = DrawTransform::around(point(0, 0), point(0, 0), Angle::degrees_f(0.), 0.);
+        assert_eq!(zero.inverse(), None);
+        let nan = DrawTransform::around(point(0, 0), point(0, 0), Angle::degrees_f(0.), f32::NAN);
+        assert_eq!(nan.inverse(), None);
+    }
+
+    #[test]
+    fn pivot_is_fixed() {
+        let transform =
+            DrawTransform::around(point(50, 20), point(0, 0), Angle::degrees_f(70.), 2.);
+        assert_near(transform.apply(point(50, 20)), point(50, 20));
+        let translated =
+            DrawTransform::around(point(50, 20), point(5, -5), Angle::degrees_f(70.), 2.);
+        assert_near(translated.apply(point(50, 20)), point(55, 15));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let rect = Rect::new(point(0, 0), Size::new(Px::new(10), Px::new(20)));
+
+        let rotated = DrawTransform::around(point(0, 0), point(0, 0), Angle::degrees_f(90.), 1.);
+        assert_eq!(
+            rotated.bounding_box(rect),
+            Rect::new(point(-20, 0), Size::new(Px::new(20), Px::new(10)))
+        );
+
+        let scaled = DrawTransform::around(point(0, 0), point(5, 5), Angle::degrees_f(0.), 2.);
+        assert_eq!(
+            scaled.bounding_box(rect),
+            Rect::new(point(5, 5), Size::new(Px::new(20), Px::new(40)))
+        );
+
+        // A 45 degree rotation around the center grows the box in both
+        // directions.
+        let diagonal = DrawTransform::around(point(5, 10), point(0, 0), Angle::degrees_f(45.), 1.);
+        let bounds = diagonal.bounding_box(rect);
+        assert_near(bounds.origin, point(-6, -1));
+        assert_near(
+            Point::new(bounds.size.width, bounds.size.height),
+            point(21, 21),
+        );
+    }
+}
@@ -742,3 +742,165 @@ impl std::ops::AddAssign for CallbackHandle {
         }
     }
 }
+
+/// A handle to a future being executed in the background by
+/// [`Dynamic::set_from_future`]. When dropped, the future will be cancelled.
+///
+/// Cancelled futures are not polled again and are dropped the next time they
+/// would have been polled. To allow the future to run to completion
+/// regardless of this handle, use [`Self::persist()`].
+///
+/// Storing this handle in a widget ties the background work to the lifetime
+/// of the widget: when the widget is unmounted and dropped, its pending work
+/// is cancelled.
+#[must_use = "Background tasks are cancelled once the associated TaskHandle is dropped. Consider using `TaskHandle::persist()` to allow the task to complete."]
+#[derive(Debug)]
+pub struct TaskHandle(Option<Arc<Mutex<TaskState>>>);
+
+impl TaskHandle {
+    /// Spawns a thread that executes `future`.
+    ///
+    /// When the `tokio` feature is enabled and this function is called from
+    /// within a tokio runtime, the runtime is entered while executing
+    /// `future`.
+    pub(crate) fn spawn<F>(future: F) -> Self
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let (handle, future) = Self::cancellable(future);
+        #[cfg(feature = "tokio")]
+        let runtime = tokio::runtime::Handle::try_current().ok();
+        std::thread::spawn(move || {
+            #[cfg(feature = "tokio")]
+            let _guard = runtime.as_ref().map(tokio::runtime::Handle::enter);
+            pollster::block_on(future);
+        });
+        handle
+    }
+
+    /// Returns a handle for `future` and the future that should be executed.
+    ///
+    /// The returned future completes early once the handle is dropped.
+    fn cancellable<F>(future: F) -> (Self, CancellableFuture)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(TaskState::default()));
+        let future = CancellableFuture {
+            future: Box::pin(future),
+            state: state.clone(),
+        };
+        (Self(Some(state)), future)
+    }
+
+    /// Allows the task to run to completion, even though this handle is
+    /// dropped.
+    pub fn persist(mut self) {
+        self.0 = None;
+    }
+
+    /// Cancels the task.
+    ///
+    /// This is equivalent to dropping the handle.
+    pub fn cancel(self) {
+        drop(self);
+    }
+
+    /// Returns true if the task has finished executing or has been
+    /// cancelled.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.0.as_ref().map_or(true, |state| state.lock().complete)
+    }
+}
+
+impl Drop for TaskHandle {
+    fn drop(&mut self) {
+        let Some(state) = self.0.take() else {
+            return;
+        };
+        let mut state = state.lock();
+        state.cancelled = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Executes futures one at a time on a single background thread.
+///
+/// The thread is spawned when the first future is executed, and it exits once
+/// the worker is dropped.
+#[derive(Debug, Default)]
+pub(crate) struct TaskWorker {
+    sender: Option<mpsc::Sender<CancellableFuture>>,
+}
+
+impl TaskWorker {
+    /// Executes `future` once all previously executed futures have completed
+    /// or been cancelled.
+    ///
+    /// When the `tokio` feature is enabled and the worker's thread is spawned
+    /// from within a tokio runtime, the runtime is entered while executing
+    /// futures.
+    pub(crate) fn spawn<F>(&mut self, future: F) -> TaskHandle
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let (handle, mut future) = TaskHandle::cancellable(future);
+        if let Some(sender) = &self.sender {
+            match sender.send(future) {
+                Ok(()) => return handle,
+                // The worker's thread is no longer running, which can only
+                // happen if a future panicked. Start a new thread.
+                Err(mpsc::SendError(unsent)) => future = unsent,
+            }
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        #[cfg(feature = "tokio")]
+        let runtime = tokio::runtime::Handle::try_current().ok();
+        std::thread::spawn(move || {
+            #[cfg(feature = "tokio")]
+            let _guard = runtime.as_ref().map(tokio::runtime::Handle::enter);
+            pollster::block_on(future);
+            while let Ok(future) = receiver.recv() {
+                pollster::block_on(future);
+            }
+        });
+        self.sender = Some(sender);
+        handle
+    }
+}
+
+#[derive(Debug, Default)]
+struct TaskState {
+    cancelled: bool,
+    complete: bool,
+    waker: Option<Waker>,
+}
+
+struct CancellableFuture {
+    future: Pin<Box<dyn Future<Output = ()> + Send>>,
+    state: Arc<Mutex<TaskState>>,
+}
+
+impl Future for CancellableFuture {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock();
+        if state.cancelled {
+            state.complete = true;
+            return Poll::Ready(());
+        }
+        state.waker = Some(cx.waker().clone());
+        drop(state);
+
+        let result = self.future.as_mut().poll(cx);
+        if result.is_ready() {
+            self.state.lock().complete = true;
+        }
+        result
+    }
+}
@@ -7,7 +7,7 @@ use std::future::Future;
 use std::hash::{BuildHasher, Hash};
 use std::ops::{Add, AddAssign, Deref, DerefMut, Not};
 use std::str::FromStr;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Weak};
 use std::task::{Poll, Waker};
 use std::thread::ThreadId;
@@ -24,7 +24,7 @@ use crate::context::{self, Trackable, WidgetContext};
 use crate::reactive::{
     defer_execute_callbacks, CallbackCollection, CallbackDisconnected, CallbackHandle,
     CallbackHandleData, CallbackHandleInner, CallbackKind, ChangeCallbacks, ChangeCallbacksData,
-    IntoOption,
+    IntoOption, TaskHandle, TaskWorker,
 };
 use crate::utils::WithClone;
 use crate::widget::{
@@ -375,7 +375,12 @@ pub trait Source<T> {
 
     /// Returns a new dynamic that contains the updated contents of this dynamic
     /// at most once every `period`.
+    ///
+    /// This is often referred to as throttling. The most recent value is
+    /// always stored once the current period elapses, even if updates stop
+    /// before the period ends.
     #[must_use]
+    #[doc(alias = "throttled")]
     fn debounced_every(&self, period: Duration) -> Dynamic<T>
     where
         T: PartialEq + Clone + Send + Sync + 'static,
@@ -390,7 +395,11 @@ pub trait Source<T> {
     /// Returns a new dynamic that contains the updated contents of this dynamic
     /// delayed by `period`. Each time this value is updated, the delay is
     /// reset.
+    ///
+    /// This is often referred to as debouncing: the value is only stored
+    /// once updates have settled for `period`.
     #[must_use]
+    #[doc(alias = "debounced")]
     fn debounced_with_delay(&self, period: Duration) -> Dynamic<T>
     where
         T: PartialEq + Clone + Send + Sync + 'static,
@@ -1271,6 +1280,32 @@ impl<T> Dynamic<T> {
         self.state::<true>().expect("deadlocked").readers
     }
 
+    /// Executes `future` in the background, storing its output in this
+    /// dynamic once it completes.
+    ///
+    /// The future is executed on its own thread. When the `tokio` feature is
+    /// enabled and this function is called within a tokio runtime, such as
+    /// from a Cushy application's callbacks, the runtime is entered while
+    /// executing `future`. Because the result is stored using
+    /// [`set()`](Destination::set), any widgets observing this dynamic will be
+    /// updated automatically.
+    ///
+    /// Dropping the returned handle cancels the future. If this dynamic is
+    /// freed before the future completes, its output is discarded.
+    pub fn set_from_future<F>(&self, future: F) -> TaskHandle
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: PartialEq + Send + 'static,
+    {
+        let target = self.downgrade();
+        TaskHandle::spawn(async move {
+            let value = future.await;
+            if let Some(target) = target.upgrade() {
+                target.set(value);
+            }
+        })
+    }
+
     /// Returns a new dynamic that has its contents linked with `self` by the
     /// pair of mapping functions provided.
     ///
@@ -1282,6 +1317,16 @@ impl<T> Dynamic<T> {
     /// accepts `&T` and can return `R` or `Option<R>`. If a value is produced,
     /// the returned dynamic will be updated with the new value.
     ///
+    /// When a conversion can fail, such as when parsing user input, return
+    /// `None` from `r_into_t` (e.g., by using [`Result::ok`]) to leave `self`
+    /// unchanged. [`linked_string`](Self::linked_string) uses this approach
+    /// with [`str::parse`]. To display the error to the user, validate the
+    /// returned dynamic using [`validate_with`](Self::validate_with).
+    ///
+    /// Because both dynamics only notify observers when their contents change,
+    /// an update that round-trips back to an equal value stops propagating
+    /// instead of looping between the two dynamics.
+    ///
     /// # Panics
     ///
     /// This function panics if calling `t_into_r` with the current contents of
@@ -1616,6 +1661,66 @@ impl<T> Dynamic<T> {
         validation.set_source(callback);
         validation
     }
+
+    /// Validates the contents of this dynamic using the future returned from
+    /// `check`, returning a dynamic that contains the validation status.
+    ///
+    /// Each time the contents change, `check` is invoked and the returned
+    /// future is executed on a background thread dedicated to this validation.
+    /// While the future is executing, the validation status is
+    /// [`Validation::Pending`]. If the contents change again before the future
+    /// completes, the in-flight validation is cancelled and its result is
+    /// ignored. Setting the result on the returned dynamic also invalidates any
+    /// widgets displaying it, causing their windows to redraw.
+    ///
+    /// This is useful for validations that require I/O, such as checking
+    /// whether a username is available.
+    #[must_use]
+    pub fn validate_async_with<E, Valid, Fut>(&self, mut check: Valid) -> Dynamic<Validation>
+    where
+        T: Send + 'static,
+        Valid: for<'a> FnMut(&'a T) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: IntoValue<MaybeLocalized>,
+    {
+        let validation = Dynamic::new(Validation::None);
+        let current_check = Arc::new(Mutex::new(0_usize));
+        let mut worker = TaskWorker::default();
+        let mut task: Option<TaskHandle> = None;
+        let callback = self.for_each({
+            let validation = validation.clone();
+            move |value| {
+                let future = check(value);
+                let this_check = {
+                    let mut current_check = current_check.lock();
+                    *current_check = current_check.wrapping_add(1);
+                    *current_check
+                };
+                validation.set(Validation::Pending);
+
+                let weak_validation = validation.downgrade();
+                let current_check = current_check.clone();
+                // Dropping the previous task's handle cancels it.
+                let _previous = task.replace(worker.spawn(async move {
+                    let result = match future.await {
+                        Ok(()) => Validation::Valid,
+                        Err(err) => Validation::Invalid(err.into_value()),
+                    };
+                    let Some(validation) = weak_validation.upgrade() else {
+                        return;
+                    };
+                    // Holding the lock ensures a newer check cannot begin
+                    // while this result is being stored.
+                    let current_check = current_check.lock();
+                    if *current_check == this_check {
+                        validation.set(result);
+                    }
+                }));
+            }
+        });
+        validation.set_source(callback);
+        validation
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -2666,6 +2771,94 @@ fn disconnecting_reader_async() {
     async_thread.join().unwrap();
 }
 
+#[test]
+fn set_from_future() {
+    let value = Dynamic::new(1);
+    let reader = value.create_reader();
+    let task = value.set_from_future(async { 2 });
+
+    assert!(reader.block_until_updated());
+    assert_eq!(reader.get(), 2);
+    drop(task);
+}
+
+#[test]
+fn validate_async_with() {
+    let text = Dynamic::new(String::new());
+    let validation = text.validate_async_with(|text: &String| {
+        let is_empty = text.is_empty();
+        async move {
+            if is_empty {
+                Err("empty")
+            } else {
+                Ok(())
+            }
+        }
+    });
+    let reader = validation.create_reader();
+
+    while !reader.get().is_error() {
+        assert!(reader.block_until_updated());
+    }
+
+    text.set(String::from("cushy"));
+    while reader.get() != Validation::Valid {
+        assert!(reader.block_until_updated());
+    }
+}
+
+#[test]
+fn validate_async_with_cancels_superseded_checks() {
+    let text = Dynamic::new(String::from("slow"));
+    let validation = text.validate_async_with(|text: &String| {
+        let slow = text == "slow";
+        async move {
+            if slow {
+                std::future::pending::<()>().await;
+            }
+            Ok::<(), &str>(())
+        }
+    });
+    let reader = validation.create_reader();
+    assert_eq!(reader.get(), Validation::Pending);
+
+    // The check that never completes must be cancelled for the next check to
+    // be executed by the validation's worker.
+    text.set(String::from("fast"));
+    while reader.get() != Validation::Valid {
+        assert!(reader.block_until_updated());
+    }
+}
+
+#[test]
+fn validations_aggregate() {
+    let text = Dynamic::new(String::new());
+    let validations = Validations::default();
+    let _validation = validations.validate(&text, |text: &String| {
+        if text.is_empty() {
+            Err("empty")
+        } else {
+            Ok(())
+        }
+    });
+    let valid = validations.valid().into_reader();
+    let errors = validations.errors().into_reader();
+
+    assert!(!valid.get());
+    assert_eq!(
+        errors.get(),
+        [Value::Constant(MaybeLocalized::from("empty"))]
+    );
+
+    text.set(String::from("cushy"));
+    while !valid.get() {
+        assert!(valid.block_until_updated());
+    }
+    while !errors.get().is_empty() {
+        assert!(errors.block_until_updated());
+    }
+}
+
 /// A tag that represents an individual revision of a [`Dynamic`] value.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct Generation(usize);
@@ -3413,6 +3606,36 @@ impl_all_tuples!(impl_tuple_for_each, 2);
 
 /// A type that can create a `Dynamic<U>` from a `T` passed into a mapping
 /// function.
+///
+/// This trait is implemented for tuples of references to sources, which
+/// allows creating a value computed from multiple dependencies. The mapping
+/// function is invoked again each time any of the sources in the tuple
+/// change:
+///
+/// ```rust
+/// use cushy::reactive::value::{Destination, Dynamic, MapEach, Source};
+///
+/// let name = Dynamic::new(String::new());
+/// let age = Dynamic::new(Some(42_u8));
+/// let submit_enabled = (&name, &age)
+///     .map_each(|(name, age)| !name.is_empty() && age.is_some())
+///     .into_reader();
+/// assert!(!submit_enabled.get());
+///
+/// name.set(String::from("Ferris"));
+/// submit_enabled.block_until_updated();
+/// assert!(submit_enabled.get());
+/// ```
+///
+/// Only the sources in the tuple are tracked. Reading another dynamic from
+/// within the mapping function does not cause the value to be recomputed when
+/// that dynamic changes.
+///
+/// The computed dynamic is updated using [`Destination::set`], which only
+/// notifies observers when the new value differs from the current value.
+/// Because of this, a cycle of dependencies stops propagating once the values
+/// involved stop changing. A cycle that produces a new value on every
+/// invocation will continue updating indefinitely.
 pub trait MapEach<T, U> {
     /// The borrowed representation of `T` passed into the mapping function.
     type Ref<'a>
@@ -3620,6 +3843,11 @@ pub enum Validation {
     /// errors should be delayed until it is changed.
     #[default]
     None,
+    /// The data is currently being validated.
+    ///
+    /// This status is used by asynchronous validations, such as
+    /// [`Dynamic::validate_async_with`], while the validation is in progress.
+    Pending,
     /// The data is valid.
     Valid,
     /// The data is invalid. The string contains a human-readable message.
@@ -3634,7 +3862,7 @@ impl Validation {
     #[must_use]
     pub fn message<'a>(&'a self, hint: &'a Value<MaybeLocalized>) -> &'a Value<MaybeLocalized> {
         match self {
-            Validation::None | Validation::Valid => hint,
+            Validation::None | Validation::Pending | Validation::Valid => hint,
             Validation::Invalid(err) => err,
         }
     }
@@ -3645,6 +3873,12 @@ impl Validation {
         matches!(self, Self::Invalid(_))
     }
 
+    /// Returns true if the validation is still in progress.
+    #[must_use]
+    pub const fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
+
     /// Returns the result of merging both validations.
     #[must_use]
     pub fn and(&self, other: &Self) -> Self {
@@ -3653,6 +3887,7 @@ impl Validation {
             (Validation::Invalid(error), _) | (_, Validation::Invalid(error)) => {
                 Validation::Invalid(error.clone())
             }
+            (Validation::Pending, _) | (_, Validation::Pending) => Validation::Pending,
             (Validation::None, _) | (_, Validation::None) => Validation::None,
         }
     }
@@ -3672,10 +3907,37 @@ where
 }
 
 /// A grouping of validations that can be checked simultaneously.
+///
+/// The combined status of all validations created through this type can be
+/// observed using [`valid()`](Self::valid) and [`errors()`](Self::errors). For
+/// example, a submit button can be disabled until a form is valid:
+///
+/// ```rust
+/// use cushy::reactive::value::{Dynamic, Validations};
+/// use cushy::widget::MakeWidget;
+/// use cushy::widgets::input::InputValue;
+///
+/// let name = Dynamic::new(String::new());
+/// let validations = Validations::default();
+///
+/// let form = name
+///     .to_input()
+///     .validation(validations.validate(&name, |name: &String| {
+///         if name.is_empty() {
+///             Err("a name is required")
+///         } else {
+///             Ok(())
+///         }
+///     }))
+///     .and("Submit".into_button().with_enabled(validations.valid()))
+///     .into_rows();
+/// ```
 #[derive(Debug, Default, Clone)]
 pub struct Validations {
     state: Dynamic<ValidationsState>,
     invalid: Dynamic<usize>,
+    errors: Dynamic<Map<usize, Value<MaybeLocalized>>>,
+    next_id: Arc<AtomicUsize>,
 }
 
 #[derive(Default, Debug, Eq, PartialEq, Clone)]
@@ -3773,13 +4035,16 @@ impl Validations {
     {
         self.invalid.map_mut(|mut invalid| *invalid += 1);
 
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
         let invalid_count = self.invalid.clone();
+        let errors = self.errors.clone();
         let dynamic = dynamic.clone();
         let mut initial_generation = dynamic.generation();
         let mut invalid = true;
 
         move |current_state, generational| {
-            let new_invalid = match (&current_state, &generational.value) {
+            let error = generational.value.map(IntoValue::into_value);
+            let new_invalid = match (&current_state, &error) {
                 (ValidationsState::Disabled, _) | (_, None) => false,
                 (_, Some(_)) => true,
             };
@@ -3791,8 +4056,24 @@ impl Validations {
                 }
                 invalid = new_invalid;
             }
-            let new_status = if let Some(err) = generational.value {
-                Validation::Invalid(err.into_value())
+            match (&error, new_invalid) {
+                (Some(error), true) => {
+                    if errors.map_ref(|errors| errors.get(&id) != Some(error)) {
+                        errors.map_mut(|mut errors| {
+                            errors.insert(id, error.clone());
+                        });
+                    }
+                }
+                _ => {
+                    if errors.map_ref(|errors| errors.get(&id).is_some()) {
+                        errors.map_mut(|mut errors| {
+                            errors.remove(&id);
+                        });
+                    }
+                }
+            }
+            let new_status = if let Some(err) = error {
+                Validation::Invalid(err)
             } else {
                 Validation::Valid
             };
@@ -3829,6 +4110,27 @@ impl Validations {
         }
     }
 
+    /// Returns a dynamic that contains `true` when all validations in this
+    /// set are valid.
+    ///
+    /// Unlike the individual validation statuses, this value reflects the
+    /// contents of each validated dynamic even before the user has changed
+    /// them.
+    #[must_use]
+    pub fn valid(&self) -> Dynamic<bool> {
+        self.invalid.map_each_cloned(|invalid| invalid == 0)
+    }
+
+    /// Returns a dynamic containing the error messages of all invalid
+    /// validations in this set.
+    ///
+    /// The messages are ordered by the order the validations were created in.
+    #[must_use]
+    pub fn errors(&self) -> Dynamic<Vec<Value<MaybeLocalized>>> {
+        self.errors
+            .map_each(|errors| errors.values().cloned().collect())
+    }
+
     /// Returns true if this set of validations are all valid.
     #[must_use]
     pub fn is_valid(&self) -> bool {
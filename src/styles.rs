@@ -1,4 +1,38 @@
 //! Types for styling widgets.
+//!
+//! # Custom components
+//!
+//! Applications and libraries can define their own style components using
+//! [`define_components!`](crate::define_components). Custom components
+//! participate in the style system exactly like the built-in components: they
+//! cascade from [`Style`](crate::widgets::Style) widgets to their contents,
+//! can be overridden by nested styles, and fall back to their default value
+//! when no value has been set. Defaults can be computed from the current
+//! theme or from other components.
+//!
+//! ```rust
+//! use cushy::define_components;
+//! use cushy::kludgine::Color;
+//! use cushy::widget::MakeWidget;
+//!
+//! define_components! {
+//!     MyApp {
+//!         /// The color used to highlight important content.
+//!         MyAccentColor(Color, "accent_color", .primary.color)
+//!     }
+//! }
+//!
+//! let panel = "Important".contain().with(&MyAccentColor, Color::RED);
+//! ```
+//!
+//! Widgets can then read the component's value using
+//! `context.get(&MyAccentColor)`.
+//!
+//! Any type that implements [`ComponentType`] can be used as the type of a
+//! component. To store a type that isn't supported by [`Component`], implement
+//! [`RequireInvalidation`], implement `From<T> for Component` using
+//! [`Component::custom`], and implement `TryFrom<Component>` by downcasting a
+//! [`Component::Custom`] value using [`CustomComponent::downcast`].
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -22,7 +56,7 @@ pub use kludgine::Color;
 pub use palette::OklabHue;
 use palette::{IntoColor, Okhsl, Srgb};
 
-use crate::animation::{EasingFunction, ZeroToOne};
+use crate::animation::{EasingFunction, LinearInterpolate, ZeroToOne};
 use crate::context::{Trackable, WidgetContext};
 use crate::names::Name;
 use crate::reactive::value::{Dynamic, IntoValue, Source, Value};
@@ -706,6 +740,32 @@ impl RequireInvalidation for Lp {
     }
 }
 
+impl From<i32> for Component {
+    fn from(value: i32) -> Self {
+        Component::custom(value)
+    }
+}
+
+impl TryFrom<Component> for i32 {
+    type Error = Component;
+
+    fn try_from(value: Component) -> Result<Self, Self::Error> {
+        match value {
+            Component::Custom(custom) => custom
+                .downcast()
+                .copied()
+                .ok_or_else(|| Component::Custom(custom)),
+            other => Err(other),
+        }
+    }
+}
+
+impl RequireInvalidation for i32 {
+    fn requires_invalidation(&self) -> bool {
+        false
+    }
+}
+
 impl<Unit> From<CornerRadii<Unit>> for Component
 where
     Dimension: From<Unit>,
@@ -1643,7 +1703,7 @@ where
 }
 
 /// A set of light and dark [`Theme`]s.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, LinearInterpolate)]
 pub struct ThemePair {
     /// The theme to use when the user interface is in light mode.
     pub light: Theme,
@@ -1710,7 +1770,7 @@ impl Default for ThemePair {
 }
 
 /// A Cushy Color theme.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, LinearInterpolate)]
 pub struct Theme {
     /// The primary color theme.
     pub primary: ColorTheme,
@@ -1766,7 +1826,7 @@ impl Theme {
 }
 
 /// A theme of surface colors.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, LinearInterpolate)]
 pub struct SurfaceTheme {
     /// The default background color.
     pub color: Color,
@@ -1844,7 +1904,7 @@ impl SurfaceTheme {
 }
 
 /// A pallete of a shared [`ColorSource`].
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, LinearInterpolate)]
 pub struct ColorTheme {
     /// The primary color, used for high-emphasis content.
     pub color: Color,
@@ -1889,7 +1949,7 @@ impl ColorTheme {
 }
 
 /// A theme of colors that is shared between light and dark theme variants.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, LinearInterpolate)]
 pub struct FixedTheme {
     /// An accent background color.
     pub color: Color,
@@ -2790,6 +2850,11 @@ pub enum HorizontalAlign {
     Center,
     /// Align the content to the right of the area provided.
     Right,
+    /// Stretch the spacing between words so that each wrapped line of text
+    /// fills the area provided.
+    ///
+    /// Content other than text is aligned to the left.
+    Justify,
 }
 
 impl HorizontalAlign {
@@ -2801,7 +2866,7 @@ impl HorizontalAlign {
         Unit::Representation: CastFrom<i32>,
     {
         match self {
-            Self::Left => Unit::ZERO,
+            Self::Left | Self::Justify => Unit::ZERO,
             Self::Center => (available_space - measured) * Unit::from_unscaled(2.cast_into()),
             Self::Right => available_space - measured,
         }
@@ -2957,3 +3022,48 @@ impl DynamicComponent {
         self.0.resolve_component(context)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use figures::units::Lp;
+
+    use super::{Component, Dimension, NamedComponent, Styles};
+    use crate::reactive::value::Value;
+    use crate::styles::components::TextSize;
+    use crate::tree::Tree;
+    use crate::widget::MakeWidget;
+    use crate::widgets::Space;
+
+    fn text_size(styles: &Styles) -> Option<Component> {
+        styles
+            .0
+            .components
+            .get(&TextSize.name())
+            .map(|stored| stored.component.get())
+    }
+
+    fn text_size_styles(points: i32) -> Value<Styles> {
+        Value::Constant(Styles::new().with(&TextSize, Dimension::Lp(Lp::points(points))))
+    }
+
+    #[test]
+    fn nested_styles_override_outer_styles() {
+        let tree = Tree::default();
+        let outer = tree.push_boxed(Space::clear().make_widget(), None);
+        let inner = tree.push_boxed(Space::clear().make_widget(), Some(&outer));
+        let nested = tree.push_boxed(Space::clear().make_widget(), Some(&inner));
+        let sibling = tree.push_boxed(Space::clear().make_widget(), Some(&outer));
+
+        tree.attach_styles(outer.node_id, text_size_styles(10));
+        tree.attach_styles(inner.node_id, text_size_styles(20));
+
+        assert_eq!(
+            text_size(&tree.effective_styles(nested.node_id)),
+            Some(Component::Dimension(Dimension::Lp(Lp::points(20))))
+        );
+        assert_eq!(
+            text_size(&tree.effective_styles(sibling.node_id)),
+            Some(Component::Dimension(Dimension::Lp(Lp::points(10))))
+        );
+    }
+}
@@ -179,6 +179,8 @@ define_components! {
         HighlightColor(Color,"highlight_color", .primary.color.with_alpha(128))
         /// A [`Color`] to be used as to indicate keyboard focus.
         FocusColor(Color,"focus_color", @HighlightColor)
+        /// The width of the focus ring drawn around focused widgets.
+        FocusWidth(Dimension,"focus_width", @OutlineWidth)
         /// The width of outlines drawn around widgets.
         OutlineWidth(Dimension,"outline_width", Dimension::Lp(Lp::points(1)))
         /// The primary color from the current theme.
@@ -234,6 +236,10 @@ define_components! {
         EasingOut(EasingFunction, "easing_out", EasingFunction::from(EaseOutQuadradic))
         /// The [`VisualOrder`] strategy to use when laying out content.
         LayoutOrder(VisualOrder, "visual_order", VisualOrder::left_to_right())
+        /// The order a widget is drawn relative to its siblings. Siblings with a
+        /// higher z-index are drawn above, and receive events before, siblings
+        /// with a lower z-index.
+        ZIndex(i32, "z_index", 0)
         /// The set of controls to allow focusing via tab key and initial focus
         /// selection.
         AutoFocusableControls(FocusableWidgets, "focus")
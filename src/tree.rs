@@ -4,15 +4,16 @@ use std::sync::{Arc, Weak};
 use ahash::AHashMap;
 use alot::{LotId, Lots};
 use figures::units::{Px, UPx};
-use figures::{Point, Rect, Size};
+use figures::{FloatConversion, Point, Rect, Size};
 use parking_lot::Mutex;
 #[cfg(feature = "localization")]
 use unic_langid::LanguageIdentifier;
 
+use crate::graphics::DrawTransform;
 use crate::reactive::value::Value;
-use crate::styles::{Styles, ThemePair, VisualOrder};
+use crate::styles::{CornerRadii, Styles, ThemePair, VisualOrder};
 use crate::widget::{MountedWidget, WidgetId, WidgetInstance};
-use crate::window::{ThemeMode, WindowHandle};
+use crate::window::{ThemeMode, WidgetLayout, WindowHandle};
 use crate::ConstraintLimit;
 
 #[derive(Clone, Default)]
@@ -100,6 +101,11 @@ impl Tree {
         data.nodes.get(widget).and_then(|widget| widget.layout)
     }
 
+    pub(crate) fn layout_tree(&self, widget: LotId) -> Option<WidgetLayout> {
+        let data = self.data.lock();
+        data.layout_tree(widget)
+    }
+
     pub(crate) fn new_frame(&self, invalidations: impl IntoIterator<Item = WidgetId>) {
         let mut data = self.data.lock();
         data.render_info.clear();
@@ -113,12 +119,46 @@ impl Tree {
         }
     }
 
-    pub(crate) fn note_widget_rendered(&self, widget: LotId) {
+    pub(crate) fn note_widget_rendered(&self, widget: LotId, transform: Option<DrawTransform>) {
         let mut data = self.data.lock();
         let Some(layout) = data.nodes.get(widget).and_then(|node| node.layout) else {
             return;
         };
-        data.render_info.push(widget, layout);
+        let inverse = match transform {
+            Some(transform) => {
+                let Some(inverse) = transform.inverse() else {
+                    // A widget that has been scaled to nothing can't be hit.
+                    return;
+                };
+                Some(inverse)
+            }
+            None => None,
+        };
+        data.render_info.push(widget, layout, inverse);
+    }
+
+    /// Returns the number of widgets that have been rendered this frame.
+    pub(crate) fn rendered_widget_count(&self) -> usize {
+        self.data.lock().render_info.order.len()
+    }
+
+    /// Restricts hit testing of every widget rendered after the first
+    /// `first_rendered` widgets this frame to `clip`.
+    pub(crate) fn clip_hit_testing(&self, first_rendered: usize, clip: HitClip) {
+        let mut data = self.data.lock();
+        data.render_info.clip(first_rendered, clip);
+    }
+
+    /// Returns `point` with the inverse of the transformation `widget` was last
+    /// rendered with applied.
+    pub(crate) fn untransform(&self, widget: LotId, point: Point<Px>) -> Point<Px> {
+        let data = self.data.lock();
+        data.render_info
+            .order
+            .iter()
+            .find(|area| area.node == widget)
+            .and_then(|area| area.inverse)
+            .map_or(point, |inverse| inverse.apply(point))
     }
 
     pub(crate) fn begin_layout(
@@ -287,6 +327,18 @@ impl Tree {
         data.widget_from_node(id, self)
     }
 
+    pub(crate) fn widgets(&self) -> Vec<MountedWidget> {
+        let data = self.data.lock();
+        data.nodes
+            .entries()
+            .map(|(node_id, node)| MountedWidget {
+                node_id,
+                widget: node.widget.clone(),
+                tree: WeakTree(Arc::downgrade(&self.data)),
+            })
+            .collect()
+    }
+
     pub(crate) fn is_enabled(&self, mut id: LotId, context: &WindowHandle) -> bool {
         let data = self.data.lock();
         loop {
@@ -345,6 +397,20 @@ impl Tree {
         self.data.lock().focus
     }
 
+    pub(crate) fn focus_is_visible(&self) -> bool {
+        !self.data.lock().pointer_interaction
+    }
+
+    /// Notes whether the user's most recent interaction was with a pointing
+    /// device rather than the keyboard. Returns true if the visibility of the
+    /// focus changed while a widget is focused.
+    pub(crate) fn note_pointer_interaction(&self, pointer: bool) -> bool {
+        let mut data = self.data.lock();
+        let changed = data.pointer_interaction != pointer;
+        data.pointer_interaction = pointer;
+        changed && data.focus.is_some()
+    }
+
     pub(crate) fn widgets_under_point(&self, point: Point<Px>) -> Vec<MountedWidget> {
         let data = self.data.lock();
         data.render_info.widgets_under_point(point, &data, self)
@@ -449,6 +515,7 @@ struct TreeData {
     escapes: Vec<LotId>,
     render_info: RenderInfo,
     previous_focuses: AHashMap<WidgetId, WidgetId>,
+    pointer_interaction: bool,
 }
 
 impl TreeData {
@@ -469,6 +536,19 @@ impl TreeData {
         })
     }
 
+    fn layout_tree(&self, id: LotId) -> Option<WidgetLayout> {
+        let node = self.nodes.get(id)?;
+        Some(WidgetLayout {
+            id: node.widget.id(),
+            layout: node.layout,
+            children: node
+                .children
+                .iter()
+                .filter_map(|child| self.layout_tree(*child))
+                .collect(),
+        })
+    }
+
     fn attach_styles(&mut self, id: LotId, styles_value: Value<Styles>) {
         let node = &mut self.nodes[id];
         node.associated_styles = Some(styles_value);
@@ -589,16 +669,49 @@ impl TreeData {
 #[derive(Default)]
 struct RenderInfo {
     order: Vec<RenderArea>,
+    clips: Vec<RenderClip>,
 }
 
 impl RenderInfo {
-    pub fn push(&mut self, node: LotId, region: Rect<Px>) {
-        let area = RenderArea::new(node, region);
+    pub fn push(&mut self, node: LotId, region: Rect<Px>, inverse: Option<DrawTransform>) {
+        let area = RenderArea::new(node, region, inverse);
         self.order.push(area);
     }
 
     pub fn clear(&mut self) {
         self.order.clear();
+        self.clips.clear();
+    }
+
+    fn clip(&mut self, first_rendered: usize, clip: HitClip) {
+        let clip_index = self.clips.len();
+        self.clips.push(RenderClip { clip, outer: None });
+        for area in self.order.iter_mut().skip(first_rendered) {
+            let Some(mut innermost) = area.clip else {
+                area.clip = Some(clip_index);
+                continue;
+            };
+
+            // This area was already clipped by a nested clip. Attach this clip
+            // to the outermost clip of the chain.
+            while let Some(outer) = self.clips[innermost].outer {
+                innermost = outer;
+            }
+            if innermost != clip_index {
+                self.clips[innermost].outer = Some(clip_index);
+            }
+        }
+    }
+
+    fn clip_contains(&self, mut clip: Option<usize>, point: Point<Px>) -> bool {
+        while let Some(index) = clip {
+            let render_clip = &self.clips[index];
+            if !render_clip.clip.contains(point) {
+                return false;
+            }
+            clip = render_clip.outer;
+        }
+        true
     }
 
     fn widgets_under_point(
@@ -613,10 +726,12 @@ impl RenderInfo {
         // amount of depth.
         let mut hits = Vec::with_capacity(self.order.len().min(256));
         for area in self.order.iter().rev() {
-            if area.min.x <= point.x
-                && area.min.y <= point.y
-                && area.max.x >= point.x
-                && area.max.y >= point.y
+            let local = area.inverse.map_or(point, |inverse| inverse.apply(point));
+            if area.min.x <= local.x
+                && area.min.y <= local.y
+                && area.max.x >= local.x
+                && area.max.y >= local.y
+                && self.clip_contains(area.clip, point)
             {
                 let Some(widget) = tree_data.widget_from_node(area.node, tree) else {
                     continue;
@@ -628,17 +743,82 @@ impl RenderInfo {
     }
 }
 
-#[derive(Eq, PartialEq, Clone, Copy)]
+#[derive(PartialEq, Clone, Copy)]
 struct RenderArea {
     node: LotId,
     min: Point<Px>,
     max: Point<Px>,
+    inverse: Option<DrawTransform>,
+    clip: Option<usize>,
 }
 
 impl RenderArea {
-    fn new(node: LotId, area: Rect<Px>) -> Self {
+    fn new(node: LotId, area: Rect<Px>, inverse: Option<DrawTransform>) -> Self {
         let (min, max) = area.saturating_extents();
-        Self { node, min, max }
+        Self {
+            node,
+            min,
+            max,
+            inverse,
+            clip: None,
+        }
+    }
+}
+
+struct RenderClip {
+    clip: HitClip,
+    outer: Option<usize>,
+}
+
+/// A region that restricts hit testing of the widgets rendered within it.
+#[derive(Clone, Copy)]
+pub(crate) struct HitClip {
+    /// The clipped region, in window coordinates.
+    pub region: Rect<Px>,
+    /// The radius of each corner of `region`.
+    pub corner_radii: CornerRadii<Px>,
+    /// The inverse of the transformation the clipping widget was drawn with.
+    pub inverse: Option<DrawTransform>,
+}
+
+impl HitClip {
+    fn contains(&self, point: Point<Px>) -> bool {
+        let point = self.inverse.map_or(point, |inverse| inverse.apply(point));
+        let (min, max) = self.region.saturating_extents();
+        if point.x < min.x || point.y < min.y || point.x > max.x || point.y > max.y {
+            return false;
+        }
+
+        let max_radius = self.region.size.width.min(self.region.size.height) / 2;
+        let radii = self.corner_radii.map(|radius| radius.min(max_radius));
+        let outside_corner = |radius: Px, center: Point<Px>, in_corner: bool| {
+            let x = (point.x - center.x).into_float();
+            let y = (point.y - center.y).into_float();
+            let radius = radius.into_float();
+            in_corner && x * x + y * y > radius * radius
+        };
+
+        let top_left = Point::new(min.x + radii.top_left, min.y + radii.top_left);
+        let top_right = Point::new(max.x - radii.top_right, min.y + radii.top_right);
+        let bottom_right = Point::new(max.x - radii.bottom_right, max.y - radii.bottom_right);
+        let bottom_left = Point::new(min.x + radii.bottom_left, max.y - radii.bottom_left);
+        !(outside_corner(
+            radii.top_left,
+            top_left,
+            point.x < top_left.x && point.y < top_left.y,
+        ) || outside_corner(
+            radii.top_right,
+            top_right,
+            point.x > top_right.x && point.y < top_right.y,
+        ) || outside_corner(
+            radii.bottom_right,
+            bottom_right,
+            point.x > bottom_right.x && point.y > bottom_right.y,
+        ) || outside_corner(
+            radii.bottom_left,
+            bottom_left,
+            point.x < bottom_left.x && point.y > bottom_left.y,
+        ))
     }
 }
 
@@ -683,3 +863,107 @@ impl WeakTree {
         self.0.upgrade().map(|data| Tree { data })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alot::Lots;
+    use figures::units::Px;
+    use figures::{Angle, Point, Rect, Size};
+
+    use super::{HitClip, RenderInfo};
+    use crate::graphics::DrawTransform;
+    use crate::styles::CornerRadii;
+
+    fn point(x: i32, y: i32) -> Point<Px> {
+        Point::new(Px::new(x), Px::new(y))
+    }
+
+    fn rect(x: i32, y: i32, width: i32, height: i32) -> Rect<Px> {
+        Rect::new(point(x, y), Size::new(Px::new(width), Px::new(height)))
+    }
+
+    fn clip(region: Rect<Px>, radius: i32) -> HitClip {
+        HitClip {
+            region,
+            corner_radii: CornerRadii::from(Px::new(radius)),
+            inverse: None,
+        }
+    }
+
+    #[test]
+    fn hit_clip_corners() {
+        let rounded = clip(rect(0, 0, 100, 50), 20);
+        // The extreme corner is outside of the rounded corner.
+        assert!(!rounded.contains(point(1, 1)));
+        assert!(!rounded.contains(point(99, 49)));
+        // Points along the edges, away from the corners, are inside.
+        assert!(rounded.contains(point(50, 0)));
+        assert!(rounded.contains(point(0, 25)));
+        // Points within the corner's inset but inside the curve are inside.
+        assert!(rounded.contains(point(8, 8)));
+        assert!(rounded.contains(point(92, 42)));
+        assert!(rounded.contains(point(50, 25)));
+        // Points outside of the region are never inside.
+        assert!(!rounded.contains(point(-1, 25)));
+        assert!(!rounded.contains(point(101, 25)));
+
+        // Without a radius, the corners are included.
+        assert!(clip(rect(0, 0, 100, 50), 0).contains(point(0, 0)));
+    }
+
+    #[test]
+    fn hit_clip_oversized_radius() {
+        // Radii larger than half of the shortest side are limited, producing
+        // a capsule.
+        let capsule = clip(rect(0, 0, 100, 20), 100);
+        assert!(capsule.contains(point(50, 0)));
+        assert!(capsule.contains(point(1, 10)));
+        assert!(!capsule.contains(point(2, 2)));
+    }
+
+    #[test]
+    fn hit_clip_transformed() {
+        let transform = DrawTransform::around(point(0, 0), point(100, 0), Angle::degrees_f(0.), 2.);
+        let scaled = HitClip {
+            inverse: transform.inverse(),
+            ..clip(rect(0, 0, 10, 10), 0)
+        };
+        // The clip was drawn at (100, 0) with a size of 20x20.
+        assert!(scaled.contains(point(119, 19)));
+        assert!(!scaled.contains(point(5, 5)));
+        assert!(!scaled.contains(point(125, 5)));
+    }
+
+    #[test]
+    fn nested_clips_chain() {
+        let mut nodes = Lots::new();
+        let node = nodes.push(());
+        let mut info = RenderInfo::default();
+
+        // An outer clip contains one widget followed by two sibling clips,
+        // each containing one widget.
+        info.push(node, rect(0, 0, 100, 100), None);
+        info.push(node, rect(50, 0, 100, 100), None);
+        info.clip(1, clip(rect(50, 0, 100, 100), 0));
+        info.push(node, rect(0, 50, 100, 100), None);
+        info.clip(2, clip(rect(0, 50, 100, 100), 0));
+        info.clip(0, clip(rect(0, 0, 100, 100), 0));
+
+        let [outer, first, second] = [0, 1, 2].map(|index| info.order[index].clip);
+        assert_eq!(outer, Some(2));
+        assert_eq!(first, Some(0));
+        assert_eq!(second, Some(1));
+        assert_eq!(info.clips[0].outer, Some(2));
+        assert_eq!(info.clips[1].outer, Some(2));
+        assert_eq!(info.clips[2].outer, None);
+
+        // Points must be within every clip of the chain.
+        assert!(info.clip_contains(first, point(75, 25)));
+        assert!(!info.clip_contains(first, point(125, 25)));
+        assert!(!info.clip_contains(first, point(25, 25)));
+        assert!(info.clip_contains(second, point(25, 75)));
+        assert!(!info.clip_contains(second, point(25, 125)));
+        assert!(info.clip_contains(outer, point(25, 25)));
+        assert!(info.clip_contains(None, point(500, 500)));
+    }
+}
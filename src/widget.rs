@@ -3,14 +3,17 @@
 use std::any::Any;
 use std::clone::Clone;
 use std::fmt::{self, Debug};
+use std::hash::Hash;
 use std::ops::{ControlFlow, Deref, DerefMut};
+use std::path::PathBuf;
 use std::sync::atomic::{self, AtomicU64};
 use std::sync::Arc;
 use std::{slice, vec};
 
+use ahash::AHashMap;
 use alot::LotId;
 use figures::units::{Px, UPx};
-use figures::{IntoSigned, IntoUnsigned, Point, Rect, Size, Zero};
+use figures::{Fraction, IntoSigned, IntoUnsigned, Point, Rect, Size, Zero};
 use intentional::Assert;
 use kludgine::app::winit::event::{Ime, MouseButton, MouseScrollDelta, TouchPhase};
 use kludgine::app::winit::keyboard::ModifiersState;
@@ -20,6 +23,7 @@ use parking_lot::{Mutex, MutexGuard};
 #[cfg(feature = "localization")]
 use unic_langid::LanguageIdentifier;
 
+use crate::animation::ZeroToOne;
 use crate::app::Run;
 use crate::context::sealed::Trackable as _;
 use crate::context::{
@@ -29,7 +33,7 @@ use crate::reactive::channel::{BroadcastChannel, Broadcaster, Sender};
 use crate::reactive::value::{
     Dynamic, Generation, IntoDynamic, IntoValue, Source, Validation, Value,
 };
-use crate::styles::components::{HorizontalAlignment, IntrinsicPadding, VerticalAlignment};
+use crate::styles::components::{HorizontalAlignment, IntrinsicPadding, VerticalAlignment, ZIndex};
 use crate::styles::{
     ComponentDefinition, ContainerLevel, ContextFreeComponent, Dimension, DimensionRange, Edges,
     FlexibleDimension, HorizontalAlign, IntoComponentValue, IntoDynamicComponentValue, Styles,
@@ -39,16 +43,17 @@ use crate::tree::{Tree, WeakTree};
 use crate::widgets::checkbox::{Checkable, CheckboxState};
 use crate::widgets::layers::{OverlayLayer, Tooltipped};
 use crate::widgets::list::List;
+use crate::widgets::menu::{ContextMenu, Menu};
 use crate::widgets::shortcuts::{ShortcutKey, Shortcuts};
 #[cfg(feature = "localization")]
 use crate::widgets::Localized;
 use crate::widgets::{
-    Align, Button, Checkbox, Collapse, Container, Disclose, Expand, Layers, Resize, Scroll, Space,
-    Stack, Style, Themed, ThemedMode, Validated, Wrap,
+    Align, Button, Checkbox, Clip, Collapse, Container, Disclose, Expand, Layers, Opacity, Padding,
+    Resize, Scroll, Space, Stack, Style, Themed, ThemedMode, Validated, Wrap,
 };
 use crate::window::sealed::WindowCommand;
 use crate::window::{
-    DeviceId, KeyEvent, MakeWindow, Rgb8, RunningWindow, StandaloneWindowBuilder, ThemeMode,
+    DeviceId, KeyEvent, MakeWindow, Rgb8, RunningWindow, StandaloneWindowBuilder, ThemeMode, Touch,
     VirtualRecorderBuilder, Window, WindowBehavior, WindowHandle, WindowLocal,
 };
 use crate::ConstraintLimit;
@@ -175,7 +180,9 @@ use crate::ConstraintLimit;
 /// As the cursor moves across the window, the window will look at the render
 /// information to see what widgets are positioned under the cursor and the
 /// order in which they were drawn. Beginning at the topmost widget,
-/// [`Widget::hit_test`] is called on each widget.
+/// [`Widget::hit_test`] is called on each widget. The first widget to return
+/// true becomes the hovered widget, which means a widget that returns false
+/// passes the cursor through to the widgets drawn beneath it.
 ///
 /// The currently hovered widget state is tracked for events that target widgets
 /// beneath the current cursor.
@@ -330,6 +337,12 @@ pub trait Widget: Send + Debug + 'static {
     /// - [`Self::mouse_drag`]
     /// - [`Self::mouse_wheel`]
     ///
+    /// `location` is relative to this widget's layout origin. Widgets whose
+    /// visible shape does not fill their entire layout rectangle should return
+    /// false for points outside of the shape. When false is returned, the
+    /// event is offered to the next widget beneath the cursor, allowing
+    /// widgets behind this one to be hovered and clicked.
+    ///
     /// See [Hover State: Hit Testing](Self#hover-state-hit-testing) for an
     /// explanation of how these events work together.
     #[allow(unused_variables)]
@@ -489,6 +502,72 @@ pub trait Widget: Send + Debug + 'static {
         IGNORED
     }
 
+    /// A touch event has been sent to this widget. Returns whether the event
+    /// has been handled or not.
+    ///
+    /// When a touch begins, the widget beneath the touch's location is found
+    /// using [`Self::hit_test`], and the event is bubbled up the hierarchy
+    /// until a widget handles it. The widget that handled the
+    /// [`TouchPhase::Started`] event will receive all remaining events for that
+    /// finger.
+    ///
+    /// [`Touch::location`] is relative to this widget's origin.
+    #[allow(unused_variables)]
+    fn touch(&mut self, touch: Touch, context: &mut EventContext<'_>) -> EventHandling {
+        IGNORED
+    }
+
+    /// A file is being dragged over this widget. Returns whether the event has
+    /// been handled or not.
+    ///
+    /// This event is first sent to the widget beneath the cursor and bubbles up
+    /// to its parents until it is handled. The widget that handles this event
+    /// will receive [`Widget::hovered_file_cancelled`] if the file leaves the
+    /// window or is dropped onto another widget.
+    #[allow(unused_variables)]
+    fn hovered_file(&mut self, path: PathBuf, context: &mut EventContext<'_>) -> EventHandling {
+        IGNORED
+    }
+
+    /// A file that was previously reported to [`Widget::hovered_file`] is no
+    /// longer being dragged over this widget.
+    #[allow(unused_variables)]
+    fn hovered_file_cancelled(&mut self, context: &mut EventContext<'_>) {}
+
+    /// A file has been dropped on this widget. Returns whether the event has
+    /// been handled or not.
+    ///
+    /// This event is first sent to the widget beneath the cursor and bubbles up
+    /// to its parents until it is handled.
+    #[allow(unused_variables)]
+    fn dropped_file(&mut self, path: PathBuf, context: &mut EventContext<'_>) -> EventHandling {
+        IGNORED
+    }
+
+    /// The window containing this widget has been resized to `new_size`.
+    ///
+    /// This event is delivered to every widget in the window before the next
+    /// frame is laid out.
+    #[allow(unused_variables)]
+    fn window_resized(&mut self, new_size: Size<UPx>, context: &mut EventContext<'_>) {}
+
+    /// The DPI scale of the window containing this widget has changed to
+    /// `scale`.
+    ///
+    /// This event is delivered to every widget in the window before the next
+    /// frame is laid out. Widgets that cache resources rendered at a specific
+    /// scale should use this event to invalidate those caches.
+    #[allow(unused_variables)]
+    fn scale_factor_changed(&mut self, scale: Fraction, context: &mut EventContext<'_>) {}
+
+    /// The theme of the window containing this widget has changed.
+    ///
+    /// This event is also delivered once immediately after a widget is
+    /// mounted, allowing widgets that cache values derived from the theme to
+    /// compute them in a single location.
+    #[allow(unused_variables)]
+    fn theme_changed(&mut self, context: &mut EventContext<'_>) {}
+
     /// Returns a reference to a single child widget if this widget is a widget
     /// that primarily wraps a single other widget to customize its behavior.
     #[must_use]
@@ -705,6 +784,9 @@ pub trait WrapperWidget: Debug + Send + 'static {
     }
 
     /// Returns true if this widget should respond to mouse input at `location`.
+    ///
+    /// When false is returned, the event is offered to the next widget beneath
+    /// the cursor.
     #[allow(unused_variables)]
     fn hit_test(&mut self, location: Point<Px>, context: &mut EventContext<'_>) -> bool {
         false
@@ -837,6 +919,48 @@ pub trait WrapperWidget: Debug + Send + 'static {
     ) -> EventHandling {
         IGNORED
     }
+
+    /// A touch event has been sent to this widget. Returns whether the event
+    /// has been handled or not.
+    #[allow(unused_variables)]
+    fn touch(&mut self, touch: Touch, context: &mut EventContext<'_>) -> EventHandling {
+        IGNORED
+    }
+
+    /// A file is being dragged over this widget. Returns whether the event has
+    /// been handled or not.
+    #[allow(unused_variables)]
+    fn hovered_file(&mut self, path: PathBuf, context: &mut EventContext<'_>) -> EventHandling {
+        IGNORED
+    }
+
+    /// A file that was previously reported to [`Widget::hovered_file`] is no
+    /// longer being dragged over this widget.
+    #[allow(unused_variables)]
+    fn hovered_file_cancelled(&mut self, context: &mut EventContext<'_>) {}
+
+    /// A file has been dropped on this widget. Returns whether the event has
+    /// been handled or not.
+    #[allow(unused_variables)]
+    fn dropped_file(&mut self, path: PathBuf, context: &mut EventContext<'_>) -> EventHandling {
+        IGNORED
+    }
+
+    /// The window containing this widget has been resized to `new_size`.
+    #[allow(unused_variables)]
+    fn window_resized(&mut self, new_size: Size<UPx>, context: &mut EventContext<'_>) {}
+
+    /// The DPI scale of the window containing this widget has changed to
+    /// `scale`.
+    #[allow(unused_variables)]
+    fn scale_factor_changed(&mut self, scale: Fraction, context: &mut EventContext<'_>) {}
+
+    /// The theme of the window containing this widget has changed.
+    ///
+    /// This event is also delivered once immediately after a widget is
+    /// mounted.
+    #[allow(unused_variables)]
+    fn theme_changed(&mut self, context: &mut EventContext<'_>) {}
 }
 
 impl<T> Widget for T
@@ -970,6 +1094,34 @@ where
         T::mouse_wheel(self, device_id, delta, phase, context)
     }
 
+    fn touch(&mut self, touch: Touch, context: &mut EventContext<'_>) -> EventHandling {
+        T::touch(self, touch, context)
+    }
+
+    fn hovered_file(&mut self, path: PathBuf, context: &mut EventContext<'_>) -> EventHandling {
+        T::hovered_file(self, path, context)
+    }
+
+    fn hovered_file_cancelled(&mut self, context: &mut EventContext<'_>) {
+        T::hovered_file_cancelled(self, context);
+    }
+
+    fn dropped_file(&mut self, path: PathBuf, context: &mut EventContext<'_>) -> EventHandling {
+        T::dropped_file(self, path, context)
+    }
+
+    fn window_resized(&mut self, new_size: Size<UPx>, context: &mut EventContext<'_>) {
+        T::window_resized(self, new_size, context);
+    }
+
+    fn scale_factor_changed(&mut self, scale: Fraction, context: &mut EventContext<'_>) {
+        T::scale_factor_changed(self, scale, context);
+    }
+
+    fn theme_changed(&mut self, context: &mut EventContext<'_>) {
+        T::theme_changed(self, context);
+    }
+
     fn advance_focus(
         &mut self,
         direction: VisualOrder,
@@ -1336,6 +1488,14 @@ pub trait MakeWidget: Sized {
         Resize::from_height(height, self)
     }
 
+    /// Constrains `self` to a ratio of `width` to `height`.
+    ///
+    /// See [`Resize::aspect_ratio`] for more information.
+    #[must_use]
+    fn aspect_ratio(self, width: f32, height: f32) -> Resize {
+        Resize::from_width(.., self).aspect_ratio(width, height)
+    }
+
     /// Returns this widget as the contents of a clickable button.
     fn into_button(self) -> Button {
         Button::new(self)
@@ -1447,6 +1607,14 @@ pub trait MakeWidget: Sized {
         self.contain().transparent().pad_by(padding)
     }
 
+    /// Surrounds `self` with `padding` without drawing anything.
+    ///
+    /// Unlike [`pad_by()`](Self::pad_by), this does not wrap `self` in a
+    /// [`Container`].
+    fn with_padding(self, padding: impl IntoValue<Edges<Dimension>>) -> Padding {
+        Padding::new(padding, self)
+    }
+
     /// Applies `theme` to `self` and its children.
     fn themed(self, theme: impl IntoValue<ThemePair>) -> Themed {
         Themed::new(theme, self)
@@ -1480,6 +1648,25 @@ pub trait MakeWidget: Sized {
         Collapse::vertical(collapse_when, self)
     }
 
+    /// Returns a widget that clips `self` to its bounds.
+    fn clipped(self) -> Clip {
+        Clip::new(self)
+    }
+
+    /// Returns a widget that draws `self` using `opacity`.
+    fn with_opacity(self, opacity: impl IntoValue<ZeroToOne>) -> Opacity {
+        Opacity::new(opacity, self)
+    }
+
+    /// Styles `self` to be drawn in order of `z_index` relative to its
+    /// siblings.
+    ///
+    /// The z-index is applied as a local component, so it is not inherited
+    /// by the children of `self`. See [`ZIndex`] for more information.
+    fn z_index(self, z_index: impl IntoValue<i32>) -> Style {
+        self.with_local(&ZIndex, z_index)
+    }
+
     /// Returns a new widget that allows hiding and showing `contents`.
     fn disclose(self) -> Disclose {
         Disclose::new(self)
@@ -1494,6 +1681,12 @@ pub trait MakeWidget: Sized {
     fn tooltip(self, layer: &OverlayLayer, tip: impl MakeWidget) -> Tooltipped {
         layer.new_tooltip(tip, self)
     }
+
+    /// Returns a widget that shows `menu` on `layer` when `self` is
+    /// right-clicked.
+    fn context_menu<T>(self, layer: &OverlayLayer, menu: Menu<T>) -> ContextMenu<T> {
+        ContextMenu::new(self, menu, layer)
+    }
 }
 
 /// A type that can create a [`WidgetInstance`] with a preallocated
@@ -2631,6 +2824,62 @@ impl Dynamic<WidgetList> {
     }
 }
 
+impl<T> Dynamic<Vec<T>>
+where
+    T: Send + 'static,
+{
+    /// Returns a list of widgets that contains a widget for each item in
+    /// `self`, updating as the items change.
+    ///
+    /// `key` returns a unique, stable identifier for an item. When the items
+    /// change, the widget created for an item whose key was already present is
+    /// reused rather than recreated. Because [`Stack`], [`Layers`], and other
+    /// multi-child widgets synchronize their children by widget identity,
+    /// reordering, inserting, or removing items only mounts/unmounts the
+    /// affected rows, and the remaining rows keep their state, including
+    /// focus.
+    ///
+    /// `make_row` is only invoked for items with a new key. A reused row is
+    /// not rebuilt when its item changes, so rows whose contents need to
+    /// update should observe reactive values themselves.
+    ///
+    /// ```rust
+    /// use cushy::reactive::value::Dynamic;
+    ///
+    /// let todos = Dynamic::new(vec![(1, "Write docs"), (2, "Ship it")]);
+    /// let rows = todos
+    ///     .map_each_keyed(|(id, _)| *id, |(_, title)| *title)
+    ///     .into_rows();
+    /// ```
+    pub fn map_each_keyed<Key, GetKey, MakeRow, Row>(
+        &self,
+        mut key: GetKey,
+        mut make_row: MakeRow,
+    ) -> Dynamic<WidgetList>
+    where
+        Key: Hash + Eq + Send + 'static,
+        GetKey: FnMut(&T) -> Key + Send + 'static,
+        MakeRow: FnMut(&T) -> Row + Send + 'static,
+        Row: MakeWidget,
+    {
+        let mut rows = AHashMap::<Key, WidgetInstance>::new();
+        self.map_each(move |items: &Vec<T>| {
+            let mut previous = std::mem::take(&mut rows);
+            items
+                .iter()
+                .map(|item| {
+                    let key = key(item);
+                    let row = previous
+                        .remove(&key)
+                        .unwrap_or_else(|| make_row(item).make_widget());
+                    rows.insert(key, row.clone());
+                    row
+                })
+                .collect()
+        })
+    }
+}
+
 impl FromIterator<WidgetList> for WidgetList {
     fn from_iter<T: IntoIterator<Item = WidgetList>>(iter: T) -> Self {
         let mut iter = iter.into_iter();
@@ -3063,3 +3312,43 @@ impl Deref for WidgetTag {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{WidgetId, WidgetInstance, WidgetList};
+    use crate::reactive::value::{Destination, Dynamic, Source};
+
+    fn row_ids(rows: &Dynamic<WidgetList>) -> Vec<WidgetId> {
+        rows.map_ref(|rows| rows.iter().map(WidgetInstance::id).collect())
+    }
+
+    #[test]
+    fn map_each_keyed_reuses_rows() {
+        let items = Dynamic::new(vec![(1, "a"), (2, "b"), (3, "c")]);
+        let rows = items.map_each_keyed(|(id, _)| *id, |(_, label)| *label);
+        let initial = row_ids(&rows);
+
+        // Reordering keeps every row.
+        items.set(vec![(3, "c"), (1, "a"), (2, "b")]);
+        assert_eq!(row_ids(&rows), [initial[2], initial[0], initial[1]]);
+
+        // Inserting only creates a row for the new key.
+        items.set(vec![(3, "c"), (4, "d"), (1, "a"), (2, "b")]);
+        let inserted = row_ids(&rows);
+        assert_eq!(
+            [inserted[0], inserted[2], inserted[3]],
+            [initial[2], initial[0], initial[1]]
+        );
+        assert!(!initial.contains(&inserted[1]));
+
+        // Removing keeps the remaining rows.
+        items.set(vec![(4, "d"), (2, "b")]);
+        assert_eq!(row_ids(&rows), [inserted[1], initial[1]]);
+
+        // A key that was removed gets a new row when it returns.
+        items.set(vec![(4, "d"), (2, "b"), (1, "a")]);
+        let restored = row_ids(&rows);
+        assert_eq!(restored[..2], [inserted[1], initial[1]]);
+        assert_ne!(restored[2], initial[0]);
+    }
+}
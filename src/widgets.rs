@@ -4,6 +4,7 @@ mod align;
 pub mod button;
 mod canvas;
 pub mod checkbox;
+mod clip;
 mod collapse;
 pub mod color;
 mod component_probe;
@@ -12,6 +13,7 @@ mod custom;
 mod data;
 pub mod delimiter;
 pub mod disclose;
+mod drag_window;
 mod expand;
 pub mod grid;
 pub mod image;
@@ -24,20 +26,29 @@ pub mod list;
 mod localized;
 pub mod menu;
 mod mode_switch;
+pub mod number_input;
+mod opacity;
+mod padding;
 pub mod pile;
 pub mod progress;
 pub mod radio;
 mod resize;
+pub mod rich_text;
 pub mod scroll;
 pub mod select;
 pub mod shortcuts;
 pub mod slider;
 mod space;
+pub mod split;
 pub mod stack;
 mod style;
+#[cfg(feature = "svg")]
+mod svg;
 mod switcher;
+pub mod tabs;
 mod themed;
 mod tilemap;
+mod transform;
 pub mod validated;
 mod virtual_list;
 pub mod wrap;
@@ -47,6 +58,7 @@ pub use self::align::Align;
 pub use self::button::Button;
 pub use self::canvas::Canvas;
 pub use self::checkbox::Checkbox;
+pub use self::clip::Clip;
 pub use self::collapse::Collapse;
 pub use self::component_probe::ComponentProbe;
 pub use self::container::Container;
@@ -54,6 +66,7 @@ pub use self::custom::Custom;
 pub use self::data::Data;
 pub use self::delimiter::Delimiter;
 pub use self::disclose::Disclose;
+pub use self::drag_window::DragWindow;
 pub use self::expand::Expand;
 pub use self::grid::Grid;
 pub use self::image::Image;
@@ -64,18 +77,27 @@ pub use self::layers::Layers;
 pub use self::localized::Localized;
 pub use self::menu::Menu;
 pub use self::mode_switch::ThemedMode;
+pub use self::number_input::NumberInput;
+pub use self::opacity::Opacity;
+pub use self::padding::Padding;
 pub use self::progress::ProgressBar;
 pub use self::radio::Radio;
 pub use self::resize::Resize;
+pub use self::rich_text::{RichLabel, RichText};
 pub use self::scroll::Scroll;
-pub use self::select::Select;
+pub use self::select::{Select, SelectGroup, ToggleButton};
 pub use self::slider::Slider;
 pub use self::space::Space;
+pub use self::split::Split;
 pub use self::stack::Stack;
 pub use self::style::Style;
-pub use self::switcher::Switcher;
+#[cfg(feature = "svg")]
+pub use self::svg::Svg;
+pub use self::switcher::{Switcher, SwitcherTransition};
+pub use self::tabs::Tabs;
 pub use self::themed::Themed;
-pub use self::tilemap::TileMap;
+pub use self::tilemap::{TileCoord, TileMap, TileMapCamera, TilePress};
+pub use self::transform::Transform;
 pub use self::validated::Validated;
 pub use self::virtual_list::VirtualList;
 pub use self::wrap::Wrap;
@@ -10,6 +10,24 @@ use crate::widget::{MakeWidget, RootBehavior, WidgetRef, WrappedLayout, WrapperW
 use crate::ConstraintLimit;
 
 /// A widget aligns its contents to its container's boundaries.
+///
+/// Each edge of the child is either positioned at a fixed distance from this
+/// widget's corresponding edge, or is [`FlexibleDimension::Auto`]. Along an
+/// axis where at least one edge is `Auto`, the child is given its preferred
+/// size and is positioned using the remaining space: when both edges are
+/// `Auto` the child is centered, and when one edge is `Auto` the child is
+/// pushed against the opposite edge. When neither edge is `Auto`, the child
+/// is stretched to fill the space between the edges.
+///
+/// ```rust
+/// use cushy::widget::MakeWidget;
+/// use cushy::widgets::Align;
+///
+/// // Aligned in both axes using one of the nine positions.
+/// let corner = Align::bottom_right("Status");
+/// // Aligned horizontally and stretched vertically.
+/// let sidebar = Align::left("Sidebar").fit_vertically();
+/// ```
 #[derive(Debug)]
 pub struct Align {
     child: WidgetRef,
@@ -32,6 +50,76 @@ impl Align {
         Self::new(FlexibleDimension::Auto, widget)
     }
 
+    /// Returns a new spacing widget that aligns `widget` to the top-left
+    /// corner.
+    pub fn top_left(widget: impl MakeWidget) -> Self {
+        Self::centered(widget).align_top().align_left()
+    }
+
+    /// Returns a new spacing widget that aligns `widget` to the top edge,
+    /// centered horizontally.
+    pub fn top(widget: impl MakeWidget) -> Self {
+        Self::centered(widget).align_top()
+    }
+
+    /// Returns a new spacing widget that aligns `widget` to the top-right
+    /// corner.
+    pub fn top_right(widget: impl MakeWidget) -> Self {
+        Self::centered(widget).align_top().align_right()
+    }
+
+    /// Returns a new spacing widget that aligns `widget` to the left edge,
+    /// centered vertically.
+    pub fn left(widget: impl MakeWidget) -> Self {
+        Self::centered(widget).align_left()
+    }
+
+    /// Returns a new spacing widget that aligns `widget` to the right edge,
+    /// centered vertically.
+    pub fn right(widget: impl MakeWidget) -> Self {
+        Self::centered(widget).align_right()
+    }
+
+    /// Returns a new spacing widget that aligns `widget` to the bottom-left
+    /// corner.
+    pub fn bottom_left(widget: impl MakeWidget) -> Self {
+        Self::centered(widget).align_bottom().align_left()
+    }
+
+    /// Returns a new spacing widget that aligns `widget` to the bottom edge,
+    /// centered horizontally.
+    pub fn bottom(widget: impl MakeWidget) -> Self {
+        Self::centered(widget).align_bottom()
+    }
+
+    /// Returns a new spacing widget that aligns `widget` to the bottom-right
+    /// corner.
+    pub fn bottom_right(widget: impl MakeWidget) -> Self {
+        Self::centered(widget).align_bottom().align_right()
+    }
+
+    /// Centers the child horizontally, giving it its preferred width, and
+    /// returns self.
+    #[must_use]
+    pub fn center_horizontally(mut self) -> Self {
+        self.edges.map_mut(|mut edges| {
+            edges.left = FlexibleDimension::Auto;
+            edges.right = FlexibleDimension::Auto;
+        });
+        self
+    }
+
+    /// Centers the child vertically, giving it its preferred height, and
+    /// returns self.
+    #[must_use]
+    pub fn center_vertically(mut self) -> Self {
+        self.edges.map_mut(|mut edges| {
+            edges.top = FlexibleDimension::Auto;
+            edges.bottom = FlexibleDimension::Auto;
+        });
+        self
+    }
+
     /// Sets the left edge of alignment to 0 and returns self.
     #[must_use]
     pub fn align_left(mut self) -> Self {
@@ -211,3 +299,54 @@ struct Layout {
     margin: Edges<UPx>,
     content: Size<UPx>,
 }
+
+#[cfg(test)]
+mod tests {
+    use figures::units::UPx;
+
+    use super::FrameInfo;
+    use crate::ConstraintLimit;
+
+    #[test]
+    fn bottom_right() {
+        // An Align::bottom_right has an auto top/left edge and a zero
+        // bottom/right edge.
+        let frame = FrameInfo {
+            a: None,
+            b: Some(UPx::new(0)),
+        };
+        let available = ConstraintLimit::Fill(UPx::new(400));
+        assert_eq!(
+            frame.child_constraint(available),
+            ConstraintLimit::SizeToFit(UPx::new(400))
+        );
+
+        let (leading, trailing, size) = frame.measure(available, UPx::new(100));
+        assert_eq!(leading, UPx::new(300));
+        assert_eq!(trailing, UPx::new(0));
+        assert_eq!(size, UPx::new(100));
+    }
+
+    #[test]
+    fn centered_keeps_preferred_size() {
+        let frame = FrameInfo { a: None, b: None };
+        let (leading, trailing, size) =
+            frame.measure(ConstraintLimit::Fill(UPx::new(400)), UPx::new(101));
+        assert_eq!(leading, UPx::new(149));
+        assert_eq!(trailing, UPx::new(150));
+        assert_eq!(size, UPx::new(101));
+    }
+
+    #[test]
+    fn fit_stretches() {
+        let frame = FrameInfo {
+            a: Some(UPx::new(0)),
+            b: Some(UPx::new(0)),
+        };
+        let (leading, trailing, size) =
+            frame.measure(ConstraintLimit::Fill(UPx::new(400)), UPx::new(100));
+        assert_eq!(leading, UPx::new(0));
+        assert_eq!(trailing, UPx::new(0));
+        assert_eq!(size, UPx::new(400));
+    }
+}
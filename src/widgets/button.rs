@@ -9,7 +9,7 @@ use kludgine::shapes::{Shape, StrokeOptions};
 use kludgine::Color;
 
 use crate::animation::{
-    AnimationHandle, AnimationTarget, IntoAnimate, LinearInterpolate, Spawn, ZeroToOne,
+    self, AnimationHandle, AnimationTarget, IntoAnimate, LinearInterpolate, Spawn, ZeroToOne,
 };
 use crate::context::{AsEventContext, EventContext, GraphicsContext, LayoutContext, WidgetContext};
 use crate::reactive::value::{Destination, Dynamic, IntoValue, Source, Value};
@@ -17,17 +17,28 @@ use crate::styles::components::{
     AutoFocusableControls, CornerRadius, DefaultActiveBackgroundColor,
     DefaultActiveForegroundColor, DefaultBackgroundColor, DefaultDisabledBackgroundColor,
     DefaultDisabledForegroundColor, DefaultForegroundColor, DefaultHoveredBackgroundColor,
-    DefaultHoveredForegroundColor, Easing, HighlightColor, IntrinsicPadding, OpaqueWidgetColor,
+    DefaultHoveredForegroundColor, Easing, FocusColor, IntrinsicPadding, OpaqueWidgetColor,
     OutlineColor, OutlineWidth, SurfaceColor, TextColor,
 };
 use crate::styles::{ColorExt, Styles};
 use crate::widget::{
     EventHandling, MakeWidget, Notify, SharedCallback, Widget, WidgetRef, HANDLED,
 };
+use crate::widgets::layers::{OverlayLayer, Tooltipped};
+use crate::widgets::Label;
 use crate::window::{DeviceId, WindowLocal};
 use crate::FitMeasuredSize;
 
 /// A clickable button.
+///
+/// # Default and escape buttons
+///
+/// A button can be made the window's default action using
+/// [`MakeWidget::into_default`] or its escape action using
+/// [`MakeWidget::into_escape`]. Pressing Enter or Escape activates the
+/// corresponding button, which is drawn in its active state until the key is
+/// released. Default buttons are drawn using the theme's default colors, e.g.
+/// [`DefaultBackgroundColor`], to distinguish them from other buttons.
 #[derive(Debug)]
 pub struct Button {
     /// The label to display on the button.
@@ -37,6 +48,8 @@ pub struct Button {
     /// The kind of button to draw.
     pub kind: Value<ButtonKind>,
     focusable: bool,
+    trigger: ClickTrigger,
+    name: Option<Value<String>>,
     per_window: WindowLocal<PerWindow>,
 }
 
@@ -44,9 +57,28 @@ pub struct Button {
 struct PerWindow {
     buttons_pressed: usize,
     modifiers: Modifiers,
+    click_count: usize,
     cached_state: CacheState,
     active_colors: Option<Dynamic<ButtonColors>>,
     color_animation: AnimationHandle,
+    repeating: Option<Repeating>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum ClickTrigger {
+    #[default]
+    Release,
+    Press,
+    Repeat {
+        initial_delay: Duration,
+        interval: Duration,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Repeating {
+    next: Instant,
+    click: ButtonClick,
 }
 
 #[derive(Default, Debug, Eq, PartialEq, Clone, Copy)]
@@ -54,6 +86,21 @@ struct CacheState {
     style: Option<ButtonColors>,
 }
 
+/// The placement of an icon relative to the label of a
+/// [`Button`](Button::icon_and_label).
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum IconPosition {
+    /// The icon is placed to the left of the label.
+    #[default]
+    Left,
+    /// The icon is placed to the right of the label.
+    Right,
+    /// The icon is placed above the label.
+    Top,
+    /// The icon is placed below the label.
+    Bottom,
+}
+
 /// The type of a [`Button`] or similar clickable widget.
 #[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
 pub enum ButtonKind {
@@ -145,9 +192,60 @@ impl Button {
             per_window: WindowLocal::default(),
             kind: Value::Constant(ButtonKind::default()),
             focusable: true,
+            trigger: ClickTrigger::default(),
+            name: None,
         }
     }
 
+    /// Returns a new button that only displays `icon`.
+    ///
+    /// Because an icon may not convey the button's purpose to all users,
+    /// `name` is required and is used as the button's
+    /// [accessible name](Self::accessible_name). To also show `name` when the
+    /// button is hovered, use [`name_tooltip()`](Self::name_tooltip).
+    pub fn icon(icon: impl MakeWidget, name: impl IntoValue<String>) -> Self {
+        Self::new(icon).accessible_name(name)
+    }
+
+    /// Returns a new button that displays `icon` next to `label`.
+    ///
+    /// `position` controls which side of the label the icon is placed on.
+    pub fn icon_and_label(
+        icon: impl MakeWidget,
+        label: impl MakeWidget,
+        position: IconPosition,
+    ) -> Self {
+        let content = match position {
+            IconPosition::Left => icon.and(label).into_columns(),
+            IconPosition::Right => label.and(icon).into_columns(),
+            IconPosition::Top => icon.and(label).into_rows(),
+            IconPosition::Bottom => label.and(icon).into_rows(),
+        };
+        Self::new(content)
+    }
+
+    /// Sets the name that describes this button's action to `name`, and
+    /// returns self.
+    ///
+    /// This name is intended for users who are unable to see the button's
+    /// contents, such as when the button only contains an icon.
+    #[must_use]
+    pub fn accessible_name(mut self, name: impl IntoValue<String>) -> Self {
+        self.name = Some(name.into_value());
+        self
+    }
+
+    /// Returns a widget that shows this button's
+    /// [accessible name](Self::accessible_name) in a tooltip on `layer` when
+    /// the button is hovered.
+    ///
+    /// If no accessible name has been set, no tooltip is shown.
+    pub fn name_tooltip(self, layer: &OverlayLayer) -> Tooltipped {
+        let has_name = self.name.is_some();
+        let name = Label::new(self.name.clone().unwrap_or_default());
+        layer.new_tooltip(name, self).when(has_name)
+    }
+
     /// Sets the button's `kind` and returns self.
     #[must_use]
     pub fn kind(mut self, kind: impl IntoValue<ButtonKind>) -> Self {
@@ -157,7 +255,12 @@ impl Button {
 
     /// Sets the `on_click` callback and returns self.
     ///
-    /// This callback will be invoked each time the button is clicked.
+    /// This callback will be invoked each time the button is clicked. By
+    /// default, a click is recognized when the mouse button is released while
+    /// the cursor is above the button. To invoke the callback as soon as the
+    /// mouse button is pressed, use [`on_press`](Self::on_press) or
+    /// [`repeat`](Self::repeat).
+    #[doc(alias = "on_release")]
     #[must_use]
     pub fn on_click<F>(self, callback: F) -> Self
     where
@@ -173,6 +276,44 @@ impl Button {
         self
     }
 
+    /// Sets the `on_click` callback to be invoked as soon as a mouse button is
+    /// pressed, and returns self.
+    ///
+    /// Unlike [`on_click`](Self::on_click), releasing the mouse button has no
+    /// effect. Activating the button using the keyboard invokes `callback`
+    /// immediately. If [`repeat`](Self::repeat) has already been called, the
+    /// button continues to repeat.
+    #[must_use]
+    pub fn on_press<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(Option<ButtonClick>) + Send + 'static,
+    {
+        if self.trigger == ClickTrigger::Release {
+            self.trigger = ClickTrigger::Press;
+        }
+        self.on_click_notify(callback)
+    }
+
+    /// Causes the click callback to be invoked repeatedly while a mouse button
+    /// is held down, and returns self.
+    ///
+    /// The callback is invoked when the mouse button is pressed. If the button
+    /// is still held after `initial_delay`, the callback is invoked again every
+    /// `interval`. Releasing the mouse button or dragging the cursor off of the
+    /// button stops repeating. This can be called before or after
+    /// [`on_click`](Self::on_click) or [`on_press`](Self::on_press).
+    ///
+    /// This is useful for buttons that increment or decrement a value, such as
+    /// the arrows of a number stepper.
+    #[must_use]
+    pub fn repeat(mut self, initial_delay: Duration, interval: Duration) -> Self {
+        self.trigger = ClickTrigger::Repeat {
+            initial_delay,
+            interval,
+        };
+        self
+    }
+
     /// Prevents focus being given to this button.
     #[must_use]
     pub fn prevent_focus(mut self) -> Self {
@@ -188,6 +329,26 @@ impl Button {
         }
     }
 
+    fn repeat_if_needed(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        let ClickTrigger::Repeat { interval, .. } = self.trigger else {
+            return;
+        };
+        let per_window = self.per_window.entry(context).or_default();
+        let Some(repeating) = &mut per_window.repeating else {
+            return;
+        };
+
+        let now = animation::now();
+        if repeating.next <= now {
+            repeating.next = now + interval;
+            let click = repeating.click;
+            context.redraw_in(interval);
+            self.invoke_on_click(Some(click), context);
+        } else {
+            context.redraw_in(repeating.next - now);
+        }
+    }
+
     fn visual_style(context: &WidgetContext<'_>) -> VisualState {
         if !context.enabled() {
             VisualState::Disabled
@@ -367,6 +528,7 @@ impl Widget for Button {
         fmt.debug_struct("Button")
             .field("content", &self.content)
             .field("kind", &self.kind)
+            .field("name", &self.name)
             .finish()
     }
 
@@ -375,6 +537,7 @@ impl Widget for Button {
 
         let current_style = self.kind.get_tracking_redraw(context);
         self.update_colors(context, false);
+        self.repeat_if_needed(context);
 
         let style = self.current_style(context);
         context.fill(style.background);
@@ -387,9 +550,9 @@ impl Widget for Button {
         );
         context.stroke_outline(style.outline, outline_options);
 
-        if context.focused(true) {
+        if context.focused(true) && context.focus_is_visible() {
             if current_style == ButtonKind::Transparent {
-                let focus_color = context.get(&HighlightColor);
+                let focus_color = context.get(&FocusColor);
                 // Some states of a transparent button have solid background
                 // colors. most_contrasting from a 0-alpha color is not a
                 // meaningful measurement, so we only start measuring contrast
@@ -444,15 +607,43 @@ impl Widget for Button {
 
     fn mouse_down(
         &mut self,
-        _location: Point<Px>,
+        location: Point<Px>,
         _device_id: DeviceId,
-        _button: MouseButton,
+        button: MouseButton,
         context: &mut EventContext<'_>,
     ) -> EventHandling {
         let per_window = self.per_window.entry(context).or_default();
         per_window.buttons_pressed += 1;
         per_window.modifiers = context.modifiers();
+        let first_press = per_window.buttons_pressed == 1;
+        if first_press {
+            per_window.click_count = context.click_count();
+        }
+        let modifiers = per_window.modifiers;
+        let click_count = per_window.click_count;
         context.activate();
+
+        if first_press && self.trigger != ClickTrigger::Release {
+            let click = ButtonClick {
+                mouse_button: button,
+                location,
+                window_location: location
+                    + context
+                        .last_layout()
+                        .expect("must have been rendered")
+                        .origin,
+                modifiers,
+                click_count,
+            };
+            if let ClickTrigger::Repeat { initial_delay, .. } = self.trigger {
+                self.per_window.entry(context).or_default().repeating = Some(Repeating {
+                    next: animation::now() + initial_delay,
+                    click,
+                });
+                context.set_needs_redraw();
+            }
+            self.invoke_on_click(Some(click), context);
+        }
         HANDLED
     }
 
@@ -468,6 +659,7 @@ impl Widget for Button {
         {
             context.activate()
         } else {
+            self.per_window.entry(context).or_default().repeating = None;
             context.deactivate()
         };
 
@@ -486,6 +678,7 @@ impl Widget for Button {
         let window_local = self.per_window.entry(context).or_default();
         window_local.buttons_pressed -= 1;
         if window_local.buttons_pressed == 0 {
+            window_local.repeating = None;
             context.deactivate();
 
             if let (true, Some(location)) = (self.focusable, location) {
@@ -494,13 +687,19 @@ impl Widget for Button {
                 if Rect::from(last_layout.size).contains(location) {
                     context.focus();
 
+                    if self.trigger != ClickTrigger::Release {
+                        return;
+                    }
+
                     let modifiers = window_local.modifiers;
+                    let click_count = window_local.click_count;
                     self.invoke_on_click(
                         Some(ButtonClick {
                             mouse_button: button,
                             location,
                             window_location: location + last_layout.origin,
                             modifiers,
+                            click_count,
                         }),
                         context,
                     );
@@ -628,6 +827,12 @@ pub struct ButtonClick {
 
     /// The keyboard modifiers state when this click began.
     pub modifiers: Modifiers,
+    /// The number of consecutive clicks this click completes, including
+    /// itself.
+    ///
+    /// See [`WidgetContext::click_count()`] for how consecutive clicks are
+    /// recognized.
+    pub click_count: usize,
 }
 
 /// A multi-click gesture recognizer.
@@ -676,19 +881,27 @@ impl ClickCounter {
     }
 
     /// Notes a single click.
+    ///
+    /// When `click` is provided, its
+    /// [`click_count`](ButtonClick::click_count) is used, which requires each
+    /// click to be at nearly the same location. Clicks without a
+    /// [`ButtonClick`], such as keyboard activations, are counted using only
+    /// the time elapsed since the previous click.
     pub fn click(&mut self, click: Option<ButtonClick>) {
         let now = Instant::now();
         let threshold = self.threshold.get();
-        if let Some(last_click) = self.last_click {
-            let elapsed = now.saturating_duration_since(last_click);
-            if elapsed < threshold {
-                self.count += 1;
-            } else {
-                self.count = 1;
-            }
+        self.count = if let Some(click) = click {
+            // The window keeps counting after this counter has reached its
+            // maximum, so each run of `maximum` clicks is its own action.
+            (click.click_count.max(1) - 1) % self.maximum.max(1) + 1
         } else {
-            self.count = 1;
-        }
+            match self.last_click {
+                Some(last_click) if now.saturating_duration_since(last_click) < threshold => {
+                    self.count + 1
+                }
+                _ => 1,
+            }
+        };
         self.last_click = Some(now);
 
         if self.count == self.maximum {
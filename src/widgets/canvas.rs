@@ -1,14 +1,34 @@
 use std::fmt::Debug;
 
-use figures::units::UPx;
-use figures::Size;
+use figures::units::{Px, UPx};
+use figures::{Point, Size};
+use kludgine::app::winit::event::{ElementState, MouseButton};
+use kludgine::app::winit::window::CursorIcon;
 
-use crate::context::{GraphicsContext, LayoutContext};
+use crate::context::{EventContext, GraphicsContext, LayoutContext};
 use crate::reactive::value::Dynamic;
-use crate::widget::Widget;
+use crate::widget::{EventHandling, Widget, HANDLED, IGNORED};
+use crate::window::{DeviceId, KeyEvent};
 use crate::{ConstraintLimit, Tick};
 
 /// A 2d drawable surface.
+///
+/// The render function is invoked each time the canvas is redrawn. The size
+/// of the canvas is available through `context.gfx.size()`. Any
+/// [`Dynamic`] values read using
+/// [`get_tracking_redraw()`](crate::reactive::value::Source::get_tracking_redraw)
+/// or registered with
+/// [`redraw_when_changed()`](crate::context::WidgetContext::redraw_when_changed)
+/// cause the canvas to be redrawn when they are changed.
+///
+/// # Animation and Input
+///
+/// When a [`Tick`] is associated with a canvas using [`Canvas::tick`], the
+/// canvas is redrawn after each tick, allowing for continuous animation. The
+/// tick callback receives the elapsed time since the previous tick and an
+/// [`InputState`](crate::InputState) containing the keys currently pressed,
+/// the location of the cursor, and the mouse buttons currently pressed. The
+/// cursor's location is relative to the canvas's origin.
 #[must_use]
 pub struct Canvas {
     render: Box<dyn RenderFunction>,
@@ -33,6 +53,9 @@ impl Canvas {
     }
 
     /// Associates a [`Tick`] with this widget and returns self.
+    ///
+    /// The canvas will be redrawn each time `tick` is invoked, and mouse and
+    /// keyboard input received by the canvas will be reported to `tick`.
     pub fn tick(mut self, tick: Tick) -> Self {
         self.tick = Some(tick);
         self
@@ -55,6 +78,87 @@ impl Widget for Canvas {
     ) -> Size<UPx> {
         available_space.map(ConstraintLimit::max)
     }
+
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        self.tick.is_some()
+    }
+
+    fn hover(
+        &mut self,
+        location: Point<Px>,
+        _context: &mut EventContext<'_>,
+    ) -> Option<CursorIcon> {
+        if let Some(tick) = &self.tick {
+            tick.set_cursor_position(Some(location));
+        }
+
+        None
+    }
+
+    fn unhover(&mut self, _context: &mut EventContext<'_>) {
+        if let Some(tick) = &self.tick {
+            tick.set_cursor_position(None);
+        }
+    }
+
+    fn accept_focus(&mut self, _context: &mut EventContext<'_>) -> bool {
+        self.tick.is_some()
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        _context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if let Some(tick) = &self.tick {
+            tick.key_input(&input)?;
+        }
+
+        IGNORED
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if let Some(tick) = &self.tick {
+            tick.set_cursor_position(Some(location));
+            tick.mouse_button(button, ElementState::Pressed);
+            context.focus();
+            HANDLED
+        } else {
+            IGNORED
+        }
+    }
+
+    fn mouse_drag(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        _context: &mut EventContext<'_>,
+    ) {
+        if let Some(tick) = &self.tick {
+            tick.set_cursor_position(Some(location));
+        }
+    }
+
+    fn mouse_up(
+        &mut self,
+        _location: Option<Point<Px>>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        _context: &mut EventContext<'_>,
+    ) {
+        if let Some(tick) = &self.tick {
+            tick.mouse_button(button, ElementState::Released);
+        }
+    }
 }
 
 impl Debug for Canvas {
@@ -0,0 +1,89 @@
+use figures::{Round, ScreenScale, Zero};
+
+use crate::context::GraphicsContext;
+use crate::graphics::DrawTransform;
+use crate::reactive::value::{IntoValue, Source, Value};
+use crate::styles::{CornerRadii, Dimension};
+use crate::tree::HitClip;
+use crate::widget::{MakeWidget, WidgetRef, WrapperWidget};
+
+/// A widget that clips its child to its own bounds.
+///
+/// Every widget is drawn clipped to the area it was laid out in, but a child
+/// that is laid out to extend past its parent is still able to be hovered and
+/// clicked outside of its parent's bounds. This widget also restricts hit
+/// testing of its child and all of its descendants to its bounds, which can be
+/// disabled using [`clip_hit_testing()`](Self::clip_hit_testing).
+///
+/// A [corner radius](Self::corner_radius) can be provided to exclude the
+/// rounded corners of this widget from hit testing. Drawing is always clipped
+/// using a rectangle, so a child that needs to appear rounded must also draw
+/// itself with rounded corners.
+#[derive(Debug)]
+pub struct Clip {
+    child: WidgetRef,
+    corner_radius: Value<CornerRadii<Dimension>>,
+    clip_hit_testing: Value<bool>,
+    first_rendered: usize,
+}
+
+impl Clip {
+    /// Returns a widget that clips `child` to its bounds.
+    #[must_use]
+    pub fn new(child: impl MakeWidget) -> Self {
+        Self {
+            child: WidgetRef::new(child),
+            corner_radius: Value::Constant(CornerRadii::from(Dimension::ZERO)),
+            clip_hit_testing: Value::Constant(true),
+            first_rendered: 0,
+        }
+    }
+
+    /// Sets the radius of the corners of the clipped region and returns self.
+    #[must_use]
+    pub fn corner_radius(mut self, radius: impl IntoValue<CornerRadii<Dimension>>) -> Self {
+        self.corner_radius = radius.into_value();
+        self
+    }
+
+    /// Sets whether hit testing of the child is clipped to this widget's
+    /// bounds and returns self.
+    #[must_use]
+    pub fn clip_hit_testing(mut self, clip: impl IntoValue<bool>) -> Self {
+        self.clip_hit_testing = clip.into_value();
+        self
+    }
+}
+
+impl WrapperWidget for Clip {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn redraw_background(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        self.first_rendered = context.tree.rendered_widget_count();
+    }
+
+    fn redraw_foreground(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        if !self.clip_hit_testing.get_tracking_redraw(context) {
+            return;
+        }
+        let Some(region) = context.last_layout() else {
+            return;
+        };
+
+        let corner_radii = self
+            .corner_radius
+            .get_tracking_redraw(context)
+            .into_px(context.gfx.scale())
+            .round();
+        context.tree.clip_hit_testing(
+            self.first_rendered,
+            HitClip {
+                region,
+                corner_radii,
+                inverse: context.gfx.transform.and_then(DrawTransform::inverse),
+            },
+        );
+    }
+}
@@ -1,16 +1,23 @@
 use std::time::Duration;
 
+use easing_function::EasingFunction;
 use figures::units::Px;
 use figures::{Size, Zero};
 
 use crate::animation::{AnimationHandle, AnimationTarget, Spawn};
 use crate::context::LayoutContext;
-use crate::reactive::value::{Dynamic, Generation, IntoDynamic, Source};
+use crate::reactive::value::{Dynamic, Generation, IntoDynamic, IntoValue, Source, Value};
 use crate::styles::components::{EasingIn, EasingOut};
 use crate::widget::{MakeWidget, WidgetInstance, WidgetRef, WrappedLayout, WrapperWidget};
 use crate::ConstraintLimit;
 
 /// A widget that collapses/hides its contents based on a [`Dynamic<bool>`].
+///
+/// When the dynamic changes, the size of this widget animates between zero and
+/// the size of its contents, clipping the contents while animating. By
+/// default, the animation lasts 250 milliseconds and uses [`EasingIn`] when
+/// revealing and [`EasingOut`] when collapsing. [`Collapse::animated`] can be
+/// used to customize the animation.
 #[derive(Debug)]
 pub struct Collapse {
     child: WidgetRef,
@@ -19,6 +26,8 @@ pub struct Collapse {
     size: Dynamic<Px>,
     collapse_animation: Option<CollapseAnimation>,
     vertical: bool,
+    duration: Value<Duration>,
+    easing: Option<Value<EasingFunction>>,
 }
 
 impl Collapse {
@@ -31,9 +40,26 @@ impl Collapse {
             size: Dynamic::default(),
             vertical,
             collapse_animation: None,
+            duration: Value::Constant(Duration::from_millis(250)),
+            easing: None,
         }
     }
 
+    /// Sets the animation used when collapsing or revealing the contents to
+    /// last `duration` and use `easing`, and returns self.
+    ///
+    /// A duration of [`Duration::ZERO`] disables the animation.
+    #[must_use]
+    pub fn animated(
+        mut self,
+        duration: impl IntoValue<Duration>,
+        easing: impl IntoValue<EasingFunction>,
+    ) -> Self {
+        self.duration = duration.into_value();
+        self.easing = Some(easing.into_value());
+        self
+    }
+
     /// Returns a widget that collapses `child` vertically based on the dynamic
     /// boolean value.
     ///
@@ -65,6 +91,7 @@ impl Collapse {
         } else {
             (context.get(&EasingIn), size)
         };
+        let easing = self.easing.as_ref().map_or(easing, Value::get);
         match &self.collapse_animation {
             Some(state) if state.target == target => {}
             Some(_) if generation == self.collapse_generation => {
@@ -79,7 +106,7 @@ impl Collapse {
                 // If this is our first setup, immediately give the child the
                 // space they request.
                 let duration = if self.collapse_animation.is_some() {
-                    Duration::from_millis(250)
+                    self.duration.get()
                 } else {
                     Duration::ZERO
                 };
@@ -4,7 +4,7 @@ use std::ops::Div;
 
 use figures::units::{Lp, Px, UPx};
 use figures::{Abs, Angle, IntoSigned, IntoUnsigned, Point, Rect, Round, ScreenScale, Size, Zero};
-use kludgine::shapes::{CornerRadii, PathBuilder, Shape};
+use kludgine::shapes::{CornerRadii, PathBuilder, Shape, StrokeOptions};
 use kludgine::Color;
 
 use crate::context::{EventContext, GraphicsContext, LayoutContext, WidgetContext};
@@ -32,6 +32,16 @@ use crate::ConstraintLimit;
 /// - [`ContainerBackground::Level`]: The
 ///   [`SurfaceTheme`](crate::styles::SurfaceTheme) container color associated
 ///   with the given level will be used.
+///
+/// # Borders, Corners, and Shadows
+///
+/// A border is drawn inside of the container's edges when
+/// [`ContainerBorderWidth`] is greater than zero, using
+/// [`ContainerBorderColor`]. By default, containers have no border.
+///
+/// The background and border are rounded using [`CornerRadius`], which can
+/// specify a different radius for each corner. A drop shadow can be rendered
+/// behind the background using [`Container::shadow`].
 #[derive(Debug)]
 pub struct Container {
     /// The configured background selection.
@@ -220,22 +230,22 @@ impl Widget for Container {
 
         let background = self.effective_background_color(context);
         let background = background.with_alpha_f32(background.alpha_f32() * *opacity);
-        if background.alpha() > 0 {
-            let shadow = self
-                .shadow
-                .get_tracking_invalidate(context)
-                .into_px(context.gfx.scale())
-                .ceil();
+        let shadow = self
+            .shadow
+            .get_tracking_invalidate(context)
+            .into_px(context.gfx.scale())
+            .ceil();
 
-            let child_shadow_offset = shadow.offset.min(Point::ZERO).abs().ceil();
-            let child_size = context.gfx.region().size - shadow.spread * 2 - shadow.offset.abs();
-            let child_area = Rect::new(child_shadow_offset + shadow.spread, child_size);
+        let child_shadow_offset = shadow.offset.min(Point::ZERO).abs().ceil();
+        let child_size = context.gfx.region().size - shadow.spread * 2 - shadow.offset.abs();
+        let child_area = Rect::new(child_shadow_offset + shadow.spread, child_size);
 
-            let corner_radii = context
-                .get(&CornerRadius)
-                .into_px(context.gfx.scale())
-                .ceil();
+        let corner_radii = context
+            .get(&CornerRadius)
+            .into_px(context.gfx.scale())
+            .ceil();
 
+        if background.alpha() > 0 {
             // check if the shadow would be obscured before we try to draw it.
             if child_area.origin != Point::ZERO || child_size != context.gfx.region().size {
                 render_shadow(&child_area, corner_radii, &shadow, background, context);
@@ -248,6 +258,26 @@ impl Widget for Container {
             ));
         }
 
+        let border_width = context
+            .get(&ContainerBorderWidth)
+            .into_px(context.gfx.scale())
+            .ceil();
+        let border_color = context.get(&ContainerBorderColor);
+        let border_color = border_color.with_alpha_f32(border_color.alpha_f32() * *opacity);
+        if border_width > 0 && border_color.alpha() > 0 {
+            let border_area = Rect::new(
+                child_area.origin + Point::squared(border_width / 2),
+                child_area.size - Point::squared(border_width),
+            );
+            let options = StrokeOptions::px_wide(border_width).colored(border_color);
+            let border = if corner_radii.is_zero() {
+                Shape::stroked_rect(border_area, options)
+            } else {
+                Shape::stroked_round_rect(border_area, corner_radii, options)
+            };
+            context.gfx.draw_shape(&border);
+        }
+
         let child = self.child.mounted(context);
         context.for_other(&child).redraw();
     }
@@ -287,6 +317,14 @@ impl Widget for Container {
             .bottom
             .max(corner_radii.bottom_right / std::f32::consts::PI)
             .max(corner_radii.bottom_left / std::f32::consts::PI);
+        let border_width = context
+            .get(&ContainerBorderWidth)
+            .into_upx(context.gfx.scale())
+            .ceil();
+        padding.left += border_width;
+        padding.right += border_width;
+        padding.top += border_width;
+        padding.bottom += border_width;
         let padding_amount = padding.size();
 
         let shadow = self
@@ -678,6 +716,10 @@ define_components! {
     Container {
         /// The container background behind the current widget.
         CurrentContainerBackground(EffectiveBackground, "background", |context| EffectiveBackground::Color(context.get(&SurfaceColor)))
+        /// The width of the border drawn inside of a [`Container`]'s edges.
+        ContainerBorderWidth(Dimension, "border_width", Dimension::ZERO)
+        /// The color of the border drawn inside of a [`Container`]'s edges.
+        ContainerBorderColor(Color, "border_color", .surface.outline_variant)
     }
 }
 
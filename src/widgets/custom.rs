@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::path::PathBuf;
 
 use figures::units::Px;
 use figures::{Point, Size};
@@ -11,7 +12,7 @@ use crate::reactive::value::{IntoValue, Value};
 use crate::styles::VisualOrder;
 use crate::widget::{EventHandling, MakeWidget, WidgetRef, WrappedLayout, WrapperWidget, IGNORED};
 use crate::widgets::Space;
-use crate::window::{DeviceId, KeyEvent};
+use crate::window::{DeviceId, KeyEvent, Touch};
 use crate::ConstraintLimit;
 
 /// A callback-based custom widget.
@@ -44,8 +45,12 @@ pub struct Custom {
     keyboard_input: Option<Box<dyn ThreeParamEventFunc<DeviceId, KeyEvent, bool, EventHandling>>>,
     mouse_wheel:
         Option<Box<dyn ThreeParamEventFunc<DeviceId, MouseScrollDelta, TouchPhase, EventHandling>>>,
+    touch: Option<Box<dyn OneParamEventFunc<Touch, EventHandling>>>,
     allow_blur: Option<Box<dyn EventFunc<bool>>>,
     advance_focus: Option<Box<dyn OneParamEventFunc<VisualOrder, EventHandling>>>,
+    hovered_file: Option<Box<dyn OneParamEventFunc<PathBuf, EventHandling>>>,
+    hovered_file_cancelled: Option<Box<dyn EventFunc>>,
+    dropped_file: Option<Box<dyn OneParamEventFunc<PathBuf, EventHandling>>>,
 }
 
 impl Debug for Custom {
@@ -93,8 +98,12 @@ impl Custom {
             ime: None,
             keyboard_input: None,
             mouse_wheel: None,
+            touch: None,
             allow_blur: None,
             advance_focus: None,
+            hovered_file: None,
+            hovered_file_cancelled: None,
+            dropped_file: None,
         }
     }
 
@@ -436,6 +445,57 @@ impl Custom {
         self.mouse_wheel = Some(Box::new(mouse_wheel));
         self
     }
+
+    /// Invokes `touch` when a touch event occurs.
+    ///
+    /// This callback corresponds to [`WrapperWidget::touch`].
+    pub fn on_touch<OnTouch>(mut self, touch: OnTouch) -> Self
+    where
+        OnTouch: Send
+            + 'static
+            + for<'context> FnMut(Touch, &mut EventContext<'context>) -> EventHandling,
+    {
+        self.touch = Some(Box::new(touch));
+        self
+    }
+
+    /// Invokes `hovered_file` when a file is dragged over this widget.
+    ///
+    /// This callback corresponds to [`WrapperWidget::hovered_file`].
+    pub fn on_hovered_file<HoveredFile>(mut self, hovered_file: HoveredFile) -> Self
+    where
+        HoveredFile: Send
+            + 'static
+            + for<'context> FnMut(PathBuf, &mut EventContext<'context>) -> EventHandling,
+    {
+        self.hovered_file = Some(Box::new(hovered_file));
+        self
+    }
+
+    /// Invokes `cancelled` when a file previously hovered over this widget is
+    /// no longer being dragged over it.
+    ///
+    /// This callback corresponds to [`WrapperWidget::hovered_file_cancelled`].
+    pub fn on_hovered_file_cancelled<Cancelled>(mut self, cancelled: Cancelled) -> Self
+    where
+        Cancelled: Send + 'static + for<'context> FnMut(&mut EventContext<'context>),
+    {
+        self.hovered_file_cancelled = Some(Box::new(cancelled));
+        self
+    }
+
+    /// Invokes `dropped_file` when a file is dropped on this widget.
+    ///
+    /// This callback corresponds to [`WrapperWidget::dropped_file`].
+    pub fn on_dropped_file<DroppedFile>(mut self, dropped_file: DroppedFile) -> Self
+    where
+        DroppedFile: Send
+            + 'static
+            + for<'context> FnMut(PathBuf, &mut EventContext<'context>) -> EventHandling,
+    {
+        self.dropped_file = Some(Box::new(dropped_file));
+        self
+    }
 }
 
 impl WrapperWidget for Custom {
@@ -629,6 +689,14 @@ impl WrapperWidget for Custom {
         }
     }
 
+    fn touch(&mut self, touch: Touch, context: &mut EventContext<'_>) -> EventHandling {
+        if let Some(f) = &mut self.touch {
+            f.invoke(touch, context)
+        } else {
+            IGNORED
+        }
+    }
+
     fn advance_focus(
         &mut self,
         direction: VisualOrder,
@@ -648,6 +716,28 @@ impl WrapperWidget for Custom {
             true
         }
     }
+
+    fn hovered_file(&mut self, path: PathBuf, context: &mut EventContext<'_>) -> EventHandling {
+        if let Some(hovered_file) = &mut self.hovered_file {
+            hovered_file.invoke(path, context)
+        } else {
+            IGNORED
+        }
+    }
+
+    fn hovered_file_cancelled(&mut self, context: &mut EventContext<'_>) {
+        if let Some(cancelled) = &mut self.hovered_file_cancelled {
+            cancelled.invoke(context);
+        }
+    }
+
+    fn dropped_file(&mut self, path: PathBuf, context: &mut EventContext<'_>) -> EventHandling {
+        if let Some(dropped_file) = &mut self.dropped_file {
+            dropped_file.invoke(path, context)
+        } else {
+            IGNORED
+        }
+    }
 }
 
 trait RedrawFunc: Send {
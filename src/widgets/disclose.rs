@@ -12,7 +12,7 @@ use super::button::{ButtonActiveBackground, ButtonBackground, ButtonHoverBackgro
 use crate::animation::{AnimationHandle, AnimationTarget, Spawn};
 use crate::context::{EventContext, LayoutContext};
 use crate::reactive::value::{Destination, Dynamic, IntoDynamic, IntoValue, Source, Value};
-use crate::styles::components::{HighlightColor, IntrinsicPadding, LineHeight, OutlineColor};
+use crate::styles::components::{FocusColor, IntrinsicPadding, LineHeight, OutlineColor};
 use crate::styles::Dimension;
 use crate::widget::{
     EventHandling, MakeWidget, MakeWidgetWithTag, Widget, WidgetInstance, WidgetRef, WidgetTag,
@@ -134,8 +134,8 @@ impl DiscloseIndicator {
         };
         let stroke_color = if self.hovering_indicator {
             context.get(&OutlineColor)
-        } else if context.focused(true) {
-            context.get(&HighlightColor)
+        } else if context.focused(true) && context.focus_is_visible() {
+            context.get(&FocusColor)
         } else {
             context.get(&OutlineColor).with_alpha(0)
         };
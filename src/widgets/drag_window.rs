@@ -0,0 +1,62 @@
+use figures::units::Px;
+use figures::Point;
+use kludgine::app::winit::event::MouseButton;
+
+use crate::context::EventContext;
+use crate::widget::{EventHandling, MakeWidget, WidgetRef, WrapperWidget, HANDLED, IGNORED};
+use crate::window::DeviceId;
+
+/// A widget that moves its window when dragged using the left mouse button.
+///
+/// This widget is intended to be used to build custom title bars for windows
+/// that have [decorations](crate::window::Window::decorated) disabled. Widgets
+/// contained within this widget that handle mouse events, such as buttons,
+/// continue to function normally.
+///
+/// On platforms that do not support dragging windows, this widget ignores the
+/// mouse event.
+#[derive(Debug)]
+pub struct DragWindow {
+    child: WidgetRef,
+}
+
+impl DragWindow {
+    /// Returns a widget that moves its window when `child` is dragged.
+    #[must_use]
+    pub fn new(child: impl MakeWidget) -> Self {
+        Self {
+            child: WidgetRef::new(child),
+        }
+    }
+}
+
+impl WrapperWidget for DragWindow {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        true
+    }
+
+    fn mouse_down(
+        &mut self,
+        _location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button != MouseButton::Left {
+            return IGNORED;
+        }
+
+        match context.window().winit().map(|winit| winit.drag_window()) {
+            Some(Ok(())) => HANDLED,
+            Some(Err(err)) => {
+                tracing::debug!("unable to drag window: {err}");
+                IGNORED
+            }
+            None => IGNORED,
+        }
+    }
+}
@@ -86,6 +86,12 @@ impl Expand {
     /// Returns a widget that expands `child` to fill the parent widget, using
     /// `weight` when competing with available space with other [`Expand`]s.
     ///
+    /// A [`Stack`](crate::widgets::Stack) divides the space remaining after
+    /// its other children are measured between its expanded children in
+    /// proportion to their weights. For example, three children weighted 1,
+    /// 2, and 1 receive a quarter, a half, and a quarter of the remaining
+    /// space.
+    ///
     /// Note: Not all container widgets support weighted expansion.
     #[must_use]
     pub fn weighted(weight: u8, child: impl MakeWidget) -> Self {
@@ -17,12 +17,20 @@ use crate::styles::Dimension;
 use crate::widget::{MakeWidget, MountedWidget, Widget, WidgetInstance};
 use crate::ConstraintLimit;
 
+/// Divides `measured` evenly across `span` tracks, excluding the gutters
+/// between them.
+fn span_share(measured: UPx, span: usize, gutter: UPx) -> UPx {
+    let gutters = gutter.saturating_mul(UPx::new((span - 1).cast::<u32>()));
+    (measured.saturating_sub(gutters) / span.cast::<u32>()).ceil()
+}
+
 /// A 2D grid of widgets.
 #[derive(Debug)]
 pub struct Grid<const ELEMENTS: usize> {
     columns: Value<[GridDimension; ELEMENTS]>,
     rows: Value<GridWidgets<ELEMENTS>>,
     live_rows: Vec<[MountedWidget; ELEMENTS]>,
+    cells: Vec<[GridCell; ELEMENTS]>,
     layout: GridLayout,
     layout_generation: Option<Generation>,
     spec_generation: Option<Generation>,
@@ -34,6 +42,7 @@ impl<const ELEMENTS: usize> Grid<ELEMENTS> {
             columns: Value::Constant(array::from_fn(|_| GridDimension::FitContent)),
             rows: rows.into_value(),
             live_rows: Vec::new(),
+            cells: Vec::new(),
             layout: GridLayout::new(orientation),
             layout_generation: None,
             spec_generation: None,
@@ -126,22 +135,73 @@ impl<const ELEMENTS: usize> Grid<ELEMENTS> {
                         context.remove_child(&removed);
                     }
                 }
+
+                self.cells.clear();
+                self.cells.extend(rows.iter().map(GridSection::cells));
             });
         }
     }
-}
 
-impl<const COLUMNS: usize> Widget for Grid<COLUMNS> {
-    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
-        for (row, widgets) in self.live_rows.iter_mut().enumerate() {
-            if self.layout.others[row] > 0 {
-                for (column, cell) in widgets.iter().enumerate() {
-                    if self.layout[column].size > 0 {
-                        context.for_other(cell).redraw();
-                    }
+    /// Measures each cell that spans multiple tracks and returns the portion
+    /// of its measurement credited to each track it covers.
+    ///
+    /// Tracks are measured grouped by their dimension kind rather than in
+    /// order, so these shares must be known before any track is measured.
+    fn measure_span_shares(
+        &self,
+        available_space: Size<ConstraintLimit>,
+        gutter: UPx,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Vec<[UPx; ELEMENTS]> {
+        let orientation = self.layout.orientation;
+        let (space, other) = orientation.split_size(available_space);
+        let constraints = orientation.make_size(ConstraintLimit::SizeToFit(space.max()), other);
+        let mut span_shares = vec![[UPx::ZERO; ELEMENTS]; self.live_rows.len()];
+        for ((shares, cells), row) in span_shares.iter_mut().zip(&self.cells).zip(&self.live_rows) {
+            for (track, cell) in cells.iter().enumerate() {
+                let GridCell::Origin { span } = *cell else {
+                    continue;
+                };
+                if span <= 1 {
+                    continue;
+                }
+
+                let size = context
+                    .for_other(&row[track])
+                    .as_temporary()
+                    .layout(constraints);
+                let (measured, _) = orientation.split_size(size);
+                let share = span_share(measured, span, gutter);
+                for covered in &mut shares[track..track + span] {
+                    *covered = share;
                 }
             }
         }
+        span_shares
+    }
+}
+
+impl<const COLUMNS: usize> Widget for Grid<COLUMNS> {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        let cells = &self.cells;
+        let layout = &self.layout;
+        let visible_cells = self
+            .live_rows
+            .iter()
+            .enumerate()
+            .filter(|(row, _)| layout.others[*row] > 0)
+            .flat_map(|(row, widgets)| {
+                widgets
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(column, cell)| match cells[row][column] {
+                        GridCell::Origin { span } if layout.spanned_size(column, span) > 0 => {
+                            Some(cell)
+                        }
+                        _ => None,
+                    })
+            });
+        context.redraw_children(visible_cells);
     }
 
     fn mounted(&mut self, context: &mut EventContext<'_>) {
@@ -159,38 +219,64 @@ impl<const COLUMNS: usize> Widget for Grid<COLUMNS> {
     ) -> Size<UPx> {
         self.synchronize_children(&mut context.as_event_context());
 
+        let gutter = context
+            .get(&IntrinsicPadding)
+            .into_upx(context.gfx.scale())
+            .round();
+        let orientation = self.layout.orientation;
+        let span_shares = self.measure_span_shares(available_space, gutter, context);
         let content_size = self.layout.update(
             available_space,
-            context
-                .get(&IntrinsicPadding)
-                .into_upx(context.gfx.scale())
-                .round(),
+            gutter,
             context.gfx.scale(),
-            |row, column, constraints, persist| {
-                let mut context = context.for_other(&self.live_rows[column][row]);
-                if !persist {
-                    context = context.as_temporary();
+            |track, element, constraints, persist| match self.cells[element][track] {
+                GridCell::Origin { span } => {
+                    let mut context = context.for_other(&self.live_rows[element][track]);
+                    if !persist || span > 1 {
+                        context = context.as_temporary();
+                    }
+                    let size = context.layout(constraints);
+                    if span == 1 {
+                        size
+                    } else {
+                        let (_, other) = orientation.split_size(size);
+                        orientation.make_size(span_shares[element][track], other)
+                    }
                 }
-                context.layout(constraints)
+                GridCell::Covered => orientation.make_size(span_shares[element][track], UPx::ZERO),
             },
         );
 
         let mut other_offset = UPx::ZERO;
-        for (&other_size, row) in self.layout.others.iter().zip(&self.live_rows) {
+        for ((&other_size, row), cells) in self
+            .layout
+            .others
+            .iter()
+            .zip(&self.live_rows)
+            .zip(&self.cells)
+        {
             if other_size > 0 {
-                for (layout, cell) in self.layout.iter().zip(row) {
-                    if layout.size > 0 {
+                for (track, (cell, spec)) in row.iter().zip(cells).enumerate() {
+                    let GridCell::Origin { span } = *spec else {
+                        continue;
+                    };
+                    let size = self.layout.spanned_size(track, span);
+                    if size > 0 {
+                        if span > 1 {
+                            // Spanning cells were only measured temporarily,
+                            // so they must be laid out in their final size.
+                            context.for_other(cell).layout(orientation.make_size(
+                                ConstraintLimit::Fill(size),
+                                ConstraintLimit::Fill(other_size),
+                            ));
+                        }
                         context.set_child_layout(
                             cell,
                             Rect::new(
-                                self.layout
-                                    .orientation
-                                    .make_point(layout.offset, other_offset)
-                                    .into_signed(),
-                                self.layout
-                                    .orientation
-                                    .make_size(layout.size, other_size)
+                                orientation
+                                    .make_point(self.layout[track].offset, other_offset)
                                     .into_signed(),
+                                orientation.make_size(size, other_size).into_signed(),
                             ),
                         );
                     }
@@ -249,6 +335,12 @@ impl Orientation {
 
 /// The strategy to use when laying a widget out inside of an [`Grid`] or
 /// [`Stack`](crate::widgets::Stack).
+///
+/// Tracks are measured in phases: [`Measured`](Self::Measured) tracks are
+/// allocated first, followed by [`FitContent`](Self::FitContent) tracks being
+/// measured within the remaining space. Any space that remains is divided
+/// between [`Fractional`](Self::Fractional) tracks proportionally to their
+/// weights, similar to the `fr` unit in CSS grids.
 #[derive(Default, Debug, Clone, Copy)]
 pub enum GridDimension {
     /// Attempt to lay out the widget based on its contents.
@@ -551,6 +643,17 @@ impl GridLayout {
         }
     }
 
+    /// Returns the size of `span` tracks starting at `index`, including the
+    /// gutters between them.
+    pub fn spanned_size(&self, index: usize, span: usize) -> UPx {
+        let first = &self.layouts[index];
+        if span <= 1 {
+            return first.size;
+        }
+        let last = &self.layouts[index + span - 1];
+        (last.offset + last.size).saturating_sub(first.offset)
+    }
+
     fn total_other(&self) -> UPx {
         self.others
             .iter()
@@ -610,8 +713,9 @@ mod tests {
     use figures::units::UPx;
     use figures::{Fraction, IntoSigned, Size, Zero};
 
-    use super::{GridDimension, GridLayout, Orientation};
+    use super::{span_share, GridCell, GridDimension, GridLayout, GridSection, Orientation};
     use crate::styles::Dimension;
+    use crate::widgets::Space;
     use crate::ConstraintLimit;
 
     struct Child {
@@ -653,6 +757,7 @@ mod tests {
         orientation: Orientation,
         children: &[Child],
         available: Size<ConstraintLimit>,
+        gutter: UPx,
         expected: &[UPx],
         expected_size: Size<UPx>,
     ) {
@@ -664,7 +769,7 @@ mod tests {
 
         let computed_size = flex.update(
             available,
-            UPx::ZERO,
+            gutter,
             Fraction::ONE,
             |index, _element, constraints, _persist| {
                 let (measured_constraint, _other_constraint) = orientation.split_size(constraints);
@@ -694,7 +799,7 @@ mod tests {
                 child.size,
             );
             assert_eq!(child.offset, offset);
-            offset += child.size;
+            offset += child.size + gutter;
         }
     }
 
@@ -705,11 +810,32 @@ mod tests {
         expected: &[UPx],
         expected_measured: UPx,
         expected_other: UPx,
+    ) {
+        assert_measured_children_with_gutter(
+            children,
+            main_constraint,
+            other_constraint,
+            UPx::ZERO,
+            expected,
+            expected_measured,
+            expected_other,
+        );
+    }
+
+    fn assert_measured_children_with_gutter(
+        children: &[Child],
+        main_constraint: ConstraintLimit,
+        other_constraint: ConstraintLimit,
+        gutter: UPx,
+        expected: &[UPx],
+        expected_measured: UPx,
+        expected_other: UPx,
     ) {
         assert_measured_children_in_orientation(
             Orientation::Row,
             children,
             Orientation::Row.make_size(main_constraint, other_constraint),
+            gutter,
             expected,
             Orientation::Row.make_size(expected_measured, expected_other),
         );
@@ -717,6 +843,7 @@ mod tests {
             Orientation::Column,
             children,
             Orientation::Column.make_size(main_constraint, other_constraint),
+            gutter,
             expected,
             Orientation::Column.make_size(expected_measured, expected_other),
         );
@@ -815,6 +942,149 @@ mod tests {
             UPx::new(1),
         );
     }
+
+    #[test]
+    fn fixed_and_weighted() {
+        // Weighted children divide the space remaining after fixed children
+        // are allocated, proportionally to their weights.
+        assert_measured_children(
+            &[
+                Child::new(3, 1).fixed_size(UPx::new(100)),
+                Child::new(3, 1).weighted(1),
+                Child::new(3, 1).weighted(2),
+            ],
+            ConstraintLimit::Fill(UPx::new(400)),
+            ConstraintLimit::SizeToFit(UPx::new(400)),
+            &[UPx::new(100), UPx::new(100), UPx::new(200)],
+            UPx::new(400),
+            UPx::new(1),
+        );
+    }
+
+    #[test]
+    fn weighted_proportions() {
+        // Leftover space is distributed between weighted children in
+        // proportion to their weights, and the portions sum to the space
+        // available.
+        assert_measured_children(
+            &[
+                Child::new(3, 1).weighted(1),
+                Child::new(3, 1).weighted(2),
+                Child::new(3, 1).weighted(1),
+            ],
+            ConstraintLimit::Fill(UPx::new(400)),
+            ConstraintLimit::SizeToFit(UPx::new(400)),
+            &[UPx::new(100), UPx::new(200), UPx::new(100)],
+            UPx::new(400),
+            UPx::new(1),
+        );
+    }
+
+    #[test]
+    fn gutter() {
+        // Gutters are only placed between children.
+        assert_measured_children_with_gutter(
+            &[Child::new(20, 1), Child::new(20, 1), Child::new(20, 1)],
+            ConstraintLimit::SizeToFit(UPx::new(100)),
+            ConstraintLimit::SizeToFit(UPx::new(100)),
+            UPx::new(10),
+            &[UPx::new(20), UPx::new(20), UPx::new(20)],
+            UPx::new(80),
+            UPx::new(1),
+        );
+        // Gutters are fixed, and weighted children divide the space that
+        // remains after the gutters are allocated.
+        assert_measured_children_with_gutter(
+            &[
+                Child::new(20, 1),
+                Child::new(3, 1).weighted(1),
+                Child::new(20, 1),
+            ],
+            ConstraintLimit::Fill(UPx::new(100)),
+            ConstraintLimit::SizeToFit(UPx::new(100)),
+            UPx::new(10),
+            &[UPx::new(20), UPx::new(40), UPx::new(20)],
+            UPx::new(100),
+            UPx::new(1),
+        );
+    }
+
+    #[test]
+    fn spans() {
+        let section = GridSection::from([
+            Space::clear(),
+            Space::clear(),
+            Space::clear(),
+            Space::clear(),
+        ])
+        .span(1, 5);
+        assert_eq!(
+            section.cells(),
+            [
+                GridCell::Origin { span: 1 },
+                GridCell::Origin { span: 3 },
+                GridCell::Covered,
+                GridCell::Covered,
+            ]
+        );
+
+        let mut flex = GridLayout::new(Orientation::Column);
+        for _ in 0..3 {
+            flex.push(GridDimension::FitContent, Fraction::ONE);
+        }
+        flex.update(
+            Size::new(
+                ConstraintLimit::SizeToFit(UPx::new(100)),
+                ConstraintLimit::SizeToFit(UPx::new(100)),
+            ),
+            UPx::new(10),
+            Fraction::ONE,
+            |_index, _element, _constraints, _persist| Size::new(UPx::new(20), UPx::new(1)),
+        );
+        // A span includes the gutters between the spanned tracks.
+        assert_eq!(flex.spanned_size(0, 1), UPx::new(20));
+        assert_eq!(flex.spanned_size(1, 2), UPx::new(50));
+        assert_eq!(flex.spanned_size(0, 3), UPx::new(80));
+    }
+
+    #[test]
+    fn span_across_track_kinds() {
+        // A cell 70px wide spans a weighted track and a fit-content track.
+        // Fit-content tracks are measured before weighted tracks, so the
+        // covered track must already know its share when it is measured.
+        let cells = GridSection::from([Space::clear(), Space::clear(), Space::clear()])
+            .span(0, 2)
+            .cells();
+        let gutter = UPx::new(10);
+        let share = span_share(UPx::new(70), 2, gutter);
+        assert_eq!(share, UPx::new(30));
+        let shares = [share, share, UPx::ZERO];
+
+        let mut flex = GridLayout::new(Orientation::Column);
+        flex.push(GridDimension::Fractional { weight: 1 }, Fraction::ONE);
+        flex.push(GridDimension::FitContent, Fraction::ONE);
+        flex.push(GridDimension::FitContent, Fraction::ONE);
+        flex.update(
+            Size::new(
+                ConstraintLimit::Fill(UPx::new(100)),
+                ConstraintLimit::SizeToFit(UPx::new(100)),
+            ),
+            gutter,
+            Fraction::ONE,
+            |track, _element, _constraints, _persist| match cells[track] {
+                GridCell::Origin { span: 1 } => Size::new(UPx::new(10), UPx::new(1)),
+                GridCell::Origin { .. } => Size::new(shares[track], UPx::new(1)),
+                GridCell::Covered => Size::new(shares[track], UPx::ZERO),
+            },
+        );
+
+        assert_eq!(flex[1].size, UPx::new(30));
+        assert_eq!(flex[2].size, UPx::new(10));
+        // The weighted track receives the remaining space, and the span is
+        // at least as large as the cell that was measured.
+        assert_eq!(flex[0].size, UPx::new(40));
+        assert!(flex.spanned_size(0, 2) >= UPx::new(70));
+    }
 }
 
 /// A 2d collection of widgets for a [`Grid`].
@@ -878,8 +1148,57 @@ impl<const N: usize> DerefMut for GridWidgets<N> {
 }
 
 /// A single dimension of widgets within a [`GridWidgets`] collection.
+///
+/// By default, each widget occupies a single track of the grid. A widget can
+/// occupy multiple adjacent tracks using [`GridSection::span`].
 #[derive(Debug, Eq, PartialEq)]
-pub struct GridSection<const N: usize>([WidgetInstance; N]);
+pub struct GridSection<const N: usize>([WidgetInstance; N], [usize; N]);
+
+impl<const N: usize> GridSection<N> {
+    /// Sets the widget at `index` to occupy `span` tracks and returns self.
+    ///
+    /// For a grid created with [`Grid::from_rows`], this causes the widget to
+    /// span multiple columns. For a grid created with [`Grid::from_columns`],
+    /// the widget spans multiple rows. The spanning widget is given the
+    /// combined size of all of the tracks it spans, including the space
+    /// between them. When measuring the tracks, the spanning widget's size is
+    /// divided evenly between the tracks.
+    ///
+    /// The widgets in the tracks covered by the span are not displayed.
+    /// [`Space::clear()`](crate::widgets::Space::clear) can be used as a
+    /// placeholder for these tracks. If `span` extends beyond the final track,
+    /// it is limited to the remaining tracks. A `span` of 0 is treated as 1.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is greater than or equal to `N`.
+    #[must_use]
+    pub fn span(mut self, index: usize, span: usize) -> Self {
+        assert!(index < N, "index out of bounds");
+        self.1[index] = span.clamp(1, N - index);
+        self
+    }
+
+    fn cells(&self) -> [GridCell; N] {
+        let mut cells = [GridCell::Origin { span: 1 }; N];
+        let mut index = 0;
+        while index < N {
+            let span = self.1[index];
+            cells[index] = GridCell::Origin { span };
+            for covered in &mut cells[index + 1..index + span] {
+                *covered = GridCell::Covered;
+            }
+            index += span;
+        }
+        cells
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum GridCell {
+    Origin { span: usize },
+    Covered,
+}
 
 impl Default for GridSection<0> {
     fn default() -> Self {
@@ -891,14 +1210,14 @@ impl GridSection<0> {
     /// Returns an empty section.
     #[must_use]
     pub const fn new() -> Self {
-        Self([])
+        Self([], [])
     }
 
     /// Appends `other` to the end of this collection of widgets and
     /// returns the updated collection.
     #[must_use]
     pub fn and(self, other: impl MakeWidget) -> GridSection<1> {
-        GridSection([other.make_widget()])
+        GridSection([other.make_widget()], [1])
     }
 }
 
@@ -907,7 +1226,7 @@ where
     T: MakeWidget,
 {
     fn from(value: T) -> Self {
-        Self([value.make_widget()])
+        Self([value.make_widget()], [1])
     }
 }
 
@@ -917,9 +1236,10 @@ where
 {
     fn from(values: [T; N]) -> Self {
         let mut widgets = values.into_iter();
-        Self(array::from_fn(|_| {
-            widgets.next().assert("length checked").make_widget()
-        }))
+        Self(
+            array::from_fn(|_| widgets.next().assert("length checked").make_widget()),
+            [1; N],
+        )
     }
 }
 
@@ -956,7 +1276,7 @@ macro_rules! impl_grid_widgets_and {
                     $($done,)*
                     items.next().assert("known size"),
                     other.make_widget()
-                ])
+                ], array::from_fn(|index| self.1.get(index).copied().unwrap_or(1)))
             }
         }
 
@@ -975,7 +1295,7 @@ macro_rules! impl_grid_widgets_from_tuple {
             fn from(tuple: ($($type,)+)) -> Self {
                 Self([
                     $(tuple.$field.make_widget(),)+
-                ])
+                ], [1; { $crate::count!($($field),+;) }])
             }
         }
     };
@@ -1,16 +1,22 @@
 //! A widget that displays an image/texture.
 
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Arc;
+
 use figures::units::{Px, UPx};
 use figures::{FloatConversion, IntoSigned, IntoUnsigned, Point, Rect, ScreenScale, Size, Zero};
+use image::{DynamicImage, ImageError, ImageResult};
 use kludgine::shapes::{CornerRadii, Shape};
+use kludgine::wgpu::FilterMode;
 use kludgine::{
     AnyTexture, CollectedTexture, Color, LazyTexture, SharedTexture, Texture, TextureRegion,
 };
 
 use crate::animation::ZeroToOne;
-use crate::context::{LayoutContext, Trackable};
-use crate::reactive::value::{IntoValue, Source, Value};
-use crate::styles::Dimension;
+use crate::context::{GraphicsContext, LayoutContext, Trackable};
+use crate::reactive::value::{Destination, Dynamic, DynamicReader, IntoValue, Source, Value};
+use crate::styles::{Dimension, Edges};
 use crate::widget::Widget;
 use crate::ConstraintLimit;
 
@@ -23,6 +29,7 @@ pub struct Image {
     pub scaling: Value<ImageScaling>,
     /// The opacity to render the image with.
     pub opacity: Value<ZeroToOne>,
+    status: Option<DynamicReader<ImageLoadStatus>>,
 }
 
 impl Image {
@@ -33,9 +40,68 @@ impl Image {
             contents: contents.into_value(),
             scaling: Value::default(),
             opacity: Value::Constant(ZeroToOne::ONE),
+            status: None,
         }
     }
 
+    /// Returns a new image widget that displays the image at `path`.
+    ///
+    /// The file is read and decoded on a background thread. Nothing is
+    /// displayed until the image has been decoded or if the image fails to
+    /// load. [`Image::load_status`] can be used to observe the progress.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        Self::load_in_background(move || image::open(path))
+    }
+
+    /// Returns a new image widget that displays the encoded image contained
+    /// in `bytes`.
+    ///
+    /// The image is decoded on a background thread. Nothing is displayed
+    /// until the image has been decoded or if the image fails to decode.
+    /// [`Image::load_status`] can be used to observe the progress.
+    pub fn from_bytes(bytes: impl AsRef<[u8]> + Send + 'static) -> Self {
+        Self::load_in_background(move || image::load_from_memory(bytes.as_ref()))
+    }
+
+    fn load_in_background<F>(load: F) -> Self
+    where
+        F: FnOnce() -> ImageResult<DynamicImage> + Send + 'static,
+    {
+        let contents = Dynamic::new(AnyTexture::from(LazyTexture::from_image(
+            DynamicImage::new_rgba8(1, 1),
+            FilterMode::Linear,
+        )));
+        let status = Dynamic::new(ImageLoadStatus::Loading);
+        std::thread::spawn({
+            let contents = contents.clone();
+            let status = status.clone();
+            move || match load() {
+                Ok(image) => {
+                    contents.force_set(AnyTexture::from(LazyTexture::from_image(
+                        image,
+                        FilterMode::Linear,
+                    )));
+                    status.force_set(ImageLoadStatus::Loaded);
+                }
+                Err(err) => {
+                    status.force_set(ImageLoadStatus::Failed(Arc::new(err)));
+                }
+            }
+        });
+
+        let mut image = Self::new(contents);
+        image.status = Some(status.into_reader());
+        image
+    }
+
+    /// Returns the status of loading this image, if this image was created
+    /// using [`Image::from_path`] or [`Image::from_bytes`].
+    #[must_use]
+    pub fn load_status(&self) -> Option<DynamicReader<ImageLoadStatus>> {
+        self.status.clone()
+    }
+
     /// Applies the `scaling` strategies and returns self.
     #[must_use]
     pub fn scaling(mut self, scaling: impl IntoValue<ImageScaling>) -> Self {
@@ -111,6 +177,26 @@ impl Image {
         self.scaling(ImageScaling::Stretch)
     }
 
+    /// Applies the nine-patch scaling strategy and returns self.
+    ///
+    /// The image is divided into nine regions using `insets`, which are
+    /// measured in pixels of the source image. The corners are drawn at their
+    /// original size, the edges are stretched along one axis, and the center
+    /// is stretched along both axes to fill the surface.
+    #[must_use]
+    pub fn nine_patch(self, insets: impl Into<Edges<UPx>>) -> Self {
+        self.scaling(ImageScaling::NinePatch(insets.into()))
+    }
+
+    /// Applies the tile scaling strategy and returns self.
+    ///
+    /// The tile scaling strategy repeats the image at its original size to
+    /// fill the surface.
+    #[must_use]
+    pub fn tile(self) -> Self {
+        self.scaling(ImageScaling::Tile)
+    }
+
     /// Applies a scaling factor strategy and returns self.
     ///
     /// The image will be displayed at a scaling factor of `amount`. In this
@@ -126,28 +212,46 @@ impl Image {
 }
 
 impl Widget for Image {
-    fn redraw(&mut self, context: &mut crate::context::GraphicsContext<'_, '_, '_, '_>) {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
         self.contents.invalidate_when_changed(context);
+        if let Some(status) = &self.status {
+            // The placeholder texture used while loading is never displayed.
+            if !matches!(status.get_tracking_redraw(context), ImageLoadStatus::Loaded) {
+                return;
+            }
+        }
         let opacity = self.opacity.get_tracking_redraw(context);
         let radii = context.get(&ImageCornerRadius);
         let radii = radii.map(|r| r.into_px(context.gfx.scale()));
         let scaling = self.scaling.get_tracking_invalidate(context);
 
         self.contents.map(|texture| {
-            let rect = scaling.render_area(texture.size(), context.gfx.size());
-            if radii.is_zero() {
-                context.gfx.draw_texture(texture, rect, opacity);
+            if let ImageScaling::NinePatch(insets) = scaling {
+                draw_nine_patch(context, texture, insets, opacity);
+            } else if scaling == ImageScaling::Tile {
+                let tile = texture.size().into_signed();
+                if tile.width <= 0 || tile.height <= 0 {
+                    return;
+                }
+                let area = context.gfx.size().into_signed();
+                let mut y = Px::ZERO;
+                while y < area.height {
+                    let mut x = Px::ZERO;
+                    while x < area.width {
+                        draw_texture(
+                            context,
+                            texture,
+                            Rect::new(Point::new(x, y), tile),
+                            CornerRadii::ZERO,
+                            opacity,
+                        );
+                        x += tile.width;
+                    }
+                    y += tile.height;
+                }
             } else {
-                context.gfx.draw_textured_shape(
-                    &Shape::textured_round_rect(
-                        rect,
-                        radii,
-                        Rect::from(texture.size()),
-                        Color::WHITE,
-                    ),
-                    texture,
-                    opacity,
-                );
+                let rect = scaling.render_area(texture.size(), context.gfx.size());
+                draw_texture(context, texture, rect, radii, opacity);
             }
         });
     }
@@ -163,7 +267,93 @@ impl Widget for Image {
     }
 }
 
+fn draw_texture(
+    context: &mut GraphicsContext<'_, '_, '_, '_>,
+    texture: &AnyTexture,
+    rect: Rect<Px>,
+    radii: CornerRadii<Px>,
+    opacity: ZeroToOne,
+) {
+    // Textures drawn directly can't be rotated, so a shape is used when a
+    // transformation is being applied.
+    if radii.is_zero() && context.gfx.transform.is_none() {
+        context.gfx.draw_texture(texture, rect, opacity);
+    } else {
+        context.gfx.draw_textured_shape(
+            &Shape::textured_round_rect(rect, radii, Rect::from(texture.size()), Color::WHITE),
+            texture,
+            opacity,
+        );
+    }
+}
+
+fn draw_nine_patch(
+    context: &mut GraphicsContext<'_, '_, '_, '_>,
+    texture: &AnyTexture,
+    insets: Edges<UPx>,
+    opacity: ZeroToOne,
+) {
+    let texture_size = texture.size();
+    let area = context.gfx.size();
+    let columns = nine_patch_slices(insets.left, insets.right, texture_size.width, area.width);
+    let rows = nine_patch_slices(insets.top, insets.bottom, texture_size.height, area.height);
+    for (source_y, dest_y) in &rows {
+        for (source_x, dest_x) in &columns {
+            if source_x.is_empty() || source_y.is_empty() || dest_x.is_empty() || dest_y.is_empty()
+            {
+                continue;
+            }
+
+            let source = Rect::new(
+                Point::new(source_x.start, source_y.start),
+                Size::new(source_x.end - source_x.start, source_y.end - source_y.start),
+            );
+            let dest = Rect::new(
+                Point::new(dest_x.start, dest_y.start),
+                Size::new(dest_x.end - dest_x.start, dest_y.end - dest_y.start),
+            )
+            .into_signed();
+            context.gfx.draw_textured_shape(
+                &Shape::textured_round_rect(dest, CornerRadii::ZERO, source, Color::WHITE),
+                texture,
+                opacity,
+            );
+        }
+    }
+}
+
+/// Returns the source and destination ranges of the three nine-patch slices
+/// along a single axis.
+fn nine_patch_slices(
+    start: UPx,
+    end: UPx,
+    source: UPx,
+    dest: UPx,
+) -> [(Range<UPx>, Range<UPx>); 3] {
+    let start = start.min(source);
+    let end = end.min(source - start);
+    let fixed = start + end;
+    // When there isn't enough room for both fixed slices, they are shrunk
+    // proportionally.
+    let (dest_start, dest_end) = if fixed > dest {
+        let dest_start =
+            (dest.into_signed() * (start.into_float() / fixed.into_float())).into_unsigned();
+        (dest_start, dest - dest_start)
+    } else {
+        (start, end)
+    };
+    [
+        (UPx::ZERO..start, UPx::ZERO..dest_start),
+        (start..source - end, dest_start..dest - dest_end),
+        (source - end..source, dest - dest_end..dest),
+    ]
+}
+
 /// A scaling strategy for an [`Image`] widget.
+///
+/// To display an image at its original size centered within the available
+/// space, combine [`Image::scaled`] with
+/// [`MakeWidget::centered`](crate::widget::MakeWidget::centered).
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ImageScaling {
     /// Scales the image keeping the aspect ratio the same.
@@ -182,6 +372,17 @@ pub enum ImageScaling {
     /// In this mode, the widget will request that its size be the size of the
     /// contained image.
     Scale(f32),
+
+    /// The image is repeated at its original size to fill the surface,
+    /// starting at the top-left corner. Tiles that extend beyond the surface
+    /// are clipped.
+    Tile,
+
+    /// The image is divided into nine regions using the contained insets,
+    /// measured in pixels of the source image. The corners are drawn at their
+    /// original size while the edges and center are stretched to fill the
+    /// surface.
+    NinePatch(Edges<UPx>),
 }
 
 impl ImageScaling {
@@ -240,7 +441,9 @@ impl ImageScaling {
 
                 Rect::new(Point::new(x, y), scaled)
             }
-            ImageScaling::Stretch => available_space.into(),
+            ImageScaling::Stretch | ImageScaling::Tile | ImageScaling::NinePatch(_) => {
+                available_space.into()
+            }
             ImageScaling::Scale(factor) => {
                 let size = image_size.map(|px| px * *factor);
                 size.into()
@@ -286,6 +489,17 @@ impl IntoValue<AnyTexture> for TextureRegion {
     }
 }
 
+/// The status of an [`Image`] that is being loaded in the background.
+#[derive(Debug, Clone)]
+pub enum ImageLoadStatus {
+    /// The image is still being loaded.
+    Loading,
+    /// The image was loaded successfully and is being displayed.
+    Loaded,
+    /// The image could not be loaded.
+    Failed(Arc<ImageError>),
+}
+
 /// An aspect mode for scaling an [`Image`].
 #[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Aspect {
@@ -305,3 +519,31 @@ define_components! {
         ImageCornerRadius(CornerRadii<Dimension>, "corner_radius", CornerRadii::ZERO)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use figures::units::UPx;
+
+    use super::nine_patch_slices;
+
+    #[test]
+    fn nine_patch_stretches_center() {
+        let [start, middle, end] =
+            nine_patch_slices(UPx::new(2), UPx::new(3), UPx::new(10), UPx::new(20));
+        assert_eq!(start, (UPx::new(0)..UPx::new(2), UPx::new(0)..UPx::new(2)));
+        assert_eq!(
+            middle,
+            (UPx::new(2)..UPx::new(7), UPx::new(2)..UPx::new(17))
+        );
+        assert_eq!(end, (UPx::new(7)..UPx::new(10), UPx::new(17)..UPx::new(20)));
+    }
+
+    #[test]
+    fn nine_patch_shrinks_insets() {
+        let [start, middle, end] =
+            nine_patch_slices(UPx::new(4), UPx::new(4), UPx::new(10), UPx::new(4));
+        assert_eq!(start, (UPx::new(0)..UPx::new(4), UPx::new(0)..UPx::new(2)));
+        assert!(middle.1.is_empty());
+        assert_eq!(end, (UPx::new(6)..UPx::new(10), UPx::new(2)..UPx::new(4)));
+    }
+}
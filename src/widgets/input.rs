@@ -51,6 +51,7 @@ pub struct Input<Storage = String> {
     mouse_buttons_down: usize,
     line_navigation_x_target: Option<Px>,
     window_focused: bool,
+    multiline: bool,
 }
 
 #[derive(Eq, PartialEq, Clone, Copy)]
@@ -124,9 +125,27 @@ where
             needs_to_select_all: false,
             line_navigation_x_target: None,
             window_focused: false,
+            multiline: false,
         }
     }
 
+    /// Configures this input to accept multiple lines of text and returns
+    /// self.
+    ///
+    /// In multi-line mode, pressing Enter inserts a new line rather than being
+    /// ignored. Text is wrapped to the width of the widget, and the up and down
+    /// arrow keys move the cursor between the wrapped lines.
+    ///
+    /// A multi-line input grows vertically to fit its contents. To limit its
+    /// height, place it inside of a
+    /// [vertical scroll](crate::widget::MakeWidget::vertical_scroll) with a
+    /// constrained height.
+    #[must_use]
+    pub fn multiline(mut self) -> Self {
+        self.multiline = true;
+        self
+    }
+
     /// Sets the `placeholder` text, which is displayed when the field has an
     /// empty value.
     pub fn placeholder(mut self, placeholder: impl IntoValue<MaybeLocalized>) -> Self {
@@ -169,6 +188,26 @@ where
         });
     }
 
+    fn select_word_at(&mut self, cursor: Cursor) {
+        self.value.map_ref(|value| {
+            let text = value.as_str();
+            if let Some((start, word)) = text
+                .split_word_bound_indices()
+                .find(|(start, word)| cursor.offset < start + word.len())
+                .or_else(|| text.split_word_bound_indices().last())
+            {
+                self.selection.start = Some(Cursor {
+                    offset: start,
+                    affinity: Affinity::Before,
+                });
+                self.selection.cursor = Cursor {
+                    offset: start + word.len(),
+                    affinity: Affinity::After,
+                };
+            }
+        });
+    }
+
     fn forward_delete(&mut self, context: &mut EventContext<'_>) {
         if !context.enabled() {
             return;
@@ -410,14 +449,16 @@ where
             return;
         }
 
-        self.map_selected_text(|text| {
-            if let Some(mut clipboard) = context.cushy().clipboard_guard() {
-                match clipboard.set_text(text) {
-                    Ok(()) => {}
-                    Err(err) => tracing::error!("error copying to clipboard: {err}"),
-                }
-            }
-        });
+        self.map_selected_text(|text| context.cushy().set_clipboard_text(text));
+    }
+
+    fn cut_selection_to_clipboard(&mut self, context: &mut EventContext<'_>) {
+        if self.is_masked() || !context.enabled() || self.selection.start.is_none() {
+            return;
+        }
+
+        self.copy_selection_to_clipboard(context);
+        self.replace_selection("", context);
     }
 
     fn replace_selection(&mut self, new_text: &str, context: &mut EventContext<'_>) {
@@ -448,20 +489,11 @@ where
             return false;
         }
 
-        match context
-            .cushy()
-            .clipboard_guard()
-            .map(|mut clipboard| clipboard.get_text())
-        {
-            Some(Ok(text)) => {
-                self.replace_selection(&text, context);
-                true
-            }
-            None | Some(Err(arboard::Error::ConversionFailure)) => false,
-            Some(Err(err)) => {
-                tracing::error!("error retrieving clipboard contents: {err}");
-                false
-            }
+        if let Some(text) = context.cushy().clipboard_text() {
+            self.replace_selection(&text, context);
+            true
+        } else {
+            false
         }
     }
 
@@ -524,6 +556,12 @@ where
                 }
                 HANDLED
             }
+            (state, _, Some("x")) if context.modifiers().primary() => {
+                if state.is_pressed() {
+                    self.cut_selection_to_clipboard(context);
+                }
+                HANDLED
+            }
             (state, _, Some("v")) if context.modifiers().primary() => {
                 if state.is_pressed() {
                     self.paste_from_clipboard(context);
@@ -531,6 +569,14 @@ where
 
                 HANDLED
             }
+            (state, Key::Named(NamedKey::Enter), _)
+                if self.multiline && !context.modifiers().primary() =>
+            {
+                if state.is_pressed() {
+                    self.replace_selection("\n", context);
+                }
+                HANDLED
+            }
             (state, _, Some(text))
                 if !context.modifiers().primary()
                     && text != "\t" // tab
@@ -987,8 +1033,16 @@ where
         self.mouse_buttons_down += 1;
         context.focus();
         self.needs_to_select_all = false;
-        self.selection.cursor = self.cursor_from_point(location, context);
-        self.selection.start = Some(self.selection.cursor);
+
+        let cursor = self.cursor_from_point(location, context);
+        match context.click_count() {
+            0 | 1 => {
+                self.selection.cursor = cursor;
+                self.selection.start = Some(cursor);
+            }
+            2 => self.select_word_at(cursor),
+            _ => self.select_all(),
+        }
         context.set_needs_redraw();
         HANDLED
     }
@@ -1,25 +1,60 @@
 //! A read-only text widget.
 
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Write};
+use std::ops::Range;
 
 use figures::units::{Px, UPx};
-use figures::{IntoUnsigned, Point, Round, Size, Zero};
+use figures::{IntoUnsigned, Point, Rect, Round, Size, Zero};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::window::CursorIcon;
+use kludgine::shapes::Shape;
 use kludgine::text::{MeasuredText, Text, TextOrigin};
 use kludgine::{cosmic_text, CanRenderTo, Color, DrawableExt};
 
 use super::input::CowString;
-use crate::context::{FontSettings, GraphicsContext, LayoutContext, Trackable, WidgetContext};
+use crate::context::{
+    EventContext, FontSettings, GraphicsContext, LayoutContext, Trackable, WidgetContext,
+};
 use crate::reactive::value::{
     Dynamic, DynamicReader, Generation, IntoDynamic, IntoReadOnly, IntoValue, ReadOnly, Value,
 };
-use crate::styles::components::{HorizontalAlignment, TextColor, VerticalAlignment};
+use crate::styles::components::{
+    HighlightColor, HorizontalAlignment, TextColor, VerticalAlignment,
+};
 use crate::styles::{HorizontalAlign, VerticalAlign};
-use crate::widget::{MakeWidgetWithTag, Widget, WidgetInstance, WidgetTag};
-use crate::window::WindowLocal;
+use crate::utils::ModifiersExt;
+use crate::widget::{
+    EventHandling, MakeWidgetWithTag, Widget, WidgetInstance, WidgetTag, HANDLED, IGNORED,
+};
+use crate::widgets::layers::{OverlayLayer, Tooltipped};
+use crate::widgets::{RichLabel, RichText};
+use crate::window::{DeviceId, KeyEvent, WindowLocal};
 use crate::{ConstraintLimit, FitMeasuredSize};
 
 /// A read-only text widget.
+///
+/// # Alignment and overflow
+///
+/// Text is aligned using the [`HorizontalAlignment`] and [`VerticalAlignment`]
+/// components. [`HorizontalAlign::Justify`] stretches the spacing between
+/// words so that each wrapped line fills the label's width.
+///
+/// By default, text that is too wide to fit is wrapped at word boundaries.
+/// [`Label::overflow`] can be used to instead [clip](LabelOverflow::Clip)
+/// the text or to [truncate it with an ellipsis](LabelOverflow::Ellipsis).
+/// [`Label::tooltip_when_truncated`] shows the full text in a tooltip when
+/// it has been truncated.
+///
+/// # Selecting text
+///
+/// By default, the text of a label cannot be selected. [`Label::selectable`]
+/// allows the user to select text by dragging with the mouse and to copy the
+/// selection to the clipboard using Ctrl+C (Cmd+C on Apple platforms).
+/// Selectable labels receive focus when clicked, but are skipped when
+/// navigating focus using the keyboard unless [`Label::focusable`] is also
+/// used.
 #[derive(Debug)]
 pub struct Label<T> {
     /// The contents of the label.
@@ -29,6 +64,11 @@ pub struct Label<T> {
     pub overflow: Value<LabelOverflow>,
     displayed: String,
     prepared_text: WindowLocal<LabelCache>,
+    selectable: bool,
+    focusable: bool,
+    focus_from_click: bool,
+    selection: Option<LabelSelection>,
+    truncation: Option<LabelTruncation>,
 }
 
 impl<T> Label<T>
@@ -43,9 +83,34 @@ where
             overflow: Value::Constant(LabelOverflow::WordWrap),
             displayed: String::new(),
             prepared_text: WindowLocal::default(),
+            selectable: false,
+            focusable: false,
+            focus_from_click: false,
+            selection: None,
+            truncation: None,
         }
     }
 
+    /// Allows the text of this label to be selected and copied, and returns
+    /// self.
+    ///
+    /// See [Selecting text](Self#selecting-text) for more information.
+    #[must_use]
+    pub fn selectable(mut self) -> Self {
+        self.selectable = true;
+        self
+    }
+
+    /// Allows this label to receive focus when navigating using the keyboard
+    /// and returns self.
+    ///
+    /// This also makes the label [selectable](Self::selectable).
+    #[must_use]
+    pub fn focusable(mut self) -> Self {
+        self.focusable = true;
+        self.selectable()
+    }
+
     /// Sets the behavior when more text than can fit on a single line is
     /// displayed.
     #[must_use]
@@ -54,6 +119,21 @@ where
         self
     }
 
+    /// Returns a widget that shows the full text of this label in a tooltip
+    /// on `layer` while the text is truncated.
+    ///
+    /// This sets this label's overflow to [`LabelOverflow::Ellipsis`].
+    pub fn tooltip_when_truncated(mut self, layer: &OverlayLayer) -> Tooltipped {
+        let truncated = Dynamic::new(false);
+        let full_text = Dynamic::<String>::default();
+        self.overflow = Value::Constant(LabelOverflow::Ellipsis);
+        self.truncation = Some(LabelTruncation {
+            truncated: truncated.clone(),
+            full_text: full_text.clone(),
+        });
+        layer.new_tooltip(full_text, self).when(truncated)
+    }
+
     fn prepared_text(
         &mut self,
         context: &mut GraphicsContext<'_, '_, '_, '_>,
@@ -65,6 +145,7 @@ where
             HorizontalAlign::Left => cosmic_text::Align::Left,
             HorizontalAlign::Center => cosmic_text::Align::Center,
             HorizontalAlign::Right => cosmic_text::Align::Right,
+            HorizontalAlign::Justify => cosmic_text::Align::Justified,
         };
         let overflow = self.overflow.get_tracking_invalidate(context);
         if overflow == LabelOverflow::Clip {
@@ -79,29 +160,37 @@ where
             color,
             settings: context.current_font_settings(),
             align,
+            overflow,
         };
 
         match self.prepared_text.get(context) {
             Some(cache)
                 if cache.text.can_render_to(&context.gfx) && cache_key.is_valid_for(cache) => {}
             _ => {
-                let (measured, display_generation) = self.display.map(|text| {
-                    self.displayed.clear();
-                    if let Err(err) = write!(&mut self.displayed, "{}", text.as_display(context)) {
+                let display_generation = self.display.map(|text| {
+                    let mut displayed = String::with_capacity(self.displayed.len());
+                    if let Err(err) = write!(&mut displayed, "{}", text.as_display(context)) {
                         tracing::error!("Error invoking Display: {err}");
                     }
-                    (
-                        context
-                            .gfx
-                            .measure_text(Text::new(&self.displayed, color).align(align, width)),
-                        text.generation(context),
-                    )
+                    if displayed != self.displayed {
+                        self.displayed = displayed;
+                        self.selection = None;
+                    }
+                    text.generation(context)
                 });
+                let measured = if overflow == LabelOverflow::Ellipsis {
+                    self.measure_with_ellipsis(context, color, width, align)
+                } else {
+                    context
+                        .gfx
+                        .measure_text(Text::new(&self.displayed, color).align(align, width))
+                };
                 cache_key.display_generation = display_generation;
                 self.prepared_text.set(
                     context,
                     LabelCache {
                         text: measured,
+                        offset: Px::ZERO,
                         key: cache_key,
                     },
                 );
@@ -113,6 +202,108 @@ where
             .map(|cache| &cache.text)
             .expect("always initialized")
     }
+
+    fn measure_with_ellipsis(
+        &self,
+        context: &mut GraphicsContext<'_, '_, '_, '_>,
+        color: Color,
+        width: Px,
+        align: cosmic_text::Align,
+    ) -> MeasuredText<Px> {
+        let measured: MeasuredText<Px> =
+            context.gfx.measure_text(Text::new(&self.displayed, color));
+        // Text containing multiple lines never fits on a single line, even if
+        // each line is narrow enough.
+        let fits = measured.size.width <= width
+            && measured.glyphs.iter().all(|glyph| glyph.info.line == 0);
+        if let Some(truncation) = &self.truncation {
+            truncation.truncated.set(!fits);
+            if !fits {
+                truncation.full_text.set(self.displayed.clone());
+            }
+        }
+
+        if fits {
+            return if align == cosmic_text::Align::Left {
+                measured
+            } else {
+                context
+                    .gfx
+                    .measure_text(Text::new(&self.displayed, color).align(align, width))
+            };
+        }
+
+        // Keep as many glyphs from the first line as will fit alongside the
+        // ellipsis.
+        let ellipsis: MeasuredText<Px> = context.gfx.measure_text(Text::new(ELLIPSIS, color));
+        let available = width - ellipsis.size.width;
+        let end = measured
+            .glyphs
+            .iter()
+            .take_while(|glyph| {
+                let rect = glyph.rect();
+                glyph.info.line == 0 && rect.origin.x + rect.size.width <= available
+            })
+            .last()
+            .map_or(0, |glyph| glyph.info.end);
+        let mut truncated = self
+            .displayed
+            .get(..end)
+            .unwrap_or_default()
+            .trim_end()
+            .to_string();
+        truncated.push_str(ELLIPSIS);
+        context
+            .gfx
+            .measure_text(Text::new(&truncated, color).align(align, width))
+    }
+
+    fn offset_at(&self, location: Point<Px>, context: &WidgetContext<'_>) -> Option<usize> {
+        let cache = self.prepared_text.get(context)?;
+        let location = location - Point::new(Px::ZERO, cache.offset);
+        if location.y < 0 {
+            return Some(0);
+        }
+
+        let line_height = cache.text.line_height.get().max(1);
+        let line = usize::try_from(location.y.get() / line_height).unwrap_or(usize::MAX);
+        let mut offset = 0;
+        for glyph in &cache.text.glyphs {
+            match glyph.info.line.cmp(&line) {
+                Ordering::Less => offset = glyph.info.end,
+                Ordering::Equal => {
+                    let rect = glyph.rect();
+                    if location.x < rect.origin.x + rect.size.width / 2 {
+                        return Some(glyph.info.start);
+                    }
+                    offset = glyph.info.end;
+                }
+                Ordering::Greater => break,
+            }
+        }
+        Some(offset)
+    }
+
+    fn copy_selection_to_clipboard(&self, context: &mut EventContext<'_>) {
+        if let Some(text) = self
+            .selection
+            .and_then(|selection| self.displayed.get(selection.range()))
+            .filter(|text| !text.is_empty())
+        {
+            if !context.cushy().set_clipboard_text(text) {
+                tracing::warn!("unable to copy the selected text to the clipboard");
+            }
+        }
+    }
+}
+
+impl Label<String> {
+    /// Returns a [`RichLabel`] that displays `text`, whose spans can each be
+    /// styled differently.
+    #[must_use]
+    pub fn rich(text: impl IntoReadOnly<RichText>) -> RichLabel {
+        RichLabel::new(text)
+    }
 }
 
 impl<T> Widget for Label<T>
@@ -127,16 +318,38 @@ where
 
         let text_color = context.get(&TextColor);
 
-        let prepared_text =
-            self.prepared_text(context, text_color, context.gfx.region().size.width, align);
+        let size = context.gfx.region().size;
+        self.prepared_text(context, text_color, size.width, align);
+        let Some(cache) = self.prepared_text.get_mut(context) else {
+            return;
+        };
 
+        let prepared_text = &cache.text;
         let y_offset = match valign {
             VerticalAlign::Top => Px::ZERO,
-            VerticalAlign::Center => {
-                (context.gfx.region().size.height - prepared_text.size.height) / 2
-            }
-            VerticalAlign::Bottom => context.gfx.region().size.height - prepared_text.size.height,
+            VerticalAlign::Center => (size.height - prepared_text.size.height) / 2,
+            VerticalAlign::Bottom => size.height - prepared_text.size.height,
         };
+        cache.offset = y_offset;
+
+        if let Some(selection) = self.selection {
+            let selected = selection.range();
+            let highlight = context.get(&HighlightColor);
+            let line_height = prepared_text.line_height;
+            for glyph in &prepared_text.glyphs {
+                if glyph.info.start >= selected.start && glyph.info.end <= selected.end {
+                    let rect = glyph.rect();
+                    let line = Px::new(i32::try_from(glyph.info.line).unwrap_or(i32::MAX));
+                    context.gfx.draw_shape(&Shape::filled_rect(
+                        Rect::new(
+                            Point::new(rect.origin.x, y_offset + line_height.saturating_mul(line)),
+                            Size::new(rect.size.width, line_height),
+                        ),
+                        highlight,
+                    ));
+                }
+            }
+        }
 
         context.gfx.draw_measured_text(
             prepared_text.translate_by(Point::new(Px::ZERO, y_offset)),
@@ -157,6 +370,107 @@ where
         available_space.fit_measured(prepared.size.into_unsigned().ceil())
     }
 
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        self.selectable
+    }
+
+    fn hover(
+        &mut self,
+        _location: Point<Px>,
+        _context: &mut EventContext<'_>,
+    ) -> Option<CursorIcon> {
+        self.selectable.then_some(CursorIcon::Text)
+    }
+
+    fn accept_focus(&mut self, _context: &mut EventContext<'_>) -> bool {
+        self.focusable || self.focus_from_click
+    }
+
+    fn focus(&mut self, _context: &mut EventContext<'_>) {
+        self.focus_from_click = false;
+    }
+
+    fn blur(&mut self, context: &mut EventContext<'_>) {
+        self.focus_from_click = false;
+        if self.selection.take().is_some() {
+            context.set_needs_redraw();
+        }
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if !self.selectable || button != MouseButton::Left {
+            return IGNORED;
+        }
+
+        let Some(offset) = self.offset_at(location, context) else {
+            return IGNORED;
+        };
+        self.selection = Some(LabelSelection {
+            anchor: offset,
+            cursor: offset,
+        });
+        self.focus_from_click = true;
+        context.focus();
+        context.set_needs_redraw();
+        HANDLED
+    }
+
+    fn mouse_drag(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) {
+        let Some(offset) = self.offset_at(location, context) else {
+            return;
+        };
+        if let Some(selection) = &mut self.selection {
+            if selection.cursor != offset {
+                selection.cursor = offset;
+                context.set_needs_redraw();
+            }
+        }
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if !self.selectable || !context.modifiers().primary() {
+            return IGNORED;
+        }
+
+        match input.text.as_deref() {
+            Some("c") => {
+                if input.state.is_pressed() {
+                    self.copy_selection_to_clipboard(context);
+                }
+                HANDLED
+            }
+            Some("a") => {
+                if input.state.is_pressed() {
+                    self.selection = Some(LabelSelection {
+                        anchor: 0,
+                        cursor: self.displayed.len(),
+                    });
+                    context.set_needs_redraw();
+                }
+                HANDLED
+            }
+            _ => IGNORED,
+        }
+    }
+
     fn summarize(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         fmt.debug_tuple("Label").field(&self.display).finish()
     }
@@ -208,14 +522,41 @@ pub enum LabelOverflow {
     /// Wraps text at the boundaries between words and whitespace while
     /// attaching punctuation to the non-wrapped word when possible.
     WordWrap,
+    /// Text that cannot be drawn on a single line is truncated and an ellipsis
+    /// (`…`) is appended. Text containing line breaks is truncated after its
+    /// first line.
+    Ellipsis,
 }
 
+const ELLIPSIS: &str = "…";
+
 #[derive(Debug)]
 struct LabelCache {
     text: MeasuredText<Px>,
+    offset: Px,
     key: LabelCacheKey,
 }
 
+/// A range of selected text in a [`Label`], as byte offsets into the displayed
+/// text.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct LabelSelection {
+    anchor: usize,
+    cursor: usize,
+}
+
+impl LabelSelection {
+    fn range(self) -> Range<usize> {
+        self.anchor.min(self.cursor)..self.anchor.max(self.cursor)
+    }
+}
+
+#[derive(Debug)]
+struct LabelTruncation {
+    truncated: Dynamic<bool>,
+    full_text: Dynamic<String>,
+}
+
 #[derive(Debug)]
 struct LabelCacheKey {
     generation: Option<Generation>,
@@ -224,6 +565,7 @@ struct LabelCacheKey {
     color: Color,
     settings: FontSettings,
     align: cosmic_text::Align,
+    overflow: LabelOverflow,
 }
 
 impl LabelCacheKey {
@@ -233,8 +575,9 @@ impl LabelCacheKey {
             && self.color == cache.key.color
             && self.settings == cache.key.settings
             && self.align == cache.key.align
+            && self.overflow == cache.key.overflow
         {
-            if self.align == cosmic_text::Align::Left {
+            if self.align == cosmic_text::Align::Left && self.overflow != LabelOverflow::Ellipsis {
                 self.width <= cache.key.width && cache.text.size.width <= self.width
             } else {
                 self.width == cache.key.width
@@ -1,6 +1,7 @@
 //! Widgets that stack in the Z-direction.
 
 use std::fmt::{self, Debug};
+use std::future::Future;
 use std::marker::PhantomData;
 use std::time::Duration;
 
@@ -15,10 +16,10 @@ use super::super::widget::MountedWidget;
 use super::{Custom, Space};
 use crate::animation::{AnimationHandle, AnimationTarget, IntoAnimate, Spawn, ZeroToOne};
 use crate::context::{AsEventContext, EventContext, GraphicsContext, LayoutContext, Trackable};
+use crate::dialog::{ButtonBehavior, ShouldClose};
 use crate::reactive::value::{
     Destination, Dynamic, DynamicGuard, DynamicRead, IntoValue, Source, Value,
 };
-use crate::dialog::{ButtonBehavior, ShouldClose};
 use crate::styles::components::{EasingIn, ScrimColor};
 use crate::widget::{
     Callback, MakeWidget, MakeWidgetWithTag, MountedChildren, SharedCallback, Widget, WidgetId,
@@ -54,9 +55,7 @@ impl Widget for Layers {
     fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
         self.synchronize_children(&mut context.as_event_context());
 
-        for mounted in self.mounted.children() {
-            context.for_other(mounted).redraw();
-        }
+        context.redraw_children(self.mounted.children());
     }
 
     fn summarize(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -152,6 +151,8 @@ impl OverlayLayer {
                 widget: WidgetRef::new(overlay),
                 relative_to: None,
                 positioning: Position::Relative(Direction::Right),
+                alignment: OverlayAlign::Center,
+                z_index: 0,
                 requires_hover: false,
                 on_dismiss: None,
                 layout: None,
@@ -170,6 +171,8 @@ impl OverlayLayer {
                 direction: Direction::Down,
                 shown_tooltip: Dynamic::default(),
             },
+            delay: Value::Constant(Duration::from_millis(500)),
+            enabled: Value::Constant(true),
             show_animation: None,
         }
     }
@@ -194,7 +197,15 @@ impl Widget for OverlayLayer {
         self.easing.set(context.get(&EasingIn));
         let state = self.state.lock();
 
-        for child in &state.overlays {
+        // Overlays are drawn in order of their z-index, with overlays sharing
+        // the same z-index drawn in the order they were shown. Because hit
+        // testing follows the order widgets are drawn, the topmost overlay
+        // also receives events first.
+        let mut draw_order = (0..state.overlays.len()).collect::<Vec<_>>();
+        draw_order.sort_by_key(|&index| state.overlays[index].z_index);
+
+        for index in draw_order {
+            let child = &state.overlays[index];
             let Some(mounted) = child.widget.as_mounted(context) else {
                 continue;
             };
@@ -399,6 +410,7 @@ impl OverlayState {
         relative_to: WidgetId,
     ) -> Option<Rect<Px>> {
         let positioning = self.overlays[index].positioning;
+        let alignment = self.overlays[index].alignment;
         let relative_to = relative_to.find_in(context)?.last_layout()?;
         let relative_to_unsigned = relative_to.into_unsigned();
 
@@ -440,14 +452,14 @@ impl OverlayState {
             let (origin, intersection_matters) = match layout_direction {
                 Position::Relative(Direction::Up) => (
                     Point::new(
-                        relative_to.origin.x + relative_to.size.width / 2 - size.width / 2,
+                        alignment.align(relative_to.origin.x, relative_to.size.width, size.width),
                         relative_to.origin.y - size.height,
                     ),
                     true,
                 ),
                 Position::Relative(Direction::Down) => (
                     Point::new(
-                        relative_to.origin.x + relative_to.size.width / 2 - size.width / 2,
+                        alignment.align(relative_to.origin.x, relative_to.size.width, size.width),
                         relative_to.origin.y + relative_to.size.height,
                     ),
                     true,
@@ -455,14 +467,14 @@ impl OverlayState {
                 Position::Relative(Direction::Left) => (
                     Point::new(
                         relative_to.origin.x - size.width,
-                        relative_to.origin.y + relative_to.size.height / 2 - size.height / 2,
+                        alignment.align(relative_to.origin.y, relative_to.size.height, size.height),
                     ),
                     true,
                 ),
                 Position::Relative(Direction::Right) => (
                     Point::new(
                         relative_to.origin.x + relative_to.size.width,
-                        relative_to.origin.y + relative_to.size.height / 2 - size.height / 2,
+                        alignment.align(relative_to.origin.y, relative_to.size.height, size.height),
                     ),
                     true,
                 ),
@@ -569,7 +581,10 @@ impl OverlayState {
                     (available_space.width - size.width) / 2,
                     available_space.height / 2,
                 ),
-                Position::At(pt) => pt,
+                Position::At(pt) => Point::new(
+                    flip_to_fit(pt.x, size.width, available_space.width),
+                    flip_to_fit(pt.y, size.height, available_space.height),
+                ),
             };
 
             Some(Rect::new(origin, size))
@@ -577,6 +592,19 @@ impl OverlayState {
     }
 }
 
+/// Returns the start of a span of `size` that begins at `start`, flipping it
+/// to end at `start` instead if it would extend past `available`. If neither
+/// fits, the span is shifted to remain as visible as possible.
+fn flip_to_fit(start: Px, size: Px, available: Px) -> Px {
+    if start + size <= available {
+        start
+    } else if start - size >= 0 {
+        start - size
+    } else {
+        (available - size).max(Px::ZERO)
+    }
+}
+
 /// A type that is being prepared to be shown in an [`OverlayLayer`].
 pub trait Overlayable: Sized {
     /// The resulting handle type when this overlay is shown.
@@ -612,9 +640,40 @@ pub trait Overlayable: Sized {
     fn near(self, id: WidgetId, direction: Direction) -> Self;
 
     /// Shows this overlay at a specified window `location`.
+    ///
+    /// If the overlay would extend past the right or bottom edge of the
+    /// layer, it is flipped to the other side of `location`.
     #[must_use]
     fn at(self, location: Point<Px>) -> Self;
 
+    /// Sets how this overlay is aligned along the edge of the widget it is
+    /// shown [near](Self::near).
+    ///
+    /// By default, overlays are centered along the edge of the widget.
+    ///
+    /// The default implementation ignores `alignment` and returns self, for
+    /// overlay types that do not support alignment.
+    #[must_use]
+    fn aligned(self, alignment: OverlayAlign) -> Self {
+        let _ = alignment;
+        self
+    }
+
+    /// Sets the order this overlay is drawn in relative to other overlays in
+    /// the same layer.
+    ///
+    /// Overlays with a higher z-index are drawn above and receive events
+    /// before overlays with a lower z-index. Overlays with the same z-index,
+    /// including the default of `0`, are drawn in the order they are shown.
+    ///
+    /// The default implementation ignores `z_index` and returns self, for
+    /// overlay types that do not support ordering.
+    #[must_use]
+    fn z_index(self, z_index: i32) -> Self {
+        let _ = z_index;
+        self
+    }
+
     /// Sets `callback` to be invoked once this overlay is dismissed.
     #[must_use]
     fn on_dismiss(self, callback: Callback) -> Self;
@@ -670,6 +729,16 @@ impl Overlayable for OverlayBuilder<'_> {
         self
     }
 
+    fn aligned(mut self, alignment: OverlayAlign) -> Self {
+        self.layout.alignment = alignment;
+        self
+    }
+
+    fn z_index(mut self, z_index: i32) -> Self {
+        self.layout.z_index = z_index;
+        self
+    }
+
     fn on_dismiss(mut self, callback: Callback) -> Self {
         self.layout.on_dismiss = Some(SharedCallback::from(callback));
         self
@@ -705,6 +774,8 @@ struct OverlayLayout {
     opacity: Dynamic<ZeroToOne>,
     relative_to: Option<WidgetId>,
     positioning: Position<Px>,
+    alignment: OverlayAlign,
+    z_index: i32,
     requires_hover: bool,
     layout: Option<Rect<Px>>,
     on_dismiss: Option<SharedCallback>,
@@ -726,6 +797,8 @@ impl PartialEq for OverlayLayout {
             && self.opacity == other.opacity
             && self.relative_to == other.relative_to
             && self.positioning == other.positioning
+            && self.alignment == other.alignment
+            && self.z_index == other.z_index
             && self.requires_hover == other.requires_hover
             && self.layout == other.layout
             && self.on_dismiss == other.on_dismiss
@@ -752,6 +825,29 @@ impl<T> Position<T> {
     }
 }
 
+/// The alignment of an overlay along the edge of the widget it is shown
+/// relative to.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum OverlayAlign {
+    /// Align the overlay with the left or top edge of the widget.
+    Start,
+    /// Center the overlay along the edge of the widget.
+    #[default]
+    Center,
+    /// Align the overlay with the right or bottom edge of the widget.
+    End,
+}
+
+impl OverlayAlign {
+    fn align(self, start: Px, length: Px, size: Px) -> Px {
+        match self {
+            OverlayAlign::Start => start,
+            OverlayAlign::Center => start + length / 2 - size / 2,
+            OverlayAlign::End => start + length - size,
+        }
+    }
+}
+
 /// A relative direction.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Direction {
@@ -826,13 +922,50 @@ impl Debug for OverlayHandle {
 }
 
 /// A widget that shows a tooltip when hovered.
+///
+/// The tooltip is shown once the cursor has hovered the widget for the
+/// configured [delay](Self::delay), and it is hidden as soon as the cursor
+/// leaves both the widget and the tooltip. The tooltip may be any widget, and
+/// it is positioned next to this widget in the configured
+/// [direction](Self::direction), moving as needed to remain within the
+/// [`OverlayLayer`].
 #[derive(Debug)]
 pub struct Tooltipped {
     child: WidgetRef,
+    delay: Value<Duration>,
+    enabled: Value<bool>,
     show_animation: Option<AnimationHandle>,
     data: TooltipData,
 }
 
+impl Tooltipped {
+    /// Sets the amount of time the cursor must hover this widget before the
+    /// tooltip is shown, and returns self.
+    ///
+    /// The default delay is 500 milliseconds.
+    #[must_use]
+    pub fn delay(mut self, delay: impl IntoValue<Duration>) -> Self {
+        self.delay = delay.into_value();
+        self
+    }
+
+    /// Sets the side of this widget the tooltip is shown on, and returns self.
+    ///
+    /// Tooltips are shown below their widget by default.
+    #[must_use]
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.data.direction = direction;
+        self
+    }
+
+    /// Only shows the tooltip while `enabled` is true, and returns self.
+    #[must_use]
+    pub fn when(mut self, enabled: impl IntoValue<bool>) -> Self {
+        self.enabled = enabled.into_value();
+        self
+    }
+}
+
 #[derive(Debug, Clone)]
 struct TooltipData {
     target_layer: OverlayLayer,
@@ -851,13 +984,18 @@ impl WrapperWidget for Tooltipped {
         _location: Point<Px>,
         context: &mut EventContext<'_>,
     ) -> Option<kludgine::app::winit::window::CursorIcon> {
+        if !self.enabled.get() {
+            return None;
+        }
+
         let background_color = context.theme().surface.highest_container;
 
         let data = self.data.clone();
         let my_id = self.child.widget().id();
 
         self.show_animation = Some(
-            Duration::from_millis(500)
+            self.delay
+                .get()
                 .on_complete(move || {
                     let mut shown_tooltip = data.shown_tooltip.lock();
                     if shown_tooltip.is_none() {
@@ -1006,7 +1144,8 @@ impl WrapperWidget for ModalLayer {
             if presented != modal_widget {
                 let modal_widget = modal_widget.clone();
                 *presented = modal_widget.clone();
-                layer_widgets[index * 2 + 1] = modal_widget.clone().centered().make_widget();
+                layer_widgets[index * 2 + 1] =
+                    FocusTrap::new(modal_widget.clone().centered()).make_widget();
 
                 self.focus_top_layer = true;
             }
@@ -1018,7 +1157,7 @@ impl WrapperWidget for ModalLayer {
                 Custom::new(Space::colored(context.get(&ScrimColor))).on_hit_test(|_, _| true),
             );
             self.presented.push(to_present.clone());
-            layer_widgets.push(to_present.clone().centered());
+            layer_widgets.push(FocusTrap::new(to_present.clone().centered()));
         }
 
         if self.presented.len() > modal.len() {
@@ -1054,6 +1193,29 @@ impl WrapperWidget for ModalLayer {
     }
 }
 
+/// A widget that prevents keyboard focus from advancing out of its child.
+///
+/// When focus is advanced past the last focusable widget inside of this
+/// widget, focus wraps around to the first focusable widget inside of it.
+#[derive(Debug)]
+pub(crate) struct FocusTrap {
+    child: WidgetRef,
+}
+
+impl FocusTrap {
+    fn new(child: impl MakeWidget) -> Self {
+        Self {
+            child: WidgetRef::new(child),
+        }
+    }
+}
+
+impl WrapperWidget for FocusTrap {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+}
+
 /// A marker type indicating a special [`DialogBuilder`] button type is not
 /// present.
 pub enum No {}
@@ -1242,6 +1404,32 @@ impl ModalHandle {
         &self.layer
     }
 
+    /// Returns a future that completes once the dialog shown by this handle is
+    /// no longer presented.
+    ///
+    /// The dialog can be dismissed using [`dismiss()`](Self::dismiss), by
+    /// dismissing its layer using [`Modal::dismiss()`], or by presenting a
+    /// different dialog in its place. If nothing is presented using this handle
+    /// when the future is polled, it completes immediately.
+    pub fn dismissed(&self) -> impl Future<Output = ()> + Send + 'static {
+        let id = self.id.create_reader();
+        let modal = self.layer.modal.create_reader();
+        async move {
+            loop {
+                let Some(shown) = id.get() else {
+                    return;
+                };
+                if modal.map_ref(|modal| modal.index_of_id(shown).is_none()) {
+                    return;
+                }
+                if !modal.wait_until_updated().await {
+                    // The layer no longer exists.
+                    return;
+                }
+            }
+        }
+    }
+
     /// Returns a builder for a modal dialog that replaces the current contents
     /// of this modal with `message` and presents it.
     pub fn build_dialog(&self, message: impl MakeWidget) -> DialogBuilder {
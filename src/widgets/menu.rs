@@ -1,12 +1,15 @@
 //! Overlay menu widgets.
 
 use std::fmt::Debug;
+use std::mem;
 use std::sync::Arc;
 use std::time::Duration;
 
 use alot::LotId;
 use figures::units::{Px, UPx};
 use figures::{Angle, IntoSigned, Point, Rect, Round, ScreenScale, Size, Zero};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::keyboard::{Key, NamedKey};
 use kludgine::shapes::{PathBuilder, Shape, StrokeOptions};
 use kludgine::DrawableExt;
 
@@ -25,8 +28,9 @@ use crate::styles::components::{
 use crate::styles::Styles;
 use crate::widget::{
     Callback, EventHandling, MakeWidget, MakeWidgetWithTag, SharedNotify, Widget, WidgetId,
-    WidgetInstance, WidgetRef, WidgetTag, HANDLED,
+    WidgetInstance, WidgetRef, WidgetTag, WrapperWidget, HANDLED, IGNORED,
 };
+use crate::window::{DeviceId, KeyEvent};
 use crate::ConstraintLimit;
 
 #[derive(Debug, Clone)]
@@ -164,6 +168,8 @@ where
                     open_id: root_menu,
                     padding: UPx::ZERO,
                     selecting: None,
+                    keyboard_selection: None,
+                    keyboard_opens_submenu: false,
                     hover_location: None,
                     mouse_down: false,
                     layer: overlay.clone(),
@@ -180,6 +186,63 @@ where
     }
 }
 
+/// A widget that shows a [`Menu`] when it is clicked using the right mouse
+/// button.
+///
+/// The menu is shown in an [`OverlayLayer`] at the location of the click. To
+/// show a menu at an arbitrary location instead, use [`Menu::overlay_in`] and
+/// [`Overlayable::at`].
+#[derive(Debug)]
+pub struct ContextMenu<T> {
+    child: WidgetRef,
+    menu: Menu<T>,
+    overlay: OverlayLayer,
+}
+
+impl<T> ContextMenu<T> {
+    /// Returns a widget that shows `menu` in `overlay` when `child` is
+    /// right-clicked.
+    #[must_use]
+    pub fn new(child: impl MakeWidget, menu: Menu<T>, overlay: &OverlayLayer) -> Self {
+        Self {
+            child: WidgetRef::new(child),
+            menu,
+            overlay: overlay.clone(),
+        }
+    }
+}
+
+impl<T> WrapperWidget for ContextMenu<T>
+where
+    T: Unpin + Debug + Send + Clone + 'static,
+{
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        true
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        let (MouseButton::Right, Some(layout)) = (button, context.last_layout()) else {
+            return IGNORED;
+        };
+
+        self.menu
+            .overlay_in(&self.overlay)
+            .at(location + layout.origin)
+            .show();
+        HANDLED
+    }
+}
+
 /// A [`Menu`] that is preparing to be shown in an [`OverlayLayer`].
 pub struct MenuOverlay<'a>(OverlayBuilder<'a>, OpenMenuHandle);
 
@@ -218,6 +281,14 @@ impl Overlayable for MenuOverlay<'_> {
         Self(self.0.at(location), self.1)
     }
 
+    fn aligned(self, alignment: super::layers::OverlayAlign) -> Self {
+        Self(self.0.aligned(alignment), self.1)
+    }
+
+    fn z_index(self, z_index: i32) -> Self {
+        Self(self.0.z_index(z_index), self.1)
+    }
+
     fn on_dismiss(self, callback: Callback) -> Self {
         Self(self.0.on_dismiss(callback), self.1)
     }
@@ -465,6 +536,8 @@ struct OpenMenu<T> {
     open_id: LotId,
     padding: UPx,
     selecting: Option<usize>,
+    keyboard_selection: Option<usize>,
+    keyboard_opens_submenu: bool,
     hover_location: Option<Point<Px>>,
     mouse_down: bool,
     layer: OverlayLayer,
@@ -474,12 +547,85 @@ struct OpenMenu<T> {
     shared: Dynamic<SharedMenuState>,
 }
 impl<T> OpenMenu<T> {
+    fn dismiss_all(&self) {
+        let mut shared = self.shared.lock();
+        for handle in shared.open_menus.drain() {
+            handle.dismiss();
+        }
+    }
+
+    fn select_next(&mut self, forwards: bool, context: &mut EventContext<'_>) {
+        let enabled = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, rendered)| match &rendered.item {
+                ItemKind::Item(item) if item.enabled.get() => Some(index),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if enabled.is_empty() {
+            return;
+        }
+        let count = enabled.len();
+        let current = self
+            .selecting
+            .and_then(|selecting| enabled.iter().position(|index| *index == selecting));
+        let next = match (current, forwards) {
+            (Some(current), true) => (current + 1) % count,
+            (Some(current), false) => (current + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+        self.keyboard_selection = Some(enabled[next]);
+        self.update_visual_state(context);
+        context.set_needs_redraw();
+    }
+
+    fn open_selected_submenu(&mut self, context: &mut EventContext<'_>) -> bool {
+        let Some(index) = self.selecting else {
+            return false;
+        };
+        if self.items[index].submenu().is_none() {
+            return false;
+        }
+        self.keyboard_selection = Some(index);
+        self.keyboard_opens_submenu = true;
+        self.update_visual_state(context);
+        true
+    }
+
+    fn choose_selected(&mut self, context: &mut EventContext<'_>)
+    where
+        T: Clone,
+    {
+        if self.open_selected_submenu(context) {
+            return;
+        }
+        let Some(index) = self.selecting else {
+            return;
+        };
+        let ItemKind::Item(item) = &self.items[index].item else {
+            return;
+        };
+        self.on_click.0.notify(item.value.clone());
+        self.dismiss_all();
+    }
+
     fn update_visual_state(&mut self, context: &mut EventContext<'_>) {
         let location = self.hover_location.unwrap_or(Point::squared(Px::new(-1)));
+        // Submenus open automatically when hovered by the mouse, but only when
+        // requested while navigating using the keyboard.
+        let open_submenus =
+            self.keyboard_selection.is_none() || mem::take(&mut self.keyboard_opens_submenu);
         self.selecting = None;
         for (index, rendered) in self.items.iter_mut().enumerate() {
-            let hovered = location.y >= rendered.y - self.padding
-                && location.y < rendered.y + rendered.height + self.padding;
+            let hovered = if let Some(selected) = self.keyboard_selection {
+                selected == index
+            } else {
+                location.y >= rendered.y - self.padding
+                    && location.y < rendered.y + rendered.height + self.padding
+            };
             if let ItemKind::Item(item) = &mut rendered.item {
                 let enabled = item.enabled.get_tracking_redraw(context);
                 let new_state = if enabled {
@@ -491,7 +637,7 @@ impl<T> OpenMenu<T> {
                                 handle.dismiss();
                                 self.open_submenu = None;
                             }
-                        } else if let Some(factory) = &item.submenu {
+                        } else if let (true, Some(factory)) = (open_submenus, &item.submenu) {
                             let last_layout = context.last_layout().expect("must have rendered");
                             let menu_location = Point::new(
                                 last_layout.origin.x + last_layout.size.width
@@ -718,6 +864,7 @@ where
         context: &mut crate::context::EventContext<'_>,
     ) -> Option<kludgine::app::winit::window::CursorIcon> {
         self.hover_location = Some(location);
+        self.keyboard_selection = None;
         self.update_visual_state(context);
         self.shared.lock().hovering.insert(context.widget().id());
         None
@@ -742,6 +889,7 @@ where
     ) -> EventHandling {
         self.mouse_down = true;
         self.hover_location = Some(location);
+        self.keyboard_selection = None;
         self.update_visual_state(context);
 
         HANDLED
@@ -770,15 +918,51 @@ where
                 return;
             };
             self.on_click.0.notify(item.value.clone());
-            let mut shared = self.shared.lock();
-            for handle in shared.open_menus.drain() {
-                handle.dismiss();
-            }
+            self.dismiss_all();
         }
         self.hover_location = None;
         self.mouse_down = false;
     }
 
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        let pressed = input.state.is_pressed();
+        match input.logical_key {
+            Key::Named(NamedKey::Escape) => {
+                if pressed {
+                    self.dismiss_all();
+                }
+            }
+            Key::Named(NamedKey::ArrowDown) => {
+                if pressed {
+                    self.select_next(true, context);
+                }
+            }
+            Key::Named(NamedKey::ArrowUp) => {
+                if pressed {
+                    self.select_next(false, context);
+                }
+            }
+            Key::Named(NamedKey::ArrowRight) => {
+                if pressed {
+                    self.open_selected_submenu(context);
+                }
+            }
+            Key::Named(NamedKey::Enter | NamedKey::Space) => {
+                if pressed {
+                    self.choose_selected(context);
+                }
+            }
+            _ => return IGNORED,
+        }
+        HANDLED
+    }
+
     fn accept_focus(&mut self, _context: &mut crate::context::EventContext<'_>) -> bool {
         true
     }
@@ -0,0 +1,392 @@
+//! A text input widget for editing numbers.
+
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+use std::time::Duration;
+
+use kludgine::app::winit::keyboard::{Key, NamedKey};
+
+use crate::reactive::value::{Destination, Dynamic, IntoDynamic, IntoValue, Source, Value};
+use crate::widget::{MakeWidget, MakeWidgetWithTag, WidgetInstance, WidgetTag, HANDLED, IGNORED};
+use crate::widgets::Input;
+
+/// A text input that edits a number.
+///
+/// The text entered is parsed each time it changes. When it can be parsed and
+/// is within the [minimum](Self::minimum) and [maximum](Self::maximum), the
+/// bound [`value`](Self::value) is updated. Otherwise, a validation message is
+/// displayed beneath the input and the value is left unchanged. Pressing Enter
+/// clamps an out-of-range entry to the allowed range and reverts an entry that
+/// cannot be parsed.
+///
+/// The up and down arrow keys increment and decrement the value by
+/// [`step`](Self::step). Buttons that perform the same actions can be shown
+/// using [`NumberInput::spinner_buttons`].
+#[derive(Debug)]
+#[must_use]
+pub struct NumberInput<T> {
+    /// The value being edited.
+    pub value: Dynamic<T>,
+    minimum: Value<T>,
+    maximum: Value<T>,
+    step: Value<T>,
+    spinner_buttons: bool,
+}
+
+impl<T> NumberInput<T>
+where
+    T: NumberInputValue,
+{
+    /// Returns a new input that edits `value`.
+    pub fn new(value: impl IntoDynamic<T>) -> Self {
+        Self {
+            value: value.into_dynamic(),
+            minimum: Value::Constant(T::MIN),
+            maximum: Value::Constant(T::MAX),
+            step: Value::Constant(T::ONE),
+            spinner_buttons: false,
+        }
+    }
+
+    /// Sets the smallest value allowed and returns self.
+    pub fn minimum(mut self, minimum: impl IntoValue<T>) -> Self {
+        self.minimum = minimum.into_value();
+        self
+    }
+
+    /// Sets the largest value allowed and returns self.
+    pub fn maximum(mut self, maximum: impl IntoValue<T>) -> Self {
+        self.maximum = maximum.into_value();
+        self
+    }
+
+    /// Sets the amount the value changes when incremented or decremented and
+    /// returns self.
+    ///
+    /// The default step is `1`.
+    pub fn step(mut self, step: impl IntoValue<T>) -> Self {
+        self.step = step.into_value();
+        self
+    }
+
+    /// Shows buttons next to the input that increment and decrement the value,
+    /// and returns self.
+    ///
+    /// Holding either button down repeats its action.
+    pub fn spinner_buttons(mut self) -> Self {
+        self.spinner_buttons = true;
+        self
+    }
+}
+
+impl<T> MakeWidgetWithTag for NumberInput<T>
+where
+    T: NumberInputValue,
+{
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        let range = NumberRange {
+            value: self.value,
+            minimum: self.minimum,
+            maximum: self.maximum,
+            step: self.step,
+        };
+        let text = Dynamic::new(range.value.get().to_string());
+
+        // Only replace the text when it no longer represents the value, which
+        // keeps entries such as `1.0` from being rewritten while typing. The
+        // text is held weakly because its own callback holds the value, and a
+        // strong reference in both directions would keep both alive forever.
+        range
+            .value
+            .for_each_cloned({
+                let text = text.downgrade();
+                move |value| {
+                    let Some(text) = text.upgrade() else {
+                        return;
+                    };
+                    if text.map_ref(|text| text.trim().parse::<T>().ok()) != Some(value) {
+                        text.set(value.to_string());
+                    }
+                }
+            })
+            .persist();
+        text.for_each({
+            let range = range.clone();
+            move |text: &String| {
+                if let Ok(value) = range.parse(text) {
+                    range.value.set(value);
+                }
+            }
+        })
+        .persist();
+        let validation = text.validate_with({
+            let range = range.clone();
+            move |text: &String| range.parse(text).map(|_| ())
+        });
+
+        let input = Input::new(text.clone()).on_key({
+            let range = range.clone();
+            move |key| match key.logical_key {
+                Key::Named(NamedKey::ArrowUp) => {
+                    if key.state.is_pressed() {
+                        range.step_by(true);
+                    }
+                    HANDLED
+                }
+                Key::Named(NamedKey::ArrowDown) => {
+                    if key.state.is_pressed() {
+                        range.step_by(false);
+                    }
+                    HANDLED
+                }
+                Key::Named(NamedKey::Enter) => {
+                    if key.state.is_pressed() {
+                        range.commit(&text);
+                    }
+                    IGNORED
+                }
+                _ => IGNORED,
+            }
+        });
+
+        let contents = if self.spinner_buttons {
+            input
+                .expand()
+                .and(
+                    "-".into_button()
+                        .on_click({
+                            let range = range.clone();
+                            move |_| range.step_by(false)
+                        })
+                        .repeat(SPINNER_REPEAT_DELAY, SPINNER_REPEAT_INTERVAL),
+                )
+                .and(
+                    "+".into_button()
+                        .on_click(move |_| range.step_by(true))
+                        .repeat(SPINNER_REPEAT_DELAY, SPINNER_REPEAT_INTERVAL),
+                )
+                .into_columns()
+                .make_widget()
+        } else {
+            input.make_widget()
+        };
+
+        contents.validation(validation).make_with_tag(tag)
+    }
+}
+
+const SPINNER_REPEAT_DELAY: Duration = Duration::from_millis(500);
+const SPINNER_REPEAT_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone)]
+struct NumberRange<T> {
+    value: Dynamic<T>,
+    minimum: Value<T>,
+    maximum: Value<T>,
+    step: Value<T>,
+}
+
+impl<T> NumberRange<T>
+where
+    T: NumberInputValue,
+{
+    fn clamp(&self, value: T) -> T {
+        let minimum = self.minimum.get();
+        let maximum = self.maximum.get();
+        if !is_comparable(value) || value < minimum {
+            minimum
+        } else if value > maximum {
+            maximum
+        } else {
+            value
+        }
+    }
+
+    fn parse(&self, text: &str) -> Result<T, String> {
+        let value = text
+            .trim()
+            .parse::<T>()
+            .ok()
+            .filter(|value| is_comparable(*value))
+            .ok_or_else(|| String::from("not a valid number"))?;
+        let minimum = self.minimum.get();
+        let maximum = self.maximum.get();
+        if value < minimum {
+            Err(format!("must be at least {minimum}"))
+        } else if value > maximum {
+            Err(format!("must be at most {maximum}"))
+        } else {
+            Ok(value)
+        }
+    }
+
+    fn step_by(&self, up: bool) {
+        let step = self.step.get();
+        let current = self.value.get();
+        let stepped = if up {
+            current.step_up(step)
+        } else {
+            current.step_down(step)
+        };
+        self.value.set(self.clamp(stepped));
+    }
+
+    fn commit(&self, text: &Dynamic<String>) {
+        if let Some(value) = text
+            .map_ref(|text| text.trim().parse::<T>().ok())
+            .filter(|value| is_comparable(*value))
+        {
+            self.value.set(self.clamp(value));
+        }
+        text.set(self.value.get().to_string());
+    }
+}
+
+/// Returns false if `value` is unordered, such as a floating point `NaN`.
+fn is_comparable<T: PartialOrd>(value: T) -> bool {
+    value.partial_cmp(&value).is_some()
+}
+
+/// A numeric type that can be edited with a [`NumberInput`].
+///
+/// This trait is implemented for all of Rust's built-in integer and floating
+/// point types.
+pub trait NumberInputValue:
+    Copy + Debug + Display + FromStr + PartialOrd + Send + Sync + 'static
+{
+    /// The smallest value this type can represent.
+    const MIN: Self;
+    /// The largest value this type can represent.
+    const MAX: Self;
+    /// The value `1`, which is used as the default step.
+    const ONE: Self;
+
+    /// Returns the result of adding `step` to `self`, saturating at
+    /// [`Self::MAX`].
+    #[must_use]
+    fn step_up(self, step: Self) -> Self;
+
+    /// Returns the result of subtracting `step` from `self`, saturating at
+    /// [`Self::MIN`].
+    #[must_use]
+    fn step_down(self, step: Self) -> Self;
+}
+
+macro_rules! impl_integer_number_input_value {
+    ($($type:ident),+) => {
+        $(
+            impl NumberInputValue for $type {
+                const MAX: Self = $type::MAX;
+                const MIN: Self = $type::MIN;
+                const ONE: Self = 1;
+
+                fn step_up(self, step: Self) -> Self {
+                    self.saturating_add(step)
+                }
+
+                fn step_down(self, step: Self) -> Self {
+                    self.saturating_sub(step)
+                }
+            }
+        )+
+    };
+}
+
+impl_integer_number_input_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_float_number_input_value {
+    ($($type:ident),+) => {
+        $(
+            impl NumberInputValue for $type {
+                const MAX: Self = $type::MAX;
+                const MIN: Self = $type::MIN;
+                const ONE: Self = 1.;
+
+                fn step_up(self, step: Self) -> Self {
+                    (self + step).min($type::MAX)
+                }
+
+                fn step_down(self, step: Self) -> Self {
+                    (self - step).max($type::MIN)
+                }
+            }
+        )+
+    };
+}
+
+impl_float_number_input_value!(f32, f64);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::NumberRange;
+    use crate::reactive::value::{Destination, Dynamic, Source, Value};
+
+    fn number_range<T>(value: T, minimum: T, maximum: T, step: T) -> NumberRange<T> {
+        NumberRange {
+            value: Dynamic::new(value),
+            minimum: Value::Constant(minimum),
+            maximum: Value::Constant(maximum),
+            step: Value::Constant(step),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let range = number_range(5_i32, 0, 10, 1);
+        assert_eq!(range.parse(" 7 "), Ok(7));
+        assert_eq!(range.parse("0"), Ok(0));
+        assert_eq!(range.parse("10"), Ok(10));
+        assert!(range.parse("-1").is_err());
+        assert!(range.parse("11").is_err());
+        assert!(range.parse("seven").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_unordered_floats() {
+        let range = number_range(0.5_f32, 0., 1., 0.1);
+        assert_eq!(range.parse("0.25"), Ok(0.25));
+        assert!(range.parse("NaN").is_err());
+        assert!(range.parse("inf").is_err());
+
+        let unbounded = number_range(0_f64, f64::MIN, f64::MAX, 1.);
+        assert!(unbounded.parse("NaN").is_err());
+    }
+
+    #[test]
+    fn clamp() {
+        let range = number_range(5_i32, 0, 10, 1);
+        assert_eq!(range.clamp(-5), 0);
+        assert_eq!(range.clamp(5), 5);
+        assert_eq!(range.clamp(15), 10);
+
+        let range = number_range(0.5_f32, 0., 1., 0.1);
+        assert_eq!(range.clamp(f32::NAN), 0.);
+    }
+
+    #[test]
+    fn step_saturates() {
+        let range = number_range(250_u8, u8::MIN, u8::MAX, 10);
+        range.step_by(true);
+        assert_eq!(range.value.get(), u8::MAX);
+        range.value.set(5);
+        range.step_by(false);
+        assert_eq!(range.value.get(), u8::MIN);
+
+        let range = number_range(f32::MAX, f32::MIN, f32::MAX, f32::MAX);
+        range.step_by(true);
+        assert_eq!(range.value.get(), f32::MAX);
+    }
+
+    #[test]
+    fn step_clamps_to_bounds() {
+        let range = number_range(9_i32, 0, 10, 5);
+        range.step_by(true);
+        assert_eq!(range.value.get(), 10);
+        range.step_by(false);
+        assert_eq!(range.value.get(), 5);
+        range.step_by(false);
+        range.step_by(false);
+        assert_eq!(range.value.get(), 0);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::animation::ZeroToOne;
+use crate::context::GraphicsContext;
+use crate::reactive::value::{IntoValue, Value};
+use crate::widget::{MakeWidget, WidgetRef, WrapperWidget};
+
+/// A widget that draws its child at a reduced opacity.
+///
+/// When `opacity` is a [`Dynamic`](crate::reactive::value::Dynamic), the child
+/// is redrawn each time it changes. Animating the dynamic, for example using
+/// [`AnimationTarget::transition_to`](crate::animation::AnimationTarget::transition_to),
+/// is a convenient way to fade widgets in and out.
+///
+/// # Performance and Appearance
+///
+/// The child is not rendered to an offscreen texture. Instead, the opacity is
+/// multiplied into every drawing operation performed by the child and its
+/// descendants. This has no additional cost beyond drawing the child
+/// normally, but it means overlapping shapes within the child are blended
+/// with each other rather than being composited as a single image.
+#[derive(Debug)]
+pub struct Opacity {
+    opacity: Value<ZeroToOne>,
+    child: WidgetRef,
+}
+
+impl Opacity {
+    /// Returns a widget that draws `child` using `opacity`.
+    #[must_use]
+    pub fn new(opacity: impl IntoValue<ZeroToOne>, child: impl MakeWidget) -> Self {
+        Self {
+            opacity: opacity.into_value(),
+            child: WidgetRef::new(child),
+        }
+    }
+}
+
+impl WrapperWidget for Opacity {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn redraw_background(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        // The child's graphics context inherits the opacity of this context.
+        let opacity = self.opacity.get_tracking_redraw(context);
+        context.apply_opacity(opacity);
+    }
+}
@@ -0,0 +1,110 @@
+use figures::units::UPx;
+use figures::{IntoSigned, Point, Rect, Round, ScreenScale, Size, Zero};
+
+use crate::context::{AsEventContext, EventContext, LayoutContext};
+use crate::reactive::value::{IntoValue, Source, Value};
+use crate::styles::{Dimension, Edges};
+use crate::widget::{MakeWidget, RootBehavior, WidgetRef, WrappedLayout, WrapperWidget};
+use crate::ConstraintLimit;
+
+/// A widget that surrounds its child with empty space.
+///
+/// Unlike a [`Container`](crate::widgets::Container), this widget never draws
+/// anything. The inset area belongs to this widget rather than its child, so
+/// the child is not hovered or clicked when the cursor is within the padding.
+/// This makes this widget suitable for use as a margin as well as padding.
+///
+/// ```rust
+/// use cushy::styles::{Dimension, Edges};
+/// use cushy::widgets::Padding;
+/// use figures::units::Lp;
+///
+/// let uniform = Padding::new(Lp::points(8), "Hello");
+/// let per_side = Padding::new(
+///     Edges::from(Dimension::Lp(Lp::points(0))).with_left(Lp::points(16)),
+///     "Indented",
+/// );
+/// let axes = Padding::symmetric(Lp::points(12), Lp::points(4), "Button-like");
+/// ```
+#[derive(Debug)]
+pub struct Padding {
+    child: WidgetRef,
+    padding: Value<Edges<Dimension>>,
+}
+
+impl Padding {
+    /// Returns a widget that surrounds `child` with `padding`.
+    pub fn new(padding: impl IntoValue<Edges<Dimension>>, child: impl MakeWidget) -> Self {
+        Self {
+            child: WidgetRef::new(child),
+            padding: padding.into_value(),
+        }
+    }
+
+    /// Returns a widget that surrounds `child` with `horizontal` padding on its
+    /// left and right edges and `vertical` padding on its top and bottom edges.
+    pub fn symmetric(
+        horizontal: impl Into<Dimension>,
+        vertical: impl Into<Dimension>,
+        child: impl MakeWidget,
+    ) -> Self {
+        Self::new(
+            Edges::from(Dimension::ZERO)
+                .with_horizontal(horizontal)
+                .with_vertical(vertical),
+            child,
+        )
+    }
+
+    /// Returns a widget that pads the left and right edges of `child` by
+    /// `padding`.
+    pub fn horizontal(padding: impl Into<Dimension>, child: impl MakeWidget) -> Self {
+        Self::symmetric(padding, Dimension::ZERO, child)
+    }
+
+    /// Returns a widget that pads the top and bottom edges of `child` by
+    /// `padding`.
+    pub fn vertical(padding: impl Into<Dimension>, child: impl MakeWidget) -> Self {
+        Self::symmetric(Dimension::ZERO, padding, child)
+    }
+
+    fn padding(&self, context: &mut LayoutContext<'_, '_, '_, '_>) -> Edges<UPx> {
+        self.padding
+            .get_tracking_invalidate(context)
+            .map(|dimension| dimension.into_upx(context.gfx.scale()).round())
+    }
+}
+
+impl WrapperWidget for Padding {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn root_behavior(&mut self, context: &mut EventContext<'_>) -> Option<RootBehavior> {
+        Some(RootBehavior::Pad(
+            self.padding.get_tracking_invalidate(context),
+        ))
+    }
+
+    fn layout_child(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> WrappedLayout {
+        let padding = self.padding(context);
+        let padding_amount = padding.size();
+
+        let child = self.child.mounted(&mut context.as_event_context());
+        let child_size = context
+            .for_other(&child)
+            .layout(available_space - padding_amount);
+
+        WrappedLayout {
+            child: Rect::new(
+                Point::new(padding.left, padding.top).into_signed(),
+                child_size.into_signed(),
+            ),
+            size: child_size + padding_amount,
+        }
+    }
+}
@@ -23,6 +23,11 @@ use crate::widgets::slider::{InactiveTrackColor, Slidable, TrackColor, TrackSize
 use crate::widgets::Data;
 
 /// A bar-shaped progress indicator.
+///
+/// When the progress is [`Progress::Indeterminant`], a highlighted segment
+/// continuously animates along the bar. When a percentage later becomes
+/// available, the indicator animates from its current position to the new
+/// value.
 #[derive(Debug)]
 pub struct ProgressBar {
     progress: ReadOnly<Progress>,
@@ -48,7 +53,8 @@ impl ProgressBar {
         }
     }
 
-    /// Returns a new progress bar that displays `progress`.
+    /// Displays this progress indicator as a circular spinner instead of a bar
+    /// and returns self.
     #[must_use]
     pub fn spinner(mut self) -> Self {
         self.spinner = true;
@@ -72,7 +78,7 @@ impl MakeWidgetWithTag for ProgressBar {
         let end = Dynamic::new(ZeroToOne::ZERO);
         let value = (&start, &end).map_each(|(start, end)| *start..=*end);
 
-        let mut indeterminant_animation = None;
+        let mut animations = ProgressAnimations::default();
 
         let (slider, degree_offset) = if self.spinner {
             let degree_offset = Dynamic::new(Angle::degrees(270));
@@ -102,7 +108,7 @@ impl MakeWidgetWithTag for ProgressBar {
         let ease_out = ease_out_probe.value().clone();
         update_progress_bar(
             self.progress.get(),
-            &mut indeterminant_animation,
+            &mut animations,
             &start,
             &end,
             degree_offset.as_ref(),
@@ -118,7 +124,7 @@ impl MakeWidgetWithTag for ProgressBar {
                     move |progress| {
                         update_progress_bar(
                             *progress,
-                            &mut indeterminant_animation,
+                            &mut animations,
                             &start,
                             &end,
                             degree_offset.as_ref(),
@@ -129,13 +135,17 @@ impl MakeWidgetWithTag for ProgressBar {
                 });
                 Data::new_wrapping((callback, progress), ease_out_probe).make_widget()
             }
-            ReadOnly::Constant(_) => {
-                Data::new_wrapping(indeterminant_animation, ease_out_probe).make_widget()
-            }
+            ReadOnly::Constant(_) => Data::new_wrapping(animations, ease_out_probe).make_widget(),
         }
     }
 }
 
+#[derive(Debug, Default)]
+struct ProgressAnimations {
+    indeterminant: Option<IndeterminantAnimations>,
+    transition: AnimationHandle,
+}
+
 #[derive(Debug)]
 struct IndeterminantAnimations {
     _primary: AnimationHandle,
@@ -144,7 +154,7 @@ struct IndeterminantAnimations {
 
 fn update_progress_bar(
     progress: Progress,
-    indeterminant_animation: &mut Option<IndeterminantAnimations>,
+    animations: &mut ProgressAnimations,
     start: &Dynamic<ZeroToOne>,
     end: &Dynamic<ZeroToOne>,
     degree_offset: Option<&Dynamic<Angle>>,
@@ -153,10 +163,11 @@ fn update_progress_bar(
 ) {
     match progress {
         Progress::Indeterminant => {
-            if indeterminant_animation.is_none() {
+            if animations.indeterminant.is_none() {
+                animations.transition.clear();
                 let ease_in = ease_in.get();
                 let ease_out = ease_out.get();
-                *indeterminant_animation = Some(IndeterminantAnimations {
+                animations.indeterminant = Some(IndeterminantAnimations {
                     _primary: (
                         start
                             .transition_to(ZeroToOne::ZERO)
@@ -205,12 +216,39 @@ fn update_progress_bar(
             }
         }
         Progress::Percent(value) => {
-            let _stopped_animation = indeterminant_animation.take();
-            if let Some(degree_offset) = degree_offset {
-                degree_offset.set(Angle::degrees(270));
+            // When leaving the indeterminant state, or while still
+            // transitioning away from it, animate from the current position of
+            // the indicator rather than jumping to the new value.
+            if animations.indeterminant.take().is_some() || animations.transition.is_running() {
+                let ease_out = ease_out.get();
+                let transition = (
+                    start
+                        .transition_to(ZeroToOne::ZERO)
+                        .over(Duration::from_millis(250))
+                        .with_easing(ease_out.clone()),
+                    end.transition_to(value)
+                        .over(Duration::from_millis(250))
+                        .with_easing(ease_out.clone()),
+                );
+                animations.transition = if let Some(degree_offset) = degree_offset {
+                    (
+                        transition,
+                        degree_offset
+                            .transition_to(Angle::degrees(270))
+                            .over(Duration::from_millis(250))
+                            .with_easing(ease_out),
+                    )
+                        .spawn()
+                } else {
+                    transition.spawn()
+                };
+            } else {
+                if let Some(degree_offset) = degree_offset {
+                    degree_offset.set(Angle::degrees(270));
+                }
+                start.set(ZeroToOne::ZERO);
+                end.set(value);
             }
-            start.set(ZeroToOne::ZERO);
-            end.set(value);
         }
     }
 }
@@ -1,4 +1,5 @@
-use figures::{Fraction, ScreenScale, Size};
+use figures::units::UPx;
+use figures::{FloatConversion, Fraction, ScreenScale, Size};
 
 use crate::context::{AsEventContext, EventContext, LayoutContext};
 use crate::styles::DimensionRange;
@@ -6,12 +7,24 @@ use crate::widget::{MakeWidget, RootBehavior, WidgetRef, WrappedLayout, WrapperW
 use crate::ConstraintLimit;
 
 /// A widget that resizes its contained widget to an explicit size.
+///
+/// # Aspect Ratio
+///
+/// [`Resize::aspect_ratio`] constrains the child to a fixed ratio of width to
+/// height. The child is sized to the largest size with that ratio that fits
+/// within the available space and the [`width`](Self::width) and
+/// [`height`](Self::height) ranges, and is aligned within any remaining space.
+///
+/// When used as the root widget of a window, the window can still be resized
+/// freely. The child is kept at its aspect ratio within the window.
 #[derive(Debug)]
 pub struct Resize {
     /// The range of allowed width for the child widget.
     pub width: DimensionRange,
     /// The range of allowed height for the child widget.
     pub height: DimensionRange,
+    /// The ratio of width to height to constrain the child widget to.
+    pub aspect_ratio: Option<f32>,
     child: WidgetRef,
 }
 
@@ -30,6 +43,7 @@ impl Resize {
     {
         Self {
             child: WidgetRef::new(child),
+            aspect_ratio: None,
             width: size.width.into(),
             height: size.height.into(),
         }
@@ -40,6 +54,7 @@ impl Resize {
     pub fn from_width(width: impl Into<DimensionRange>, child: impl MakeWidget) -> Self {
         Self {
             child: WidgetRef::new(child),
+            aspect_ratio: None,
             width: width.into(),
             height: DimensionRange::from(..),
         }
@@ -73,11 +88,22 @@ impl Resize {
         self
     }
 
+    /// Constrains the child to a ratio of `width` to `height` and returns
+    /// self.
+    ///
+    /// See [Aspect Ratio](Self#aspect-ratio) for more information.
+    #[must_use]
+    pub fn aspect_ratio(mut self, width: f32, height: f32) -> Self {
+        self.aspect_ratio = Some(width / height);
+        self
+    }
+
     /// Resizes `child`'s height to `height`.
     #[must_use]
     pub fn from_height(height: impl Into<DimensionRange>, child: impl MakeWidget) -> Self {
         Self {
             child: WidgetRef::new(child),
+            aspect_ratio: None,
             width: DimensionRange::from(..),
             height: height.into(),
         }
@@ -99,6 +125,25 @@ impl WrapperWidget for Resize {
         context: &mut LayoutContext<'_, '_, '_, '_>,
     ) -> WrappedLayout {
         let child = self.child.mounted(&mut context.as_event_context());
+        if let Some(ratio) = self.aspect_ratio.filter(|ratio| ratio.is_normal()) {
+            let constraints = Size::new(
+                override_constraint(available_space.width, self.width, context.gfx.scale()),
+                override_constraint(available_space.height, self.height, context.gfx.scale()),
+            );
+            let mut bounds = constraints.map(ConstraintLimit::max);
+            if bounds.width == UPx::MAX && bounds.height == UPx::MAX {
+                // Without any bounds, fit the ratio within the child's
+                // measured size.
+                bounds = context.for_other(&child).layout(constraints);
+            }
+            let size = fit_aspect_ratio(ratio, bounds);
+            let size = context
+                .for_other(&child)
+                .layout(size.map(ConstraintLimit::Fill))
+                .min(size);
+            return WrappedLayout::aligned(size, available_space, context);
+        }
+
         let (size, fill_layout) = if let (Some(width), Some(height)) =
             (self.width.exact_dimension(), self.height.exact_dimension())
         {
@@ -135,6 +180,18 @@ impl WrapperWidget for Resize {
     }
 }
 
+fn fit_aspect_ratio(ratio: f32, bounds: Size<UPx>) -> Size<UPx> {
+    let width = bounds.height.into_float() * ratio;
+    if width <= bounds.width.into_float() {
+        Size::new(UPx::from_float(width), bounds.height)
+    } else {
+        Size::new(
+            bounds.width,
+            UPx::from_float(bounds.width.into_float() / ratio),
+        )
+    }
+}
+
 fn override_constraint(
     constraint: ConstraintLimit,
     range: DimensionRange,
@@ -0,0 +1,551 @@
+//! Text made up of individually styled spans.
+
+use figures::units::{Px, UPx};
+use figures::{FloatConversion, IntoUnsigned, Point, Round, ScreenScale, Size, Zero};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::window::CursorIcon;
+use kludgine::cosmic_text::{self, Attrs, Buffer, FamilyOwned, Metrics, Shaping};
+use kludgine::text::{MeasuredText, TextOrigin};
+use kludgine::{CanRenderTo, Color, DrawableExt};
+
+use crate::context::{EventContext, FontSettings, GraphicsContext, LayoutContext};
+use crate::reactive::value::{Dynamic, Generation, IntoReadOnly, ReadOnly, Value};
+use crate::styles::components::{HorizontalAlignment, TextColor, VerticalAlignment};
+use crate::styles::{Dimension, HorizontalAlign, Style, VerticalAlign, Weight};
+use crate::widget::{
+    Callback, EventHandling, MakeWidgetWithTag, Widget, WidgetInstance, WidgetTag, HANDLED, IGNORED,
+};
+use crate::window::{DeviceId, WindowLocal};
+use crate::{ConstraintLimit, FitMeasuredSize};
+
+/// Text composed of [`TextSpan`]s that can each be styled differently.
+///
+/// ```rust
+/// use cushy::styles::Weight;
+/// use cushy::widgets::rich_text::{RichText, TextSpan};
+/// use cushy::widgets::RichLabel;
+///
+/// let text = RichText::new()
+///     .and("Press ")
+///     .and(TextSpan::new("Save").weight(Weight::BOLD))
+///     .and(" or read the ")
+///     .and(TextSpan::new("documentation").link("docs"))
+///     .and(".");
+/// let label = RichLabel::new(text).on_link(|link: String| {
+///     println!("clicked {link}");
+/// });
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[must_use]
+pub struct RichText {
+    spans: Vec<TextSpan>,
+}
+
+impl RichText {
+    /// Returns an empty rich text.
+    pub const fn new() -> Self {
+        Self { spans: Vec::new() }
+    }
+
+    /// Adds `span` to the end of this text and returns self.
+    pub fn and(mut self, span: impl Into<TextSpan>) -> Self {
+        self.push(span);
+        self
+    }
+
+    /// Adds `span` to the end of this text.
+    pub fn push(&mut self, span: impl Into<TextSpan>) {
+        self.spans.push(span.into());
+    }
+
+    /// Returns the spans this text is composed of.
+    #[must_use]
+    pub fn spans(&self) -> &[TextSpan] {
+        &self.spans
+    }
+
+    /// Returns the text without any styling information.
+    #[must_use]
+    pub fn to_plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+}
+
+impl<T> FromIterator<T> for RichText
+where
+    T: Into<TextSpan>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            spans: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A run of text within a [`RichText`].
+///
+/// Any style left as `None` uses the value from the widget's current style.
+#[derive(Debug, Clone, PartialEq)]
+#[must_use]
+pub struct TextSpan {
+    /// The text of this span.
+    pub text: String,
+    /// The color of the text.
+    pub color: Option<Color>,
+    /// The weight of the font.
+    pub weight: Option<Weight>,
+    /// The style of the font.
+    pub style: Option<Style>,
+    /// The size of the font.
+    pub size: Option<Dimension>,
+    /// The link target of this span.
+    ///
+    /// When a linked span is clicked, this value is passed to the callback
+    /// registered with [`RichLabel::on_link`].
+    pub link: Option<String>,
+}
+
+impl TextSpan {
+    /// Returns a span of `text` using the current style.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            color: None,
+            weight: None,
+            style: None,
+            size: None,
+            link: None,
+        }
+    }
+
+    /// Sets the text color and returns self.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the font weight and returns self.
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Sets the font weight to [`Weight::BOLD`] and returns self.
+    pub fn bold(self) -> Self {
+        self.weight(Weight::BOLD)
+    }
+
+    /// Sets the font style and returns self.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Sets the font style to [`Style::Italic`] and returns self.
+    pub fn italic(self) -> Self {
+        self.style(Style::Italic)
+    }
+
+    /// Sets the font size and returns self.
+    ///
+    /// The line height of this span is scaled proportionally to the current
+    /// line height.
+    pub fn size(mut self, size: impl Into<Dimension>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Makes this span a link to `target` and returns self.
+    pub fn link(mut self, target: impl Into<String>) -> Self {
+        self.link = Some(target.into());
+        self
+    }
+}
+
+impl From<&'_ str> for TextSpan {
+    fn from(text: &'_ str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for TextSpan {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+/// A read-only widget that displays [`RichText`].
+///
+/// Text is wrapped at word boundaries to fit the available width, and is
+/// aligned using the [`HorizontalAlignment`] and [`VerticalAlignment`]
+/// components.
+#[derive(Debug)]
+pub struct RichLabel {
+    /// The contents of the label.
+    pub text: ReadOnly<RichText>,
+    on_link: Option<Callback<String>>,
+    pressed_link: Option<usize>,
+    prepared: WindowLocal<RichTextCache>,
+}
+
+impl RichLabel {
+    /// Returns a new label that displays `text`.
+    #[must_use]
+    pub fn new(text: impl IntoReadOnly<RichText>) -> Self {
+        Self {
+            text: text.into_read_only(),
+            on_link: None,
+            pressed_link: None,
+            prepared: WindowLocal::default(),
+        }
+    }
+
+    /// Invokes `on_link` with the [link target](TextSpan::link) when a linked
+    /// span is clicked.
+    #[must_use]
+    pub fn on_link<F>(mut self, on_link: F) -> Self
+    where
+        F: FnMut(String) + Send + 'static,
+    {
+        self.on_link = Some(Callback::new(on_link));
+        self
+    }
+
+    fn prepare(
+        &mut self,
+        context: &mut GraphicsContext<'_, '_, '_, '_>,
+        color: Color,
+        width: Px,
+        align: HorizontalAlign,
+    ) {
+        let align = match align {
+            HorizontalAlign::Left => cosmic_text::Align::Left,
+            HorizontalAlign::Center => cosmic_text::Align::Center,
+            HorizontalAlign::Right => cosmic_text::Align::Right,
+            HorizontalAlign::Justify => cosmic_text::Align::Justified,
+        };
+        let key = RichTextCacheKey {
+            generation: self.text.generation(),
+            width,
+            color,
+            settings: context.current_font_settings(),
+            align,
+        };
+
+        match self.prepared.get(context) {
+            Some(cache) if cache.measured.can_render_to(&context.gfx) && cache.key == key => {}
+            _ => {
+                let family = context
+                    .find_available_font_family(&key.settings.family)
+                    .unwrap_or(FamilyOwned::SansSerif);
+                let scale = context.gfx.scale();
+                let font_size = key.settings.size.into_px(scale).into_float();
+                let line_height = key.settings.line_height.into_px(scale).into_float();
+                let default_attrs = Attrs::new()
+                    .family(family.as_family())
+                    .weight(key.settings.weight)
+                    .style(key.settings.style)
+                    .color(cosmic_color(color));
+
+                let cache = self.text.map(|text| {
+                    let font_system = context.gfx.font_system();
+                    let mut buffer = Buffer::new(font_system, Metrics::new(font_size, line_height));
+                    buffer.set_size(font_system, Some(width.into_float()), None);
+                    buffer.set_rich_text(
+                        font_system,
+                        text.spans.iter().map(|span| {
+                            let mut attrs = default_attrs;
+                            if let Some(color) = span.color {
+                                attrs = attrs.color(cosmic_color(color));
+                            }
+                            if let Some(weight) = span.weight {
+                                attrs = attrs.weight(weight);
+                            }
+                            if let Some(style) = span.style {
+                                attrs = attrs.style(style);
+                            }
+                            if let Some(size) = span.size {
+                                let size = size.into_px(scale).into_float();
+                                attrs = attrs
+                                    .metrics(Metrics::new(size, line_height * size / font_size));
+                            }
+                            (span.text.as_str(), attrs)
+                        }),
+                        default_attrs,
+                        Shaping::Advanced,
+                    );
+                    for line in &mut buffer.lines {
+                        line.set_align(Some(align));
+                    }
+                    buffer.shape_until_scroll(font_system, false);
+
+                    RichTextCache {
+                        measured: context.gfx.measure_text_buffer(&buffer, color),
+                        buffer,
+                        spans: SpanRange::for_text(text),
+                        offset: Px::ZERO,
+                        key,
+                    }
+                });
+                self.prepared.set(context, cache);
+            }
+        }
+    }
+
+    fn link_at(&self, location: Point<Px>, context: &EventContext<'_>) -> Option<usize> {
+        let cache = self.prepared.get(context)?;
+        let location = location - Point::new(Px::ZERO, cache.offset);
+        if location.x < 0
+            || location.y < 0
+            || location.x > cache.measured.size.width
+            || location.y > cache.measured.size.height
+        {
+            return None;
+        }
+
+        let cursor = cache
+            .buffer
+            .hit(location.x.into_float(), location.y.into_float())?;
+        let span = cache
+            .spans
+            .iter()
+            .find(|range| {
+                range.line == cursor.line && range.start <= cursor.index && cursor.index < range.end
+            })
+            .or_else(|| {
+                // A cursor at the end of a line belongs to the last span on it.
+                cache
+                    .spans
+                    .iter()
+                    .find(|range| range.line == cursor.line && range.end == cursor.index)
+            })?
+            .span;
+
+        self.text
+            .map(|text| {
+                text.spans
+                    .get(span)
+                    .and_then(|span| span.link.as_ref())
+                    .is_some()
+            })
+            .then_some(span)
+    }
+}
+
+fn cosmic_color(color: Color) -> cosmic_text::Color {
+    cosmic_text::Color::rgba(color.red(), color.green(), color.blue(), color.alpha())
+}
+
+impl Widget for RichLabel {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        self.text.invalidate_when_changed(context);
+
+        let align = context.get(&HorizontalAlignment);
+        let valign = context.get(&VerticalAlignment);
+        let text_color = context.get(&TextColor);
+        let size = context.gfx.region().size;
+
+        self.prepare(context, text_color, size.width, align);
+        let Some(cache) = self.prepared.get_mut(context) else {
+            return;
+        };
+
+        cache.offset = match valign {
+            VerticalAlign::Top => Px::ZERO,
+            VerticalAlign::Center => (size.height - cache.measured.size.height) / 2,
+            VerticalAlign::Bottom => size.height - cache.measured.size.height,
+        };
+
+        let measured = &cache.measured;
+        context.gfx.draw_measured_text(
+            measured.translate_by(Point::new(Px::ZERO, cache.offset)),
+            TextOrigin::TopLeft,
+        );
+    }
+
+    fn layout(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        let align = context.get(&HorizontalAlignment);
+        let color = context.get(&TextColor);
+        let width = available_space.width.max().try_into().unwrap_or(Px::MAX);
+        self.prepare(context, color, width, align);
+        let size = self
+            .prepared
+            .get(context)
+            .map_or(Size::ZERO, |cache| cache.measured.size);
+
+        available_space.fit_measured(size.into_unsigned().ceil())
+    }
+
+    fn hit_test(&mut self, location: Point<Px>, context: &mut EventContext<'_>) -> bool {
+        self.on_link.is_some() && self.link_at(location, context).is_some()
+    }
+
+    fn hover(&mut self, location: Point<Px>, context: &mut EventContext<'_>) -> Option<CursorIcon> {
+        self.link_at(location, context).map(|_| CursorIcon::Pointer)
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button != MouseButton::Left || self.on_link.is_none() {
+            return IGNORED;
+        }
+
+        self.pressed_link = self.link_at(location, context);
+        if self.pressed_link.is_some() {
+            HANDLED
+        } else {
+            IGNORED
+        }
+    }
+
+    fn mouse_up(
+        &mut self,
+        location: Option<Point<Px>>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) {
+        let Some(pressed) = self.pressed_link.take() else {
+            return;
+        };
+        if location.and_then(|location| self.link_at(location, context)) != Some(pressed) {
+            return;
+        }
+
+        let link = self
+            .text
+            .map(|text| text.spans.get(pressed).and_then(|span| span.link.clone()));
+        if let (Some(link), Some(on_link)) = (link, &mut self.on_link) {
+            on_link.invoke(link);
+        }
+    }
+
+    fn summarize(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_tuple("RichLabel").field(&self.text).finish()
+    }
+
+    fn unmounted(&mut self, context: &mut EventContext<'_>) {
+        self.prepared.clear_for(context);
+    }
+}
+
+macro_rules! impl_make_widget {
+    ($($type:ty),*) => {
+        $(impl MakeWidgetWithTag for $type {
+            fn make_with_tag(self, id: WidgetTag) -> WidgetInstance {
+                RichLabel::new(self).make_with_tag(id)
+            }
+        })*
+    };
+}
+
+impl_make_widget!(
+    RichText,
+    Dynamic<RichText>,
+    Value<RichText>,
+    ReadOnly<RichText>
+);
+
+#[derive(Debug)]
+struct RichTextCache {
+    buffer: Buffer,
+    measured: MeasuredText<Px>,
+    spans: Vec<SpanRange>,
+    offset: Px,
+    key: RichTextCacheKey,
+}
+
+#[derive(Debug, PartialEq)]
+struct RichTextCacheKey {
+    generation: Option<Generation>,
+    width: Px,
+    color: Color,
+    settings: FontSettings,
+    align: cosmic_text::Align,
+}
+
+/// The byte range of a span within a single line of a laid out [`Buffer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SpanRange {
+    span: usize,
+    line: usize,
+    start: usize,
+    end: usize,
+}
+
+impl SpanRange {
+    fn for_text(text: &RichText) -> Vec<Self> {
+        let mut ranges = Vec::with_capacity(text.spans.len());
+        let mut line = 0;
+        let mut offset = 0;
+        for (span, contents) in text.spans.iter().enumerate() {
+            for (index, part) in contents.text.split('\n').enumerate() {
+                if index > 0 {
+                    line += 1;
+                    offset = 0;
+                }
+                let part = part.strip_suffix('\r').unwrap_or(part);
+                ranges.push(Self {
+                    span,
+                    line,
+                    start: offset,
+                    end: offset + part.len(),
+                });
+                offset += part.len();
+            }
+        }
+        ranges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RichText, SpanRange, TextSpan};
+
+    #[test]
+    fn span_ranges() {
+        let text = RichText::new()
+            .and("one ")
+            .and(TextSpan::new("two\nthree").bold())
+            .and(" four");
+        assert_eq!(
+            SpanRange::for_text(&text),
+            [
+                SpanRange {
+                    span: 0,
+                    line: 0,
+                    start: 0,
+                    end: 4
+                },
+                SpanRange {
+                    span: 1,
+                    line: 0,
+                    start: 4,
+                    end: 7
+                },
+                SpanRange {
+                    span: 1,
+                    line: 1,
+                    start: 0,
+                    end: 5
+                },
+                SpanRange {
+                    span: 2,
+                    line: 1,
+                    start: 5,
+                    end: 10
+                },
+            ]
+        );
+        assert_eq!(text.to_plain_text(), "one two\nthree four");
+    }
+}
@@ -1,7 +1,8 @@
 //! A container that scrolls its contents on a virtual surface.
 
 use std::mem;
-use std::time::Duration;
+use std::ops::ControlFlow;
+use std::time::{Duration, Instant};
 
 use figures::units::{Lp, Px, UPx};
 use figures::{
@@ -20,9 +21,9 @@ use crate::reactive::value::{
     Destination, Dynamic, DynamicReader, IntoDynamic, IntoValue, MapEachCloned, Source, Value,
 };
 use crate::styles::components::{EasingIn, EasingOut, LineHeight, PrimaryColor, SurfaceColor};
-use crate::styles::Dimension;
+use crate::styles::{Component, Dimension, RequireInvalidation};
 use crate::widget::{EventHandling, MakeWidget, Widget, WidgetId, WidgetRef, HANDLED, IGNORED};
-use crate::window::DeviceId;
+use crate::window::{DeviceId, Touch};
 use crate::ConstraintLimit;
 
 // TODO is this useful enough to make public?
@@ -101,6 +102,21 @@ where
 }
 
 /// A widget that supports scrolling its contents.
+///
+/// A scroll can be limited to scrolling [horizontally](Self::horizontal),
+/// [vertically](Self::vertical), or [both](Self::both). When both directions
+/// are enabled, holding shift while using a vertical mouse wheel scrolls
+/// horizontally.
+///
+/// Along each direction that can be scrolled, the contents are measured with
+/// an unbounded constraint. This means an [`Expand`](crate::widgets::Expand)
+/// child cannot fill the scroll along that direction and is instead sized to
+/// fit its own contents. Along a direction that cannot be scrolled, the
+/// contents receive the scroll's available space and can expand normally.
+///
+/// The appearance of the scroll bars can be customized using the style
+/// components in this module, such as [`ScrollBarMode`], which controls
+/// whether scroll bars overlay the contents or reserve space in the layout.
 #[derive(Debug)]
 pub struct Scroll {
     contents: WidgetRef,
@@ -110,12 +126,95 @@ pub struct Scroll {
     ///
     /// When a new value is assigned to this, this widget will scroll its
     /// contents. If a value is out of bounds of the maximum scroll, it will be
-    /// clamped and this dynamic will be updated with clamped scroll.
+    /// clamped and this dynamic will be updated with clamped scroll. This
+    /// allows scrolling to the end of the contents by setting a component to
+    /// `UPx::MAX`.
     pub scroll: Dynamic<Point<UPx>>,
     enabled: Point<bool>,
     max_scroll: DynamicReader<Point<UPx>>,
     vertical_widget: OwnedWidget<ScrollBar>,
     horizontal_widget: OwnedWidget<ScrollBar>,
+    momentum_enabled: Value<bool>,
+    momentum_friction: Value<f32>,
+    momentum: Momentum,
+    touch: Option<TouchDrag>,
+    reserved: Size<UPx>,
+}
+
+/// The velocity, in pixels per second, below which momentum scrolling stops.
+const MINIMUM_MOMENTUM_VELOCITY: f32 = 10.;
+
+#[derive(Debug, Default)]
+struct Momentum {
+    last_event: Option<Instant>,
+    velocity: Point<f32>,
+    animation: AnimationHandle,
+}
+
+impl Momentum {
+    fn stop(&mut self) {
+        self.animation.clear();
+        self.last_event = None;
+        self.velocity = Point::default();
+    }
+
+    fn track(&mut self, delta: Point<f32>) {
+        let now = Instant::now();
+        let elapsed = self
+            .last_event
+            .map(|last_event| now.duration_since(last_event).as_secs_f32());
+        self.velocity = match elapsed {
+            // Events that are too far apart indicate the gesture paused, and
+            // no momentum should be carried over.
+            Some(elapsed) if elapsed > 0. && elapsed < 0.1 => Point::new(
+                (self.velocity.x + delta.x / elapsed) * 0.5,
+                (self.velocity.y + delta.y / elapsed) * 0.5,
+            ),
+            _ => Point::default(),
+        };
+        self.last_event = Some(now);
+    }
+}
+
+/// The distance a touch must travel before its scroll direction is locked.
+const AXIS_LOCK_THRESHOLD: f32 = 8.;
+
+#[derive(Debug)]
+struct TouchDrag {
+    device_id: DeviceId,
+    finger_id: u64,
+    start_location: Point<Px>,
+    last_location: Point<Px>,
+    axes: Option<Point<bool>>,
+}
+
+impl TouchDrag {
+    /// Returns the axes this drag is allowed to scroll.
+    ///
+    /// Once the touch has moved far enough to determine its direction, a
+    /// mostly-horizontal or mostly-vertical drag is locked to that axis for
+    /// the remainder of the gesture. Diagonal drags scroll both axes.
+    fn axes(&mut self) -> Point<bool> {
+        if let Some(axes) = self.axes {
+            return axes;
+        }
+
+        let total = self.last_location - self.start_location;
+        let total = Point::new(total.x.into_float().abs(), total.y.into_float().abs());
+        if total.x.max(total.y) < AXIS_LOCK_THRESHOLD {
+            return Point::new(true, true);
+        }
+
+        let axes = if total.x > total.y * 2. {
+            Point::new(true, false)
+        } else if total.y > total.x * 2. {
+            Point::new(false, true)
+        } else {
+            Point::new(true, true)
+        };
+        self.axes = Some(axes);
+        axes
+    }
 }
 
 #[derive(Debug)]
@@ -173,6 +272,11 @@ impl Scroll {
             max_scroll,
             horizontal_widget: OwnedWidget::new(horizontal),
             vertical_widget: OwnedWidget::new(vertical),
+            momentum_enabled: Value::Constant(false),
+            momentum_friction: Value::Constant(4.),
+            momentum: Momentum::default(),
+            touch: None,
+            reserved: Size::default(),
         }
     }
 
@@ -182,6 +286,14 @@ impl Scroll {
         Self::construct(contents, Point::new(true, true))
     }
 
+    /// Returns a new scroll widget that allows scrolling `contents` both
+    /// horizontally and vertically.
+    ///
+    /// This is equivalent to [`Scroll::new`].
+    pub fn both(contents: impl MakeWidget) -> Self {
+        Self::new(contents)
+    }
+
     /// Returns a new scroll widget that allows scrolling `contents`
     /// horizontally.
     pub fn horizontal(contents: impl MakeWidget) -> Self {
@@ -209,6 +321,30 @@ impl Scroll {
         self
     }
 
+    /// Sets whether scrolling continues with momentum after a touchpad or
+    /// touchscreen gesture ends.
+    ///
+    /// Momentum is only applied to gestures that report pixel-precise deltas.
+    /// Line-based mouse wheel scrolling is unaffected. The default value for
+    /// this setting is `false`, as some platforms provide momentum scrolling
+    /// natively.
+    #[must_use]
+    pub fn momentum(mut self, enabled: impl IntoValue<bool>) -> Self {
+        self.momentum_enabled = enabled.into_value();
+        self
+    }
+
+    /// Sets the friction applied while scrolling with
+    /// [momentum](Self::momentum).
+    ///
+    /// Each second, the scroll velocity is multiplied by `e^-friction`. Higher
+    /// values cause scrolling to stop sooner. The default value is `4.0`.
+    #[must_use]
+    pub fn momentum_friction(mut self, friction: impl IntoValue<f32>) -> Self {
+        self.momentum_friction = friction.into_value();
+        self
+    }
+
     /// Returns a reader for the maximum scroll value.
     ///
     /// This represents the maximum amount that the scroll can be moved by.
@@ -229,6 +365,144 @@ impl Scroll {
         self.control_size.create_reader()
     }
 
+    /// Scrolls the minimum amount necessary to make the widget with `id`
+    /// visible.
+    ///
+    /// `context` must be the context for this widget. This function does
+    /// nothing if `id` is not a descendant of this widget or has not been laid
+    /// out yet. If the widget is larger than this widget's visible area, its
+    /// top-left corner is scrolled into view.
+    pub fn scroll_to_widget(&mut self, id: WidgetId, context: &mut EventContext<'_>) {
+        let Some(target) = context.tree.widget(id) else {
+            return;
+        };
+        if !context
+            .tree
+            .is_child(target.node_id, context.widget().instance())
+        {
+            return;
+        }
+        let (Some(target_layout), Some(layout)) = (target.last_layout(), context.last_layout())
+        else {
+            return;
+        };
+
+        let current = self.scroll.get().into_signed();
+        let visible = self.control_size.get().into_signed();
+        // The widget's location relative to the origin of the contents.
+        let origin = target_layout.origin - layout.origin + current;
+        let new_scroll = Point::new(
+            if self.enabled.x {
+                scroll_to_reveal(current.x, origin.x, target_layout.size.width, visible.width)
+            } else {
+                current.x
+            },
+            if self.enabled.y {
+                scroll_to_reveal(
+                    current.y,
+                    origin.y,
+                    target_layout.size.height,
+                    visible.height,
+                )
+            } else {
+                current.y
+            },
+        )
+        .max(Point::default())
+        .into_unsigned()
+        .min(self.max_scroll.get());
+        self.scroll.set(new_scroll);
+    }
+
+    fn scroll_by(&mut self, delta: Point<f32>) -> bool {
+        let max_scroll = self.max_scroll.get();
+        let mut scroll = self.scroll.lock();
+        let old_scroll = *scroll;
+        if self.enabled.x {
+            scroll.x = (scroll.x.into_signed() - Px::from(delta.x))
+                .into_unsigned()
+                .min(max_scroll.x);
+        }
+        if self.enabled.y {
+            scroll.y = (scroll.y.into_signed() - Px::from(delta.y))
+                .into_unsigned()
+                .min(max_scroll.y);
+        }
+        *scroll != old_scroll
+    }
+
+    /// Converts a vertical mouse wheel delta into a horizontal delta.
+    ///
+    /// Some platforms already perform this conversion when shift is held, in
+    /// which case the delta has no vertical component and is returned as-is.
+    fn shifted_wheel_delta(delta: MouseScrollDelta) -> MouseScrollDelta {
+        match delta {
+            MouseScrollDelta::LineDelta(x, y) if x.abs() <= f32::EPSILON => {
+                MouseScrollDelta::LineDelta(y, 0.)
+            }
+            MouseScrollDelta::PixelDelta(mut px) if px.x.abs() <= f64::EPSILON => {
+                px.x = px.y;
+                px.y = 0.;
+                MouseScrollDelta::PixelDelta(px)
+            }
+            other => other,
+        }
+    }
+
+    fn begin_momentum(&mut self) -> bool {
+        let mut velocity = mem::take(&mut self.momentum.velocity);
+        self.momentum.last_event = None;
+        if !self.enabled.x {
+            velocity.x = 0.;
+        }
+        if !self.enabled.y {
+            velocity.y = 0.;
+        }
+        if !self.momentum_enabled.get()
+            || (velocity.x.abs() < MINIMUM_MOMENTUM_VELOCITY
+                && velocity.y.abs() < MINIMUM_MOMENTUM_VELOCITY)
+        {
+            return false;
+        }
+
+        let friction = self.momentum_friction.get();
+        let max_scroll = self.max_scroll.get();
+        let max_scroll = Point::new(max_scroll.x.into_float(), max_scroll.y.into_float());
+        let scroll = self.scroll.clone();
+        let current = scroll.get();
+        let mut position = Point::new(current.x.into_float(), current.y.into_float());
+        self.momentum.animation = (move |elapsed: Duration| {
+            let seconds = elapsed.as_secs_f32();
+            position.x -= velocity.x * seconds;
+            position.y -= velocity.y * seconds;
+            if position.x <= 0. || position.x >= max_scroll.x {
+                position.x = position.x.clamp(0., max_scroll.x);
+                velocity.x = 0.;
+            }
+            if position.y <= 0. || position.y >= max_scroll.y {
+                position.y = position.y.clamp(0., max_scroll.y);
+                velocity.y = 0.;
+            }
+            let decay = (-friction * seconds).exp();
+            velocity.x *= decay;
+            velocity.y *= decay;
+            scroll.set(Point::new(
+                Px::from(position.x).into_unsigned(),
+                Px::from(position.y).into_unsigned(),
+            ));
+
+            if velocity.x.abs() < MINIMUM_MOMENTUM_VELOCITY
+                && velocity.y.abs() < MINIMUM_MOMENTUM_VELOCITY
+            {
+                ControlFlow::Break(Duration::ZERO)
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .spawn();
+        true
+    }
+
     fn show_scrollbars(&mut self, context: &mut EventContext<'_>) {
         let mut horizontal = self.horizontal_widget.expect_made_mut().widget().lock();
         horizontal
@@ -271,7 +545,19 @@ impl Widget for Scroll {
 
     fn redraw(&mut self, context: &mut crate::context::GraphicsContext<'_, '_, '_, '_>) {
         let contents = self.contents.mounted(&mut context.as_event_context());
-        context.for_other(&contents).redraw();
+        if self.reserved == Size::default() {
+            context.for_other(&contents).redraw();
+        } else {
+            let control_size = self.control_size.get();
+            let visible = Size::new(
+                control_size.width.saturating_sub(self.reserved.width),
+                control_size.height.saturating_sub(self.reserved.height),
+            );
+            context
+                .clipped_to(Rect::from(visible.into_signed()))
+                .for_other(&contents)
+                .redraw();
+        }
         if self.enabled.x {
             let horizontal = self
                 .horizontal_widget
@@ -293,40 +579,88 @@ impl Widget for Scroll {
         available_space: Size<ConstraintLimit>,
         context: &mut LayoutContext<'_, '_, '_, '_>,
     ) -> Size<UPx> {
-        let max_extents = Size::new(
-            if self.enabled.x {
-                ConstraintLimit::SizeToFit(UPx::MAX)
-            } else {
-                available_space.width
-            },
-            if self.enabled.y {
-                ConstraintLimit::SizeToFit(UPx::MAX)
-            } else {
-                available_space.height
-            },
-        );
+        let thickness = if context.get(&ScrollBarMode) == ScrollBars::Reserved {
+            context
+                .get(&ScrollBarThickness)
+                .into_upx(context.gfx.scale())
+                .ceil()
+        } else {
+            UPx::ZERO
+        };
         let contents = self.contents.mounted(&mut context.as_event_context());
-        let new_content_size = context.for_other(&contents).layout(max_extents);
+
+        // When space is reserved for the scroll bars, reserving space for one
+        // bar can cause the contents to overflow along the other direction.
+        // The reserved space only ever grows, so this converges after at most
+        // three measurements.
+        let mut reserved = Size::<UPx>::default();
+        let (max_extents, new_content_size) = loop {
+            let available = Size::new(
+                available_space.width - reserved.width,
+                available_space.height - reserved.height,
+            );
+            let max_extents = Size::new(
+                if self.enabled.x {
+                    ConstraintLimit::SizeToFit(UPx::MAX)
+                } else {
+                    available.width
+                },
+                if self.enabled.y {
+                    ConstraintLimit::SizeToFit(UPx::MAX)
+                } else {
+                    available.height
+                },
+            );
+            let new_content_size = context.for_other(&contents).layout(max_extents);
+            let needed = Size::new(
+                if self.enabled.y && new_content_size.height > available.height.max() {
+                    thickness
+                } else {
+                    UPx::ZERO
+                },
+                if self.enabled.x && new_content_size.width > available.width.max() {
+                    thickness
+                } else {
+                    UPx::ZERO
+                },
+            )
+            .max(reserved);
+            if needed == reserved {
+                break (max_extents, new_content_size);
+            }
+            reserved = needed;
+        };
+        self.reserved = reserved;
         self.content_size.set(new_content_size);
 
         let new_control_size = Size::new(
             if self.enabled.x {
-                constrain_child(available_space.width, new_content_size.width)
+                constrain_child(
+                    available_space.width,
+                    new_content_size.width + reserved.width,
+                )
             } else {
-                new_content_size.width.into_unsigned()
+                new_content_size.width.into_unsigned() + reserved.width
             },
             if self.enabled.y {
-                constrain_child(available_space.height, new_content_size.height)
+                constrain_child(
+                    available_space.height,
+                    new_content_size.height + reserved.height,
+                )
             } else {
-                new_content_size.height.into_unsigned()
+                new_content_size.height.into_unsigned() + reserved.height
             },
         );
+        let bar_space = Size::new(
+            available_space.width - reserved.width,
+            available_space.height - reserved.height,
+        );
 
         let horizontal = self
             .horizontal_widget
             .make_if_needed()
             .mounted(&mut context.as_event_context());
-        let layout = context.for_other(&horizontal).layout(available_space);
+        let layout = context.for_other(&horizontal).layout(bar_space);
         context.set_child_layout(
             &horizontal,
             Rect::new(
@@ -345,7 +679,7 @@ impl Widget for Scroll {
             .vertical_widget
             .make_if_needed()
             .mounted(&mut context.as_event_context());
-        let layout = context.for_other(&vertical).layout(available_space);
+        let layout = context.for_other(&vertical).layout(bar_space);
         context.set_child_layout(
             &vertical,
             Rect::new(
@@ -379,10 +713,26 @@ impl Widget for Scroll {
         &mut self,
         _device_id: DeviceId,
         delta: MouseScrollDelta,
-        _phase: TouchPhase,
+        phase: TouchPhase,
         context: &mut EventContext<'_>,
     ) -> EventHandling {
         let mut handled = false;
+        let shift = context.modifiers().state().shift_key();
+        let delta = if self.enabled.x && self.enabled.y && shift {
+            Self::shifted_wheel_delta(delta)
+        } else {
+            delta
+        };
+        self.momentum.animation.clear();
+        if let MouseScrollDelta::PixelDelta(delta) = delta {
+            match phase {
+                TouchPhase::Started | TouchPhase::Cancelled => self.momentum.stop(),
+                TouchPhase::Moved | TouchPhase::Ended => {
+                    self.momentum
+                        .track(Point::new(delta.x.cast(), delta.y.cast()));
+                }
+            }
+        }
         {
             let mut vertical = self.vertical_widget.expect_made().widget().lock();
             handled |= vertical
@@ -397,6 +747,9 @@ impl Widget for Scroll {
                 .mouse_wheel(delta, context)
                 .is_break();
         }
+        if phase == TouchPhase::Ended && matches!(delta, MouseScrollDelta::PixelDelta(_)) {
+            handled |= self.begin_momentum();
+        }
         if handled {
             self.show_scrollbars(context);
             context.set_needs_redraw();
@@ -407,6 +760,55 @@ impl Widget for Scroll {
         }
     }
 
+    fn touch(&mut self, touch: Touch, context: &mut EventContext<'_>) -> EventHandling {
+        let is_tracked = self.touch.as_ref().is_some_and(|drag| {
+            drag.device_id == touch.device_id && drag.finger_id == touch.finger_id
+        });
+        match touch.phase {
+            TouchPhase::Started => {
+                if self.max_scroll.get() == Point::default() {
+                    return IGNORED;
+                }
+                self.momentum.stop();
+                self.touch = Some(TouchDrag {
+                    device_id: touch.device_id,
+                    finger_id: touch.finger_id,
+                    start_location: touch.location,
+                    last_location: touch.location,
+                    axes: None,
+                });
+            }
+            TouchPhase::Moved => {
+                if let Some(drag) = self.touch.as_mut().filter(|_| is_tracked) {
+                    let delta = touch.location - drag.last_location;
+                    drag.last_location = touch.location;
+                    let axes = drag.axes();
+                    let delta = Point::new(
+                        if axes.x { delta.x.into_float() } else { 0. },
+                        if axes.y { delta.y.into_float() } else { 0. },
+                    );
+                    self.momentum.track(delta);
+                    if self.scroll_by(delta) {
+                        self.show_scrollbars(context);
+                    }
+                }
+            }
+            TouchPhase::Ended => {
+                if is_tracked {
+                    self.touch = None;
+                    self.begin_momentum();
+                }
+            }
+            TouchPhase::Cancelled => {
+                if is_tracked {
+                    self.touch = None;
+                    self.momentum.stop();
+                }
+            }
+        }
+        HANDLED
+    }
+
     fn summarize(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         fmt.debug_struct("Scroll")
             .field("enabled", &self.enabled)
@@ -476,6 +878,16 @@ impl DragInfo {
     }
 }
 
+fn scroll_to_reveal(current: Px, start: Px, length: Px, visible: Px) -> Px {
+    if start < current || length > visible {
+        start
+    } else if start + length > current + visible {
+        start + length - visible
+    } else {
+        current
+    }
+}
+
 fn constrain_child(constraint: ConstraintLimit, measured: UPx) -> UPx {
     match constraint {
         ConstraintLimit::Fill(size) => size.min(measured),
@@ -525,6 +937,7 @@ pub struct ScrollBar {
     control_size: UPx,
     line_height: UPx,
     vertical: bool,
+    mode: ScrollBars,
     info: ScrollbarInfo,
     scrollbar_opacity: Dynamic<ZeroToOne>,
     scrollbar_opacity_animation: Dynamic<OpacityAnimationState>,
@@ -552,6 +965,7 @@ impl ScrollBar {
             line_height: UPx::ZERO,
             control_size: UPx::ZERO,
             vertical,
+            mode: ScrollBars::default(),
             info: ScrollbarInfo::default(),
             scrollbar_opacity: Dynamic::default(),
             scrollbar_opacity_animation: Dynamic::new(OpacityAnimationState {
@@ -724,8 +1138,21 @@ impl Widget for ScrollBar {
         self.scroll.set(constrained);
         self.max_scroll.set(self.info.amount_hidden);
 
-        let opacity = self.scrollbar_opacity.get_tracking_redraw(context);
+        let opacity = match self.mode {
+            ScrollBars::Overlay => self.scrollbar_opacity.get_tracking_redraw(context),
+            ScrollBars::Reserved => ZeroToOne::ONE,
+            ScrollBars::Hidden => ZeroToOne::ZERO,
+        };
         if context.enabled() && self.info.amount_hidden > 0 && opacity > 0. {
+            let track_color = context.get(&ScrollBarTrackColor);
+            if track_color.alpha() > 0 {
+                let track_color = track_color.with_alpha_f32(track_color.alpha_f32() * *opacity);
+                context.gfx.draw_shape(&Shape::filled_rect(
+                    Rect::from(control_size).into_signed(),
+                    track_color,
+                ));
+            }
+
             let rect = if self.vertical {
                 Rect::new(
                     Point::new(control_size.width - self.bar_width, self.info.offset),
@@ -778,7 +1205,7 @@ impl Widget for ScrollBar {
     }
 
     fn hit_test(&mut self, _location: Point<Px>, context: &mut EventContext<'_>) -> bool {
-        self.max_scroll.get() > 0 && context.enabled()
+        self.mode != ScrollBars::Hidden && self.max_scroll.get() > 0 && context.enabled()
     }
 
     fn hover(
@@ -813,6 +1240,7 @@ impl Widget for ScrollBar {
             .into_upx(context.gfx.scale())
             .ceil();
         self.line_height = context.get(&LineHeight).into_upx(context.gfx.scale());
+        self.mode = context.get(&ScrollBarMode);
 
         if self.vertical {
             Size::new(self.bar_width, available_space.height.max())
@@ -926,7 +1354,56 @@ define_components! {
         ScrollBarThumbOutlineColor(Color, "thumb_outline_color", @SurfaceColor)
         /// The thickness of the outline drawn around the scroll bar thumb.
         ScrollBarThumbOutlineThickness(Dimension, "thumb_outline_size", Dimension::Lp(Lp::points(1)))
-        /// The thickness of the outline drawn around the scroll bar thumb.
+        /// The color of the area the scroll bar thumb moves within.
+        ScrollBarTrackColor(Color, "track_color", Color::CLEAR_BLACK)
+        /// Controls how scroll bars are displayed.
+        ScrollBarMode(ScrollBars, "mode", ScrollBars::default())
+        /// The radii of the corners of the scroll bar thumb.
         ScrollBarThumbCornerRadius(CornerRadii<Dimension>, "corner_radius", |context| CornerRadii::from(context.get(&ScrollBarThickness)))
     }
 }
+
+/// Describes how a [`Scroll`] displays its scroll bars.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum ScrollBars {
+    /// Scroll bars are drawn on top of the contents and fade out after a short
+    /// period of inactivity.
+    ///
+    /// This matches the default behavior on macOS.
+    #[default]
+    Overlay,
+    /// Space is reserved in the layout for scroll bars, and the scroll bars
+    /// remain visible while the contents can be scrolled.
+    ///
+    /// This matches the default behavior on Windows.
+    Reserved,
+    /// Scroll bars are never shown. The contents can still be scrolled using
+    /// the mouse wheel, touch, or by updating [`Scroll::scroll`].
+    Hidden,
+}
+
+impl From<ScrollBars> for Component {
+    fn from(mode: ScrollBars) -> Self {
+        Component::custom(mode)
+    }
+}
+
+impl TryFrom<Component> for ScrollBars {
+    type Error = Component;
+
+    fn try_from(value: Component) -> Result<Self, Self::Error> {
+        match value {
+            Component::Custom(custom) => custom
+                .downcast()
+                .copied()
+                .ok_or_else(|| Component::Custom(custom)),
+            other => Err(other),
+        }
+    }
+}
+
+impl RequireInvalidation for ScrollBars {
+    fn requires_invalidation(&self) -> bool {
+        true
+    }
+}
@@ -1,15 +1,29 @@
 //! A selectable, labeled widget representing a value.
 use std::fmt::Debug;
+use std::time::{Duration, Instant};
 
+use figures::units::Px;
+use figures::Zero;
+use kludgine::app::winit::keyboard::{Key, NamedKey};
 use kludgine::Color;
 
+use crate::animation;
+use crate::context::{EventContext, GraphicsContext};
 use crate::reactive::value::{
     Destination, Dynamic, IntoDynamic, IntoValue, MapEach, Source, Value,
 };
-use crate::styles::components::OutlineColor;
-use crate::styles::{Component, DynamicComponent};
-use crate::widget::{MakeWidget, MakeWidgetWithTag, WidgetInstance};
-use crate::widgets::button::{ButtonBackground, ButtonHoverBackground, ButtonKind};
+use crate::styles::components::{CornerRadius, OutlineColor};
+use crate::styles::{Component, CornerRadii, Dimension, DynamicComponent};
+use crate::utils::ModifiersExt;
+use crate::widget::{
+    EventHandling, MakeWidget, MakeWidgetWithTag, WidgetId, WidgetInstance, WidgetList, WidgetRef,
+    WidgetTag, WrapperWidget, HANDLED, IGNORED,
+};
+use crate::widgets::button::{Button, ButtonBackground, ButtonHoverBackground, ButtonKind};
+use crate::widgets::layers::{OverlayLayer, Overlayable};
+use crate::widgets::menu::{Menu, MenuItem};
+use crate::widgets::Style;
+use crate::window::{DeviceId, KeyEvent};
 
 /// A selectable, labeled widget representing a value.
 #[derive(Debug)]
@@ -56,32 +70,460 @@ where
             let value = self.value.clone();
             move |state| state == &value
         });
-        let selected_color = DynamicComponent::new({
-            let selected = selected.clone();
-            move |context| {
-                if selected.get_tracking_redraw(context) {
-                    Some(Component::Color(context.get(&SelectedColor)))
-                } else {
-                    None
-                }
-            }
-        });
-        let kind = (&selected, &self.kind.into_dynamic()).map_each(|(selected, default_kind)| {
-            if *selected {
-                ButtonKind::Solid
+        selectable_button(
+            self.label.into_button().on_click(move |_| {
+                self.state.set(self.value.clone());
+            }),
+            selected,
+            self.kind,
+        )
+        .make_with_tag(id)
+    }
+}
+
+/// A button that toggles a boolean state each time it is clicked.
+///
+/// While the state is `true`, the button is drawn using the same style as a
+/// selected [`Select`].
+#[derive(Debug)]
+pub struct ToggleButton {
+    /// The state of the toggle.
+    pub state: Dynamic<bool>,
+    /// The button kind to use when the toggle is not active. Toggle buttons
+    /// default to [`ButtonKind::Transparent`].
+    pub kind: Value<ButtonKind>,
+    label: WidgetInstance,
+}
+
+impl ToggleButton {
+    /// Returns a new toggle button that inverts `state` when pressed. `label`
+    /// is drawn inside of the button.
+    pub fn new(state: impl IntoDynamic<bool>, label: impl MakeWidget) -> Self {
+        Self {
+            state: state.into_dynamic(),
+            kind: Value::Constant(ButtonKind::Transparent),
+            label: label.make_widget(),
+        }
+    }
+
+    /// Updates the button kind to use when the toggle is not active, and
+    /// returns self.
+    ///
+    /// Toggle buttons default to [`ButtonKind::Transparent`].
+    #[must_use]
+    pub fn kind(mut self, kind: impl IntoValue<ButtonKind>) -> Self {
+        self.kind = kind.into_value();
+        self
+    }
+}
+
+impl MakeWidgetWithTag for ToggleButton {
+    fn make_with_tag(self, id: WidgetTag) -> WidgetInstance {
+        let state = self.state.clone();
+        selectable_button(
+            self.label.into_button().on_click(move |_| {
+                state.map_mut(|mut state| *state = !*state);
+            }),
+            self.state,
+            self.kind,
+        )
+        .make_with_tag(id)
+    }
+}
+
+fn selectable_button(
+    button: Button,
+    selected: Dynamic<bool>,
+    default_kind: Value<ButtonKind>,
+) -> Style {
+    let selected_color = DynamicComponent::new({
+        let selected = selected.clone();
+        move |context| {
+            if selected.get_tracking_redraw(context) {
+                Some(Component::Color(context.get(&SelectedColor)))
             } else {
-                *default_kind
+                None
+            }
+        }
+    });
+    let kind = (&selected, &default_kind.into_dynamic()).map_each(|(selected, default_kind)| {
+        if *selected {
+            ButtonKind::Solid
+        } else {
+            *default_kind
+        }
+    });
+    button
+        .kind(kind)
+        .with_dynamic(&ButtonBackground, selected_color.clone())
+        .with_dynamic(&ButtonHoverBackground, selected_color)
+}
+
+/// A list of [`Select`] widgets that share a single state and can be
+/// navigated using the keyboard.
+///
+/// While any of the group's options are focused:
+///
+/// - The arrow keys select the previous or next option, wrapping around at the
+///   ends of the list.
+/// - Typing selects the next option whose label starts with the typed text.
+///   Characters typed in quick succession are combined into a single search.
+///   Typing the same character repeatedly cycles through the options that
+///   start with that character.
+///
+/// The newly selected option is also given focus.
+///
+/// By default, each option is shown as a [`Select`] in a column. When
+/// [`SelectGroup::segmented`] is used, the options are instead shown in a row
+/// of joined buttons. When [`SelectGroup::dropdown_in`] is used, the group is
+/// instead shown as a single button displaying the selected option's label.
+/// Clicking the button opens a [`Menu`] of the options below it.
+#[derive(Debug)]
+pub struct SelectGroup<T> {
+    /// The state (value) of the group.
+    pub state: Dynamic<T>,
+    options: Vec<(T, String)>,
+    dropdown: Option<OverlayLayer>,
+    segmented: bool,
+}
+
+impl<T> SelectGroup<T> {
+    /// Returns a new, empty group that updates `state` when an option is
+    /// selected.
+    pub fn new(state: impl IntoDynamic<T>) -> Self {
+        Self {
+            state: state.into_dynamic(),
+            options: Vec::new(),
+            dropdown: None,
+            segmented: false,
+        }
+    }
+
+    /// Shows this group's options as a row of joined buttons, and returns
+    /// self.
+    ///
+    /// This style of control, often called a segmented control, is commonly
+    /// used to switch between views or modes. Only the outer corners of the
+    /// row are rounded using the group's [`CornerRadius`].
+    ///
+    /// This setting has no effect if [`dropdown_in`](Self::dropdown_in) is
+    /// also used.
+    #[must_use]
+    pub fn segmented(mut self) -> Self {
+        self.segmented = true;
+        self
+    }
+
+    /// Shows this group as a dropdown button whose options are presented in
+    /// `overlay` when clicked, and returns self.
+    ///
+    /// The menu of options is closed when an option is chosen, when the
+    /// Escape key is pressed, or when focus moves elsewhere, such as by
+    /// clicking outside of the menu.
+    #[must_use]
+    pub fn dropdown_in(mut self, overlay: &OverlayLayer) -> Self {
+        self.dropdown = Some(overlay.clone());
+        self
+    }
+
+    /// Adds an option that sets the group's state to `value` when selected,
+    /// and returns self.
+    ///
+    /// `label` is drawn inside of the option's [`Select`] and is used when
+    /// searching for options by typing.
+    #[must_use]
+    pub fn with(mut self, value: T, label: impl Into<String>) -> Self {
+        self.options.push((value, label.into()));
+        self
+    }
+}
+
+impl<T> SelectGroup<T>
+where
+    T: Unpin + Clone + Debug + PartialEq + Send + Sync + 'static,
+{
+    fn make_dropdown(&self, overlay: OverlayLayer) -> WidgetInstance {
+        let options = self.options.clone();
+        let selected_label = self.state.map_each({
+            let options = options.clone();
+            move |state| {
+                options
+                    .iter()
+                    .find(|(value, _)| value == state)
+                    .map(|(_, label)| label.clone())
+                    .unwrap_or_default()
             }
         });
-        self.label
+        let state = self.state.clone();
+        let (button_tag, button_id) = WidgetTag::new();
+
+        selected_label
             .into_button()
             .on_click(move |_| {
-                self.state.set(self.value.clone());
+                let menu = options.iter().fold(
+                    Menu::new().on_selected({
+                        let state = state.clone();
+                        move |value| state.set(value)
+                    }),
+                    |menu, (value, label)| menu.with(MenuItem::new(value.clone(), label.clone())),
+                );
+                menu.overlay_in(&overlay).below(button_id).show();
             })
-            .kind(kind)
-            .with_dynamic(&ButtonBackground, selected_color.clone())
-            .with_dynamic(&ButtonHoverBackground, selected_color)
-            .make_with_tag(id)
+            .make_with_tag(button_tag)
+    }
+}
+
+impl<T> MakeWidgetWithTag for SelectGroup<T>
+where
+    T: Unpin + Clone + Debug + PartialEq + Send + Sync + 'static,
+{
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        let mut options = Vec::with_capacity(self.options.len());
+        let mut corner_radius = None;
+        let child = if let Some(overlay) = self.dropdown.clone() {
+            let dropdown = self.make_dropdown(overlay);
+            for (value, label) in self.options {
+                options.push(GroupOption {
+                    value,
+                    search_label: label.to_lowercase(),
+                    id: None,
+                });
+            }
+            dropdown
+        } else {
+            let mut selects = WidgetList::new();
+            let count = self.options.len();
+            if self.segmented {
+                corner_radius = Some(Dynamic::new(CornerRadii::from(Dimension::ZERO)));
+            }
+            for (index, (value, label)) in self.options.into_iter().enumerate() {
+                let (option_tag, id) = WidgetTag::new();
+                let select = Select::new(value.clone(), self.state.clone(), label.clone());
+                if let Some(corner_radius) = &corner_radius {
+                    selects.push(
+                        select
+                            .kind(ButtonKind::Outline)
+                            .make_with_tag(option_tag)
+                            .with(&CornerRadius, segment_radii(corner_radius, index, count)),
+                    );
+                } else {
+                    selects.push(select.make_with_tag(option_tag));
+                }
+                options.push(GroupOption {
+                    value,
+                    search_label: label.to_lowercase(),
+                    id: Some(id),
+                });
+            }
+            if self.segmented {
+                selects.into_columns().gutter(Px::ZERO).make_widget()
+            } else {
+                selects.into_rows().make_widget()
+            }
+        };
+
+        SelectGroupNavigation {
+            child: WidgetRef::new(child),
+            state: self.state,
+            options,
+            type_ahead: TypeAhead::default(),
+            corner_radius,
+        }
+        .make_with_tag(tag)
+    }
+}
+
+/// Returns the corner radii for the segment at `index` of a segmented group
+/// containing `count` segments. Only the outermost corners are rounded.
+fn segment_radii(
+    radii: &Dynamic<CornerRadii<Dimension>>,
+    index: usize,
+    count: usize,
+) -> Dynamic<CornerRadii<Dimension>> {
+    fn rounded_if(rounded: bool, radius: Dimension) -> Dimension {
+        if rounded {
+            radius
+        } else {
+            Dimension::ZERO
+        }
+    }
+
+    let first = index == 0;
+    let last = index + 1 == count;
+    radii.map_each(move |radii| CornerRadii {
+        top_left: rounded_if(first, radii.top_left),
+        top_right: rounded_if(last, radii.top_right),
+        bottom_right: rounded_if(last, radii.bottom_right),
+        bottom_left: rounded_if(first, radii.bottom_left),
+    })
+}
+
+/// The amount of time between key presses in which typed characters are
+/// combined into one search.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+struct GroupOption<T> {
+    value: T,
+    search_label: String,
+    id: Option<WidgetId>,
+}
+
+#[derive(Debug)]
+struct SelectGroupNavigation<T> {
+    child: WidgetRef,
+    state: Dynamic<T>,
+    options: Vec<GroupOption<T>>,
+    type_ahead: TypeAhead,
+    corner_radius: Option<Dynamic<CornerRadii<Dimension>>>,
+}
+
+impl<T> SelectGroupNavigation<T>
+where
+    T: Clone + Debug + PartialEq + Send + Sync + 'static,
+{
+    fn selected_index(&self) -> Option<usize> {
+        self.state.map_ref(|state| {
+            self.options
+                .iter()
+                .position(|option| &option.value == state)
+        })
+    }
+
+    fn select(&self, index: usize, context: &mut EventContext<'_>) {
+        let option = &self.options[index];
+        self.state.set(option.value.clone());
+        let Some(id) = option.id else {
+            return;
+        };
+        if let Some(mut select) = context.for_other(&id) {
+            select.focus();
+        }
+    }
+
+    fn step(&self, forwards: bool, context: &mut EventContext<'_>) {
+        let count = self.options.len();
+        if count == 0 {
+            return;
+        }
+        let next = match (self.selected_index(), forwards) {
+            (Some(index), true) => (index + 1) % count,
+            (Some(index), false) => (index + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+        self.select(next, context);
+    }
+
+    fn type_ahead(&mut self, text: &str, context: &mut EventContext<'_>) {
+        let selected = self.selected_index();
+        let options = &self.options;
+        if let Some(index) =
+            self.type_ahead
+                .find(text, animation::now(), selected, options.len(), |index| {
+                    options[index].search_label.as_str()
+                })
+        {
+            self.select(index, context);
+        }
+    }
+}
+
+/// The state of a search performed by typing while a [`SelectGroup`] is
+/// focused.
+#[derive(Debug, Default)]
+struct TypeAhead {
+    search: String,
+    last_search: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// Adds `text` typed at `now` to the search and returns the index of the
+    /// option that should be selected, if any.
+    ///
+    /// `label` returns the lowercase label of the option at an index less than
+    /// `count`.
+    fn find<'a>(
+        &mut self,
+        text: &str,
+        now: Instant,
+        selected: Option<usize>,
+        count: usize,
+        label: impl Fn(usize) -> &'a str,
+    ) -> Option<usize> {
+        if self.last_search.map_or(true, |last| {
+            now.saturating_duration_since(last) > TYPE_AHEAD_TIMEOUT
+        }) {
+            self.search.clear();
+        }
+        self.last_search = Some(now);
+        self.search.push_str(&text.to_lowercase());
+
+        // Typing the same character repeatedly cycles between the options that
+        // start with that character, beginning after the current selection.
+        // Otherwise, the current selection is kept if it still matches.
+        let mut chars = self.search.chars();
+        let first = chars.next();
+        let (needle, skip) = match first {
+            Some(first) if chars.all(|ch| ch == first) => (&self.search[..first.len_utf8()], 1),
+            _ => (self.search.as_str(), 0),
+        };
+
+        let start = selected.map_or(0, |index| index + skip);
+        (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|index| label(*index).starts_with(needle))
+    }
+}
+
+impl<T> WrapperWidget for SelectGroupNavigation<T>
+where
+    T: Clone + Debug + PartialEq + Send + Sync + 'static,
+{
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn redraw_background(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        if let Some(corner_radius) = &self.corner_radius {
+            corner_radius.set(context.get(&CornerRadius));
+        }
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        match input.logical_key {
+            Key::Named(NamedKey::ArrowUp | NamedKey::ArrowLeft) => {
+                if input.state.is_pressed() {
+                    self.step(false, context);
+                }
+                HANDLED
+            }
+            Key::Named(NamedKey::ArrowDown | NamedKey::ArrowRight) => {
+                if input.state.is_pressed() {
+                    self.step(true, context);
+                }
+                HANDLED
+            }
+            _ => match input.text.as_deref() {
+                Some(text)
+                    if !context.modifiers().primary()
+                        && !text.trim().is_empty()
+                        && !text.chars().any(char::is_control) =>
+                {
+                    if input.state.is_pressed() {
+                        self.type_ahead(text, context);
+                    }
+                    HANDLED
+                }
+                _ => IGNORED,
+            },
+        }
     }
 }
 
@@ -91,3 +533,98 @@ define_components! {
         SelectedColor(Color, "color", @OutlineColor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{TypeAhead, TYPE_AHEAD_TIMEOUT};
+
+    const LABELS: [&str; 5] = ["apple", "banana", "blueberry", "cherry", "apricot"];
+
+    /// Types each of `keys` after the previous key, selecting each match, and
+    /// returns the selected indices.
+    fn type_keys(
+        type_ahead: &mut TypeAhead,
+        selected: &mut Option<usize>,
+        now: &mut Instant,
+        keys: &str,
+    ) -> Vec<Option<usize>> {
+        keys.chars()
+            .map(|key| {
+                *now += Duration::from_millis(100);
+                let found =
+                    type_ahead.find(&key.to_string(), *now, *selected, LABELS.len(), |index| {
+                        LABELS[index]
+                    });
+                if found.is_some() {
+                    *selected = found;
+                }
+                found
+            })
+            .collect()
+    }
+
+    #[test]
+    fn combines_keys() {
+        let mut type_ahead = TypeAhead::default();
+        let mut selected = None;
+        let mut now = Instant::now();
+        assert_eq!(
+            type_keys(&mut type_ahead, &mut selected, &mut now, "bl"),
+            [Some(1), Some(2)]
+        );
+        assert_eq!(
+            type_keys(&mut type_ahead, &mut selected, &mut now, "x"),
+            [None]
+        );
+        assert_eq!(selected, Some(2));
+    }
+
+    #[test]
+    fn repeated_letters_cycle() {
+        let mut type_ahead = TypeAhead::default();
+        let mut selected = None;
+        let mut now = Instant::now();
+        assert_eq!(
+            type_keys(&mut type_ahead, &mut selected, &mut now, "aaa"),
+            [Some(0), Some(4), Some(0)]
+        );
+    }
+
+    #[test]
+    fn cycling_wraps_around() {
+        let mut type_ahead = TypeAhead::default();
+        let mut selected = Some(3);
+        let mut now = Instant::now();
+        // Searching begins after the current selection and wraps to the
+        // start of the options.
+        assert_eq!(
+            type_keys(&mut type_ahead, &mut selected, &mut now, "bbb"),
+            [Some(1), Some(2), Some(1)]
+        );
+    }
+
+    #[test]
+    fn timeout_resets_search() {
+        let mut type_ahead = TypeAhead::default();
+        let mut selected = None;
+        let mut now = Instant::now();
+        assert_eq!(
+            type_keys(&mut type_ahead, &mut selected, &mut now, "b"),
+            [Some(1)]
+        );
+
+        // Within the timeout, "c" continues the search for "bc".
+        assert_eq!(
+            type_keys(&mut type_ahead, &mut selected, &mut now, "c"),
+            [None]
+        );
+
+        now += TYPE_AHEAD_TIMEOUT;
+        assert_eq!(
+            type_keys(&mut type_ahead, &mut selected, &mut now, "c"),
+            [Some(3)]
+        );
+    }
+}
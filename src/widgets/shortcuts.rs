@@ -12,6 +12,17 @@ use crate::window::KeyEvent;
 use crate::{ModifiersExt, ModifiersStateExt};
 
 /// A collection of keyboard shortcut handlers.
+///
+/// Windows invoke their shortcuts from
+/// [`Window::with_shortcuts`](crate::window::Window::with_shortcuts) after the
+/// focused widget had a chance to handle the keyboard input, and their
+/// accelerators from
+/// [`Window::with_accelerators`](crate::window::Window::with_accelerators)
+/// before widgets receive the input. There is no separate registration API on
+/// [`RunningWindow`](crate::window::RunningWindow): providing a
+/// [`Dynamic<ShortcutMap>`](crate::reactive::value::Dynamic) to either function
+/// allows shortcuts to be registered and unregistered while the window is open,
+/// including from within the window's own callbacks.
 #[derive(Default, Debug, Clone)]
 pub struct ShortcutMap(AHashMap<Shortcut, ShortcutConfig>);
 
@@ -78,15 +89,66 @@ impl ShortcutMap {
         callback: SharedCallback<KeyEvent, EventHandling>,
     ) {
         let (first, second) = Shortcut { key, modifiers }.into_variations();
-        let config = ShortcutConfig { repeat, callback };
+        let config = ShortcutConfig {
+            repeat,
+            variation: false,
+            callback,
+        };
 
         if let Some(second) = second {
-            self.0.insert(second, config.clone());
+            self.0.insert(
+                second,
+                ShortcutConfig {
+                    variation: true,
+                    ..config.clone()
+                },
+            );
         }
 
         self.0.insert(first, config);
     }
 
+    /// Removes the handler for `key` pressed with `modifiers`.
+    ///
+    /// Returns true if a handler was removed.
+    pub fn remove(&mut self, key: impl Into<ShortcutKey>, modifiers: ModifiersState) -> bool {
+        let (first, second) = Shortcut {
+            key: key.into(),
+            modifiers,
+        }
+        .into_variations();
+
+        let removed_second = second.is_some_and(|second| self.0.remove(&second).is_some());
+        self.0.remove(&first).is_some() || removed_second
+    }
+
+    /// Returns true if a handler is registered for `key` pressed with
+    /// `modifiers`.
+    #[must_use]
+    pub fn contains(&self, key: impl Into<ShortcutKey>, modifiers: ModifiersState) -> bool {
+        self.0.contains_key(&Shortcut {
+            key: key.into(),
+            modifiers,
+        })
+    }
+
+    /// Returns true if no shortcuts are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the keys and modifiers of each registered
+    /// shortcut.
+    ///
+    /// This can be used to display the available shortcuts, such as within a
+    /// menu.
+    pub fn iter(&self) -> impl Iterator<Item = (&ShortcutKey, ModifiersState)> + '_ {
+        self.0.iter().filter_map(|(shortcut, config)| {
+            (!config.variation).then_some((&shortcut.key, shortcut.modifiers))
+        })
+    }
+
     /// Invokes any associated handlers for `input`.
     ///
     /// Returns whether the event has been handled or not.
@@ -294,6 +356,7 @@ impl From<NativeKeyCode> for ShortcutKey {
 #[derive(Debug, Clone)]
 struct ShortcutConfig {
     repeat: bool,
+    variation: bool,
     callback: SharedCallback<KeyEvent, EventHandling>,
 }
 
@@ -327,3 +390,55 @@ impl WrapperWidget for Shortcuts {
         self.shortcuts.input(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use kludgine::app::winit::keyboard::{KeyCode, ModifiersState, NamedKey};
+
+    use super::{ShortcutKey, ShortcutMap};
+    use crate::widget::HANDLED;
+    use crate::ModifiersStateExt;
+
+    fn shortcuts() -> ShortcutMap {
+        ShortcutMap::default()
+            .with_shortcut("s", ModifiersState::PRIMARY, |_| HANDLED)
+            .with_repeating_shortcut(KeyCode::KeyQ, ModifiersState::CONTROL, |_| HANDLED)
+    }
+
+    #[test]
+    fn contains_variations() {
+        let shortcuts = shortcuts();
+        assert!(shortcuts.contains("s", ModifiersState::PRIMARY));
+        assert!(shortcuts.contains("S", ModifiersState::PRIMARY));
+        assert!(shortcuts.contains(KeyCode::KeyQ, ModifiersState::CONTROL));
+        assert!(!shortcuts.contains("s", ModifiersState::empty()));
+        assert!(!shortcuts.contains("q", ModifiersState::CONTROL));
+        assert!(!shortcuts.contains(NamedKey::Enter, ModifiersState::PRIMARY));
+    }
+
+    #[test]
+    fn iter_skips_variations() {
+        let shortcuts = shortcuts();
+        let registered = shortcuts
+            .iter()
+            .map(|(key, modifiers)| (key.clone(), modifiers))
+            .collect::<Vec<_>>();
+        assert_eq!(registered.len(), 2);
+        assert!(registered.contains(&(ShortcutKey::from("s"), ModifiersState::PRIMARY)));
+        assert!(registered.contains(&(ShortcutKey::from(KeyCode::KeyQ), ModifiersState::CONTROL)));
+    }
+
+    #[test]
+    fn remove_removes_variations() {
+        let mut shortcuts = shortcuts();
+        assert!(shortcuts.remove("S", ModifiersState::PRIMARY));
+        assert!(!shortcuts.contains("s", ModifiersState::PRIMARY));
+        assert!(!shortcuts.contains("S", ModifiersState::PRIMARY));
+        assert!(!shortcuts.remove("s", ModifiersState::PRIMARY));
+        assert!(!shortcuts.is_empty());
+
+        assert!(shortcuts.remove(KeyCode::KeyQ, ModifiersState::CONTROL));
+        assert!(shortcuts.is_empty());
+        assert_eq!(shortcuts.iter().count(), 0);
+    }
+}
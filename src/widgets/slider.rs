@@ -16,8 +16,8 @@ use crate::animation::{LinearInterpolate, PercentBetween, ZeroToOne};
 use crate::context::{EventContext, GraphicsContext, LayoutContext};
 use crate::reactive::value::{Destination, Dynamic, IntoDynamic, IntoValue, Source, Value};
 use crate::styles::components::{
-    AutoFocusableControls, DisabledWidgetAccentColor, OpaqueWidgetColor, OutlineColor,
-    WidgetAccentColor,
+    AutoFocusableControls, DisabledOutlineColor, DisabledWidgetAccentColor, OpaqueWidgetColor,
+    OutlineColor, WidgetAccentColor,
 };
 use crate::styles::{Dimension, HorizontalOrder, VerticalOrder, VisualOrder};
 use crate::widget::{EventHandling, Widget, HANDLED, IGNORED};
@@ -25,6 +25,23 @@ use crate::window::{DeviceId, KeyEvent};
 use crate::ConstraintLimit;
 
 /// A widget that allows sliding between two values.
+///
+/// When the value is a [`RangeInclusive<T>`] or a `(T, T)` tuple, the slider
+/// shows two knobs: one for the start of the range and one for the end. The
+/// portion of the track between the knobs is drawn using [`TrackColor`], and
+/// neither knob can be moved past the other. Clicking the track moves
+/// whichever knob is nearest to the click location.
+///
+/// # Keyboard Input
+///
+/// While focused, the focused knob can be moved using the keyboard:
+///
+/// - Arrow keys move by one [`step`](Self::step). Left and Up move towards the
+///   minimum, and Right and Down move towards the maximum.
+/// - Page Up and Page Down move by a tenth of the slider's range, or by one
+///   step if a step is larger. Page Up moves towards the minimum, matching the
+///   Up arrow.
+/// - Home and End move to the minimum and maximum.
 #[derive(Debug, Clone)]
 pub struct Slider<T>
 where
@@ -45,6 +62,8 @@ where
     ///
     /// This defaults to `0.05`/5%.
     pub step: Value<ZeroToOne>,
+    steps: Value<usize>,
+    ticks: SliderTicks<T::Value>,
     knob_visible: bool,
     interactive: bool,
     knob_size: UPx,
@@ -61,6 +80,12 @@ enum Knob {
     End,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum KeyAction {
+    Step(bool, f32),
+    Limit(bool),
+}
+
 impl<T> Slider<T>
 where
     T: SliderValue,
@@ -92,6 +117,8 @@ where
             knob_visible: true,
             interactive: true,
             step: Value::Constant(ZeroToOne::new(0.05)),
+            steps: Value::Constant(0),
+            ticks: SliderTicks::None,
             knob_size: UPx::ZERO,
             horizontal: true,
             rendered_size: Px::ZERO,
@@ -129,6 +156,32 @@ where
         self
     }
 
+    /// Divides the range of this slider into `count` equal steps and returns
+    /// self.
+    ///
+    /// When set, values chosen by the user snap to the nearest step, and
+    /// alternative inputs (e.g, keyboard/mousewheel) advance by one step at a
+    /// time instead of by [`step`](Self::step). A slider over `0..=10` with 10
+    /// steps only produces whole numbers.
+    ///
+    /// A `count` of `0` disables snapping, which is the default.
+    #[must_use]
+    pub fn steps(mut self, count: impl IntoValue<usize>) -> Self {
+        self.steps = count.into_value();
+        self
+    }
+
+    /// Draws tick marks along the track of this slider and returns self.
+    ///
+    /// `ticks` can be a number of evenly spaced ticks or a list of values to
+    /// place ticks at. See [`SliderTicks`] for more information. The color of
+    /// the ticks is controlled by [`TickColor`].
+    #[must_use]
+    pub fn ticks(mut self, ticks: impl Into<SliderTicks<T::Value>>) -> Self {
+        self.ticks = ticks.into();
+        self
+    }
+
     /// Updates this slider to not show knobs and returns self.
     ///
     /// This also prevents the slider from being focused.
@@ -215,6 +268,26 @@ where
             );
         }
 
+        // Draw the tick marks
+        if !spec.ticks.is_empty() {
+            let tick_width = Lp::points(1).into_px(context.gfx.scale()).max(Px::new(1));
+            let tick_length = spec.track_size.max(spec.half_knob);
+            let track_center = start_inset + half_track;
+            for tick in &spec.ticks {
+                let center = track_length * *tick + spec.half_knob;
+                context.gfx.draw_shape(
+                    Shape::filled_rect(
+                        self.orient_rectangle(Rect::new(
+                            Point::new(center - tick_width / 2, track_center - tick_length / 2),
+                            Size::new(tick_width, tick_length),
+                        )),
+                        spec.tick_color,
+                    )
+                    .translate_by(inset),
+                );
+            }
+        }
+
         // Draw the knob
         if spec.knob_size > 0 {
             let focus = context.focused(true).then_some(self.focused_knob).flatten();
@@ -292,7 +365,7 @@ where
         };
         let track_width = self.rendered_size - knob_size;
         let position = position.clamp(Px::ZERO, track_width);
-        let percent = position.into_float() / track_width.into_float();
+        let percent = self.snap(position.into_float() / track_width.into_float());
 
         let min = self.minimum.get();
         let max = self.maximum.get();
@@ -357,23 +430,36 @@ where
         };
         let min = self.minimum.get();
         let max = self.maximum.get();
-        let step = self.step.get();
         let mut current_percent = current.percent_between(&min, &max);
-        let new_value = loop {
+        let steps = self.steps.get();
+        let new_value = if steps > 0 {
+            let steps = steps.cast::<f32>();
+            let current_step = (*current_percent * steps).round();
+            let delta = factor.round().max(1.);
             let next = if forwards {
-                *current_percent + *step * factor
+                current_step + delta
             } else {
-                *current_percent - *step * factor
+                current_step - delta
             };
-            if next < 0. {
-                break min.clone();
-            } else if next > 1. {
-                break max.clone();
-            }
-            current_percent = ZeroToOne::new(next);
-            let generated_value = min.lerp(&max, *current_percent);
-            if generated_value != current {
-                break generated_value;
+            min.lerp(&max, next.clamp(0., steps) / steps)
+        } else {
+            let step = self.step.get();
+            loop {
+                let next = if forwards {
+                    *current_percent + *step * factor
+                } else {
+                    *current_percent - *step * factor
+                };
+                if next < 0. {
+                    break min.clone();
+                } else if next > 1. {
+                    break max.clone();
+                }
+                current_percent = ZeroToOne::new(next);
+                let generated_value = min.lerp(&max, *current_percent);
+                if generated_value != current {
+                    break generated_value;
+                }
             }
         };
         // Check that the new value didn't go past the other marker, or min/max.
@@ -392,6 +478,54 @@ where
         }
     }
 
+    /// Returns the factor to [`step`](Self::step) by to move a tenth of the
+    /// slider's range, or at least one step.
+    fn page_factor(&self) -> f32 {
+        let steps = self.steps.get();
+        if steps > 0 {
+            (steps.cast::<f32>() / 10.).max(1.)
+        } else {
+            (0.1 / *self.step.get()).max(1.)
+        }
+    }
+
+    fn jump_to_limit(&mut self, to_maximum: bool) {
+        let Some(focus) = self
+            .focused_knob
+            .or_else(|| (!T::RANGED).then_some(Knob::Start))
+        else {
+            return;
+        };
+        let limit = if to_maximum {
+            self.maximum.get()
+        } else {
+            self.minimum.get()
+        };
+        // The focused knob stops at the other knob rather than passing it.
+        let (start, end) = match (focus, T::into_parts(self.value.get())) {
+            (_, (_, None)) => (limit, None),
+            (Knob::Start, (_, Some(end))) => {
+                let start = if limit <= end { limit } else { end.clone() };
+                (start, Some(end))
+            }
+            (Knob::End, (start, Some(_))) => {
+                let end = if limit >= start { limit } else { start.clone() };
+                (start, Some(end))
+            }
+        };
+        self.value.set(T::from_parts(start, end));
+    }
+
+    fn snap(&self, percent: f32) -> f32 {
+        let steps = self.steps.get();
+        if steps > 0 {
+            let steps = steps.cast::<f32>();
+            (percent * steps).round() / steps
+        } else {
+            percent
+        }
+    }
+
     fn orient_rectangle(&self, rect: Rect<Px>) -> Rect<Px> {
         if self.horizontal {
             rect
@@ -419,17 +553,19 @@ where
     T: SliderValue,
 {
     fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
-        let (track_color, inactive_track_color, knob_color) = if context.enabled() {
+        let (track_color, inactive_track_color, knob_color, tick_color) = if context.enabled() {
             (
                 context.get(&TrackColor),
                 context.get(&InactiveTrackColor),
                 context.get(&KnobColor),
+                context.get(&TickColor),
             )
         } else {
             (
                 context.get(&DisabledTrackColor),
                 context.get(&DisabledInactiveTrackColor),
                 context.get(&DisabledKnobColor),
+                context.get(&DisabledTickColor),
             )
         };
         let knob_size = self.knob_size.into_signed();
@@ -478,6 +614,20 @@ where
 
         let start_percent = start_value.percent_between(&min, &max);
         let end_percent = end_value.map(|end| *end.percent_between(&min, &max));
+        let ticks = match &self.ticks {
+            SliderTicks::None => Vec::new(),
+            SliderTicks::Evenly(count) => {
+                let spacing = count.saturating_sub(1).max(1).cast::<f32>();
+                (0..*count)
+                    .map(|index| index.cast::<f32>() / spacing)
+                    .collect()
+            }
+            SliderTicks::At(values) => values
+                .iter()
+                .filter(|value| **value >= min && **value <= max)
+                .map(|value| *value.percent_between(&min, &max))
+                .collect(),
+        };
 
         let size = context.gfx.region().size;
         self.horizontal = size.width >= size.height;
@@ -493,6 +643,8 @@ where
                 knob_color,
                 track_color,
                 inactive_track_color,
+                ticks,
+                tick_color,
             },
             context,
         );
@@ -681,16 +833,23 @@ where
             return IGNORED;
         };
 
-        let forwards = match input.logical_key {
-            Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowUp) => false,
-            Key::Named(NamedKey::ArrowRight | NamedKey::ArrowDown) => true,
+        let action = match input.logical_key {
+            Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowUp) => KeyAction::Step(false, 1.),
+            Key::Named(NamedKey::ArrowRight | NamedKey::ArrowDown) => KeyAction::Step(true, 1.),
+            Key::Named(NamedKey::PageUp) => KeyAction::Step(false, self.page_factor()),
+            Key::Named(NamedKey::PageDown) => KeyAction::Step(true, self.page_factor()),
+            Key::Named(NamedKey::Home) => KeyAction::Limit(false),
+            Key::Named(NamedKey::End) => KeyAction::Limit(true),
             _ => return IGNORED,
         };
         if !input.state.is_pressed() {
             return HANDLED;
         }
 
-        self.step(forwards, 1.);
+        match action {
+            KeyAction::Step(forwards, factor) => self.step(forwards, factor),
+            KeyAction::Limit(to_maximum) => self.jump_to_limit(to_maximum),
+        }
 
         HANDLED
     }
@@ -745,6 +904,8 @@ struct TrackSpec {
     knob_color: Color,
     track_color: Color,
     inactive_track_color: Color,
+    ticks: Vec<f32>,
+    tick_color: Color,
 }
 
 impl TrackSpec {
@@ -780,6 +941,38 @@ define_components! {
         InactiveTrackColor(Color, "inactive_track_color", |context| context.get(&OpaqueWidgetColor))
         /// The color of the track that the knob rests.
         DisabledInactiveTrackColor(Color, "disabled_inactive_track_color", |context| context.get(&OpaqueWidgetColor))
+        /// The color of the tick marks drawn along the track.
+        TickColor(Color, "tick_color", @OutlineColor)
+        /// The color of the tick marks drawn along the track when the widget is disabled.
+        DisabledTickColor(Color, "disabled_tick_color", @DisabledOutlineColor)
+    }
+}
+
+/// Tick marks drawn along the track of a [`Slider`].
+///
+/// This type can be created from a `usize` to draw that many evenly spaced
+/// ticks, or from a list of values to draw a tick at each value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SliderTicks<T> {
+    /// No tick marks are drawn.
+    None,
+    /// The given number of ticks are spaced evenly along the track, with the
+    /// first at the slider's minimum and the last at its maximum.
+    Evenly(usize),
+    /// A tick is drawn at each value that is between the slider's minimum and
+    /// maximum.
+    At(Vec<T>),
+}
+
+impl<T> From<usize> for SliderTicks<T> {
+    fn from(count: usize) -> Self {
+        Self::Evenly(count)
+    }
+}
+
+impl<T> From<Vec<T>> for SliderTicks<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self::At(values)
     }
 }
 
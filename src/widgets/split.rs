@@ -0,0 +1,394 @@
+//! A widget that divides its space between two resizable panes.
+
+use figures::units::{Lp, Px, UPx};
+use figures::{FloatConversion, IntoSigned, Point, Rect, Round, ScreenScale, Size, Zero};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::window::CursorIcon;
+use kludgine::shapes::Shape;
+use kludgine::Color;
+
+use crate::context::{EventContext, GraphicsContext, LayoutContext};
+use crate::reactive::value::{Destination, Dynamic, IntoDynamic, IntoValue, Source, Value};
+use crate::styles::components::OutlineWidth;
+use crate::styles::Dimension;
+use crate::widget::{EventHandling, MakeWidget, Widget, WidgetRef, HANDLED, IGNORED};
+use crate::widgets::grid::Orientation;
+use crate::window::DeviceId;
+use crate::ConstraintLimit;
+
+/// A widget that divides its space between two children that are separated by
+/// a divider that can be dragged to resize them.
+///
+/// The divider's location is stored in [`position`](Self::position) as the
+/// fraction of the available space that is given to the first child. Because
+/// this is a [`Dynamic`], it can be observed to persist the position and can
+/// be set to restore it.
+///
+/// Each child can be given a minimum size, which the divider cannot be dragged
+/// past. When there isn't enough space for both minimums, the first child's
+/// minimum is honored.
+///
+/// When sized to fit its contents, each child is given its share of the
+/// available space and shrinks to the size it measures within that share.
+///
+/// ```rust
+/// use cushy::reactive::value::Dynamic;
+/// use cushy::widget::MakeWidget;
+/// use cushy::widgets::Split;
+/// use figures::units::Lp;
+///
+/// let position = Dynamic::new(0.25);
+/// let split = Split::columns("Sidebar", "Editor")
+///     .position(position.clone())
+///     .minimums(Lp::inches(1), Lp::inches(2));
+/// ```
+#[derive(Debug)]
+pub struct Split {
+    orientation: Orientation,
+    /// The fraction of the available space given to the first child, from
+    /// `0.0` to `1.0`.
+    pub position: Dynamic<f32>,
+    /// The smallest size the first child can be resized to.
+    pub first_minimum: Value<Dimension>,
+    /// The smallest size the second child can be resized to.
+    pub second_minimum: Value<Dimension>,
+    first: WidgetRef,
+    second: WidgetRef,
+    layout: SplitLayout,
+    drag_offset: Option<Px>,
+}
+
+impl Split {
+    /// Returns a new split that places `first` and `second` using
+    /// `orientation`, with the divider in the center.
+    pub fn new(orientation: Orientation, first: impl MakeWidget, second: impl MakeWidget) -> Self {
+        Self {
+            orientation,
+            position: Dynamic::new(0.5),
+            first_minimum: Value::Constant(Dimension::ZERO),
+            second_minimum: Value::Constant(Dimension::ZERO),
+            first: WidgetRef::new(first),
+            second: WidgetRef::new(second),
+            layout: SplitLayout::default(),
+            drag_offset: None,
+        }
+    }
+
+    /// Returns a new split that places `left` and `right` next to each other,
+    /// separated by a vertical divider.
+    pub fn columns(left: impl MakeWidget, right: impl MakeWidget) -> Self {
+        Self::new(Orientation::Column, left, right)
+    }
+
+    /// Returns a new split that places `top` above `bottom`, separated by a
+    /// horizontal divider.
+    pub fn rows(top: impl MakeWidget, bottom: impl MakeWidget) -> Self {
+        Self::new(Orientation::Row, top, bottom)
+    }
+
+    /// Sets the fraction of the available space given to the first child and
+    /// returns self.
+    #[must_use]
+    pub fn position(mut self, position: impl IntoDynamic<f32>) -> Self {
+        self.position = position.into_dynamic();
+        self
+    }
+
+    /// Sets the smallest sizes the first and second children can be resized
+    /// to, and returns self.
+    #[must_use]
+    pub fn minimums(
+        mut self,
+        first: impl IntoValue<Dimension>,
+        second: impl IntoValue<Dimension>,
+    ) -> Self {
+        self.first_minimum = first.into_value();
+        self.second_minimum = second.into_value();
+        self
+    }
+
+    fn divider_contains(&self, location: Point<Px>) -> bool {
+        self.layout.divider.contains(location)
+    }
+
+    fn main_axis(&self, point: Point<Px>) -> Px {
+        match self.orientation {
+            Orientation::Row => point.y,
+            Orientation::Column => point.x,
+        }
+    }
+}
+
+impl Widget for Split {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        let first = self.first.mounted(context);
+        context.for_other(&first).redraw();
+        let second = self.second.mounted(context);
+        context.for_other(&second).redraw();
+
+        let color = context.get(&SplitDividerColor);
+        if color.alpha() > 0 {
+            let line_width = context
+                .get(&OutlineWidth)
+                .into_px(context.gfx.scale())
+                .ceil()
+                .max(Px::new(1));
+            let divider_start = self.main_axis(self.layout.divider.origin);
+            let (divider_size, other_size) = self.orientation.split_size(self.layout.divider.size);
+            let line = Rect::new(
+                self.orientation
+                    .make_point(divider_start + (divider_size - line_width) / 2, Px::ZERO),
+                self.orientation.make_size(line_width, other_size),
+            );
+            context.gfx.draw_shape(&Shape::filled_rect(line, color));
+        }
+    }
+
+    fn layout(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        let scale = context.gfx.scale();
+        let first = self.first.mounted(context);
+        let second = self.second.mounted(context);
+
+        let divider = context.get(&SplitDividerSize).into_upx(scale).ceil();
+        let (total, other) = self.orientation.split_size(available_space);
+        let space = total.max().saturating_sub(divider);
+        let (first_minimum, second_minimum) = clamp_minimums(
+            space,
+            self.first_minimum
+                .get_tracking_invalidate(context)
+                .into_upx(scale)
+                .ceil(),
+            self.second_minimum
+                .get_tracking_invalidate(context)
+                .into_upx(scale)
+                .ceil(),
+        );
+        let position = self.position.get_tracking_invalidate(context);
+        let mut first_size = split_space(space, position, first_minimum, second_minimum);
+        let mut second_size = space - first_size;
+
+        let limit = |size| match total {
+            ConstraintLimit::Fill(_) => ConstraintLimit::Fill(size),
+            ConstraintLimit::SizeToFit(_) => ConstraintLimit::SizeToFit(size),
+        };
+        let first_layout = context
+            .for_other(&first)
+            .layout(self.orientation.make_size(limit(first_size), other));
+        let second_layout = context
+            .for_other(&second)
+            .layout(self.orientation.make_size(limit(second_size), other));
+        if let ConstraintLimit::SizeToFit(_) = total {
+            // Each child shrinks to its measured size, but never below its
+            // minimum or past the space it was given.
+            let (first_measured, _) = self.orientation.split_size(first_layout);
+            let (second_measured, _) = self.orientation.split_size(second_layout);
+            first_size = first_measured.max(first_minimum).min(first_size);
+            second_size = second_measured.max(second_minimum).min(second_size);
+        }
+        let other_size = match other {
+            ConstraintLimit::Fill(size) => size,
+            ConstraintLimit::SizeToFit(_) => {
+                let (_, first_other) = self.orientation.split_size(first_layout);
+                let (_, second_other) = self.orientation.split_size(second_layout);
+                first_other.max(second_other)
+            }
+        };
+
+        context.set_child_layout(
+            &first,
+            Rect::new(
+                Point::ZERO,
+                self.orientation.make_size(first_size, other_size),
+            )
+            .into_signed(),
+        );
+        context.set_child_layout(
+            &second,
+            Rect::new(
+                self.orientation.make_point(first_size + divider, UPx::ZERO),
+                self.orientation.make_size(second_size, other_size),
+            )
+            .into_signed(),
+        );
+
+        self.layout = SplitLayout {
+            space: space.into_signed(),
+            first_minimum: first_minimum.into_signed(),
+            second_minimum: second_minimum.into_signed(),
+            divider: Rect::new(
+                self.orientation.make_point(first_size, UPx::ZERO),
+                self.orientation.make_size(divider, other_size),
+            )
+            .into_signed(),
+        };
+
+        let measured = match total {
+            ConstraintLimit::Fill(size) => size,
+            ConstraintLimit::SizeToFit(_) => first_size + divider + second_size,
+        };
+        self.orientation.make_size(measured, other_size)
+    }
+
+    fn hit_test(&mut self, location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        self.divider_contains(location)
+    }
+
+    fn hover(
+        &mut self,
+        location: Point<Px>,
+        _context: &mut EventContext<'_>,
+    ) -> Option<CursorIcon> {
+        (self.drag_offset.is_some() || self.divider_contains(location)).then_some(
+            match self.orientation {
+                Orientation::Column => CursorIcon::ColResize,
+                Orientation::Row => CursorIcon::RowResize,
+            },
+        )
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        _context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button != MouseButton::Left || !self.divider_contains(location) {
+            return IGNORED;
+        }
+
+        self.drag_offset =
+            Some(self.main_axis(location) - self.main_axis(self.layout.divider.origin));
+        HANDLED
+    }
+
+    fn mouse_drag(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        _context: &mut EventContext<'_>,
+    ) {
+        let Some(offset) = self.drag_offset else {
+            return;
+        };
+        if self.layout.space <= 0 {
+            return;
+        }
+
+        let first_size = (self.main_axis(location) - offset)
+            .max(self.layout.first_minimum)
+            .min(self.layout.space - self.layout.second_minimum);
+        self.position
+            .set(first_size.into_float() / self.layout.space.into_float());
+    }
+
+    fn mouse_up(
+        &mut self,
+        _location: Option<Point<Px>>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        _context: &mut EventContext<'_>,
+    ) {
+        self.drag_offset = None;
+    }
+
+    fn unmounted(&mut self, context: &mut EventContext<'_>) {
+        self.first.unmount_in(context);
+        self.second.unmount_in(context);
+    }
+
+    fn summarize(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("Split")
+            .field("orientation", &self.orientation)
+            .field("position", &self.position)
+            .field("first", &self.first)
+            .field("second", &self.second)
+            .finish()
+    }
+}
+
+/// Returns the minimums of the first and second children, limited to fit
+/// within `space`. The first child's minimum is honored before the second's.
+fn clamp_minimums(space: UPx, first: UPx, second: UPx) -> (UPx, UPx) {
+    let first = first.min(space);
+    (first, second.min(space - first))
+}
+
+/// Returns the size of the first child when `space` is divided at
+/// `position`, respecting the minimums returned from [`clamp_minimums`].
+fn split_space(space: UPx, position: f32, first_minimum: UPx, second_minimum: UPx) -> UPx {
+    (space * position.clamp(0., 1.))
+        .round()
+        .max(first_minimum)
+        .min(space - second_minimum)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct SplitLayout {
+    space: Px,
+    first_minimum: Px,
+    second_minimum: Px,
+    divider: Rect<Px>,
+}
+
+define_components! {
+    Split {
+        /// The thickness of the draggable area between the children of a
+        /// [`Split`].
+        SplitDividerSize(Dimension, "divider_size", Dimension::Lp(Lp::points(6)))
+        /// The color of the line drawn between the children of a [`Split`].
+        SplitDividerColor(Color, "divider_color", .surface.outline_variant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::UPx;
+
+    use super::{clamp_minimums, split_space};
+
+    #[test]
+    fn minimums_fit_space() {
+        assert_eq!(
+            clamp_minimums(UPx::new(100), UPx::new(20), UPx::new(30)),
+            (UPx::new(20), UPx::new(30))
+        );
+        // The first minimum is honored when both don't fit.
+        assert_eq!(
+            clamp_minimums(UPx::new(100), UPx::new(70), UPx::new(50)),
+            (UPx::new(70), UPx::new(30))
+        );
+        assert_eq!(
+            clamp_minimums(UPx::new(100), UPx::new(150), UPx::new(50)),
+            (UPx::new(100), UPx::ZERO)
+        );
+    }
+
+    #[test]
+    fn split_clamps_position() {
+        let space = UPx::new(100);
+        assert_eq!(split_space(space, 0.5, UPx::ZERO, UPx::ZERO), UPx::new(50));
+        assert_eq!(split_space(space, 0.25, UPx::ZERO, UPx::ZERO), UPx::new(25));
+        assert_eq!(split_space(space, -1., UPx::ZERO, UPx::ZERO), UPx::ZERO);
+        assert_eq!(split_space(space, 2., UPx::ZERO, UPx::ZERO), space);
+    }
+
+    #[test]
+    fn split_respects_minimums() {
+        let space = UPx::new(100);
+        let (first, second) = clamp_minimums(space, UPx::new(20), UPx::new(30));
+        assert_eq!(split_space(space, 0.1, first, second), UPx::new(20));
+        assert_eq!(split_space(space, 0.9, first, second), UPx::new(70));
+        assert_eq!(split_space(space, 0.5, first, second), UPx::new(50));
+
+        // When the minimums fill the space, the divider can't move.
+        let (first, second) = clamp_minimums(space, UPx::new(70), UPx::new(50));
+        assert_eq!(split_space(space, 0., first, second), UPx::new(70));
+        assert_eq!(split_space(space, 1., first, second), UPx::new(70));
+    }
+}
@@ -51,6 +51,14 @@ impl Stack {
     }
 
     /// Sets the space between each child to `gutter` and returns self.
+    ///
+    /// The gutter is only placed between adjacent children that are visible,
+    /// never before the first child or after the last child. Gutters always
+    /// occupy their full size: children that
+    /// [expand](crate::widget::MakeWidget::expand) divide the space that
+    /// remains after the gutters have been allocated.
+    ///
+    /// By default, the [`IntrinsicPadding`] component is used as the gutter.
     #[must_use]
     pub fn gutter(mut self, gutter: impl IntoValue<FlexibleDimension>) -> Self {
         self.gutter = gutter.into_value();
@@ -138,9 +146,7 @@ impl Stack {
 
 impl Widget for Stack {
     fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
-        for child in &self.synced_children {
-            context.for_other(child).redraw();
-        }
+        context.redraw_children(&self.synced_children);
     }
 
     fn mounted(&mut self, context: &mut EventContext<'_>) {
@@ -14,6 +14,12 @@ use crate::styles::{ComponentDefinition, IntoComponentValue, IntoDynamicComponen
 use crate::widget::{MakeWidget, WidgetRef, WrapperWidget};
 
 /// A widget that applies a set of [`Styles`] to all contained widgets.
+///
+/// Styles cascade: when a widget queries a component, the value from the
+/// nearest ancestor `Style` that specifies it is used. A nested `Style` can
+/// override any component set by an outer `Style` for its own contents, and
+/// components added using [`with_local()`](Self::with_local) only apply to
+/// the immediate child and are not inherited.
 #[derive(Debug)]
 pub struct Style {
     styles: Value<Styles>,
@@ -0,0 +1,172 @@
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use figures::units::UPx;
+use figures::{FloatConversion, IntoSigned, IntoUnsigned, Point, Rect, Size, Zero};
+use image::{DynamicImage, RgbaImage};
+use kludgine::wgpu::FilterMode;
+use kludgine::{AnyTexture, LazyTexture};
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg::{Options, Tree};
+
+use crate::animation::ZeroToOne;
+use crate::context::{GraphicsContext, LayoutContext};
+use crate::reactive::value::{IntoValue, Source, Value};
+use crate::widget::Widget;
+use crate::ConstraintLimit;
+
+/// A widget that renders Scalable Vector Graphics (SVG).
+///
+/// The SVG is rasterized into a texture the size of the area it is drawn
+/// within, which keeps it crisp at any size and DPI scaling factor. The
+/// rasterized texture is cached and only rendered again when the size being
+/// drawn changes.
+///
+/// The SVG is scaled to fit within the available space while preserving its
+/// aspect ratio. When sizing to fit, the SVG requests its natural size
+/// multiplied by the window's scaling factor.
+///
+/// This widget requires the `svg` feature.
+#[derive(Debug)]
+pub struct Svg {
+    tree: Arc<Tree>,
+    /// The opacity to render the SVG with.
+    pub opacity: Value<ZeroToOne>,
+    rendered: Option<(Size<UPx>, AnyTexture)>,
+}
+
+impl Svg {
+    /// Returns a widget that renders the SVG document contained in `data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is not a valid SVG document.
+    pub fn from_bytes(data: impl AsRef<[u8]>) -> Result<Self, resvg::usvg::Error> {
+        let tree = Tree::from_data(data.as_ref(), &Options::default())?;
+        Ok(Self::new(tree))
+    }
+
+    /// Returns a widget that renders the SVG document at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or if it does not contain
+    /// a valid SVG document.
+    pub fn from_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        let data = std::fs::read(path)?;
+        Self::from_bytes(data).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Returns a widget that renders `tree`.
+    #[must_use]
+    pub fn new(tree: impl Into<Arc<Tree>>) -> Self {
+        Self {
+            tree: tree.into(),
+            opacity: Value::Constant(ZeroToOne::ONE),
+            rendered: None,
+        }
+    }
+
+    /// Applies `opacity` when drawing the SVG, returns self.
+    #[must_use]
+    pub fn opacity(mut self, opacity: impl IntoValue<ZeroToOne>) -> Self {
+        self.opacity = opacity.into_value();
+        self
+    }
+
+    fn natural_size(&self) -> Size<f32> {
+        let size = self.tree.size();
+        Size::new(size.width(), size.height())
+    }
+
+    fn rasterize(&self, size: Size<UPx>) -> Option<AnyTexture> {
+        let mut pixmap = Pixmap::new(size.width.get(), size.height.get())?;
+        let natural = self.natural_size();
+        resvg::render(
+            &self.tree,
+            Transform::from_scale(
+                size.width.into_float() / natural.width,
+                size.height.into_float() / natural.height,
+            ),
+            &mut pixmap.as_mut(),
+        );
+
+        // Pixmaps store premultiplied colors, while textures expect the
+        // colors to not be premultiplied.
+        let mut image = RgbaImage::new(size.width.get(), size.height.get());
+        for (pixel, rendered) in image.pixels_mut().zip(pixmap.pixels()) {
+            let color = rendered.demultiply();
+            pixel.0 = [color.red(), color.green(), color.blue(), color.alpha()];
+        }
+
+        Some(AnyTexture::from(LazyTexture::from_image(
+            DynamicImage::ImageRgba8(image),
+            FilterMode::Linear,
+        )))
+    }
+}
+
+impl Widget for Svg {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        let opacity = self.opacity.get_tracking_redraw(context);
+        let area = context.gfx.size();
+        let size = fit_within(self.natural_size(), area.map(UPx::into_float));
+        if size.width == 0 || size.height == 0 {
+            return;
+        }
+
+        if self
+            .rendered
+            .as_ref()
+            .map_or(true, |(rendered, _)| *rendered != size)
+        {
+            self.rendered = self.rasterize(size).map(|texture| (size, texture));
+        }
+
+        if let Some((_, texture)) = &self.rendered {
+            let origin = (area.into_signed() - size.into_signed()) / 2;
+            context.gfx.draw_texture(
+                texture,
+                Rect::new(Point::new(origin.width, origin.height), size.into_signed()),
+                opacity,
+            );
+        }
+    }
+
+    fn layout(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        let scale = context.gfx.scale().into_f32();
+        let natural = self.natural_size() * scale;
+        let maximum = available_space.map(|limit| limit.max().into_float());
+        // Only grow beyond the natural size when filling the available space.
+        let fill = matches!(available_space.width, ConstraintLimit::Fill(_))
+            || matches!(available_space.height, ConstraintLimit::Fill(_));
+        let bounds = if fill {
+            maximum
+        } else {
+            Size::new(
+                natural.width.min(maximum.width),
+                natural.height.min(maximum.height),
+            )
+        };
+        fit_within(natural, bounds)
+    }
+}
+
+/// Returns the largest size with the aspect ratio of `natural` that fits
+/// within `available`.
+fn fit_within(natural: Size<f32>, available: Size<f32>) -> Size<UPx> {
+    if natural.width <= 0. || natural.height <= 0. {
+        return Size::ZERO;
+    }
+    let scale = (available.width / natural.width).min(available.height / natural.height);
+    let size = natural * scale;
+    Size::new(
+        UPx::from(size.width.floor()),
+        UPx::from(size.height.floor()),
+    )
+}
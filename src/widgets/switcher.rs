@@ -1,22 +1,34 @@
 use std::fmt::Debug;
 use std::mem;
+use std::time::Duration;
 
 use ahash::HashMap;
-use figures::Size;
+use figures::units::Px;
+use figures::{IntoSigned, IntoUnsigned, Point, Size, Zero};
 use kludgine::KludgineId;
 
-use crate::context::{AsEventContext, LayoutContext};
-use crate::reactive::value::{Dynamic, DynamicReader, IntoDynamic, IntoReader, Source};
-use crate::widget::{MountedWidget, WidgetInstance, WidgetRef, WrapperWidget};
+use crate::animation::{AnimationHandle, AnimationTarget, Spawn, ZeroToOne};
+use crate::context::{AsEventContext, GraphicsContext, LayoutContext};
+use crate::reactive::value::{
+    Dynamic, DynamicReader, IntoDynamic, IntoReader, IntoValue, Source, Value,
+};
+use crate::styles::components::Easing;
+use crate::widget::{MountedWidget, WidgetInstance, WidgetRef, WrappedLayout, WrapperWidget};
 use crate::window::WindowLocal;
 use crate::ConstraintLimit;
 
 /// A widget that switches its contents based on a value of `T`.
+///
+/// By default, new contents replace the previous contents immediately.
+/// [`Switcher::transition`] can be used to animate between the previous and
+/// new contents instead.
 #[derive(Debug)]
 pub struct Switcher {
     source: DynamicReader<WidgetInstance>,
     child: WidgetRef,
     pending_unmount: HashMap<KludgineId, MountedWidget>,
+    transition: Option<(SwitcherTransition, Value<Duration>)>,
+    outgoing: Option<Outgoing>,
 }
 
 impl Switcher {
@@ -48,8 +60,26 @@ impl Switcher {
             source,
             child,
             pending_unmount: HashMap::default(),
+            transition: None,
+            outgoing: None,
         }
     }
+
+    /// Animates between the previous and new contents using `kind` each
+    /// time the contents change, and returns self.
+    ///
+    /// Both the previous and new contents are displayed while the transition
+    /// is animating over `duration`. The transition uses the [`Easing`]
+    /// component for its easing function.
+    #[must_use]
+    pub fn transition(
+        mut self,
+        kind: SwitcherTransition,
+        duration: impl IntoValue<Duration>,
+    ) -> Self {
+        self.transition = Some((kind, duration.into_value()));
+        self
+    }
 }
 
 impl WrapperWidget for Switcher {
@@ -57,6 +87,20 @@ impl WrapperWidget for Switcher {
         &mut self.child
     }
 
+    fn redraw_foreground(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        let Some(outgoing) = &self.outgoing else {
+            return;
+        };
+        let progress = outgoing.progress.get_tracking_redraw(context);
+        let mut context = context.for_other(&outgoing.widget);
+        if outgoing.kind == SwitcherTransition::CrossFade {
+            // The new contents have already been drawn beneath the previous
+            // contents. Fading the previous contents out reveals them.
+            context.apply_opacity(progress.one_minus());
+        }
+        context.redraw();
+    }
+
     // TODO this should be moved to an invalidated() event once we have it.
     fn adjust_child_constraints(
         &mut self,
@@ -67,15 +111,48 @@ impl WrapperWidget for Switcher {
             context.remove_child(&pending_unmount);
         }
 
+        if self
+            .outgoing
+            .as_ref()
+            .is_some_and(|outgoing| *outgoing.progress.get() >= 1.)
+        {
+            if let Some(finished) = self.outgoing.take() {
+                context.remove_child(&finished.widget);
+            }
+        }
+
         let current_source = self.source.get_tracking_invalidate(context);
         if &current_source != self.child.widget() {
-            // immediately unmount in the current context.
-            self.child.unmount_in(context);
-            let old_mounts = <WindowLocal<MountedWidget>>::from(mem::replace(
+            let mut old_mounts = <WindowLocal<MountedWidget>>::from(mem::replace(
                 &mut self.child,
                 WidgetRef::new(current_source),
             ));
 
+            // Either unmount immediately in the current context or keep the
+            // previous contents around until the transition completes.
+            if let Some(mounted) = old_mounts.clear_for(&context.as_event_context()) {
+                match &self.transition {
+                    Some((kind, duration)) if duration.get() > Duration::ZERO => {
+                        if let Some(interrupted) = self.outgoing.take() {
+                            context.remove_child(&interrupted.widget);
+                        }
+                        let progress = Dynamic::new(ZeroToOne::ZERO);
+                        let animation = progress
+                            .transition_to(ZeroToOne::ONE)
+                            .over(duration.get())
+                            .with_easing(context.get(&Easing))
+                            .spawn();
+                        self.outgoing = Some(Outgoing {
+                            widget: mounted,
+                            kind: *kind,
+                            progress,
+                            _animation: animation,
+                        });
+                    }
+                    _ => context.remove_child(&mounted),
+                }
+            }
+
             // For all other contexts, we have to wait until this callback to
             // try unmounting.
             for (id, mounted) in old_mounts {
@@ -87,8 +164,62 @@ impl WrapperWidget for Switcher {
             }
         }
 
+        if let Some(outgoing) = &self.outgoing {
+            context.invalidate_when_changed(&outgoing.progress);
+        }
         context.invalidate_when_changed(&self.source);
 
         available_space
     }
+
+    fn position_child(
+        &mut self,
+        size: Size<Px>,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> WrappedLayout {
+        let mut layout = WrappedLayout::aligned(size.into_unsigned(), available_space, context);
+        let Some(outgoing) = &self.outgoing else {
+            return layout;
+        };
+
+        let outgoing_size = context.for_other(&outgoing.widget).layout(available_space);
+        let mut outgoing_layout = WrappedLayout::aligned(outgoing_size, available_space, context);
+        layout.size = layout.size.max(outgoing_layout.size);
+
+        let width = layout.size.width.into_signed();
+        let progress = *outgoing.progress.get();
+        let (outgoing_offset, offset) = match outgoing.kind {
+            SwitcherTransition::CrossFade => (Px::ZERO, Px::ZERO),
+            SwitcherTransition::SlideLeft => (-(width * progress), width - width * progress),
+            SwitcherTransition::SlideRight => (width * progress, width * progress - width),
+        };
+        outgoing_layout.child.origin += Point::new(outgoing_offset, Px::ZERO);
+        layout.child.origin += Point::new(offset, Px::ZERO);
+        context.set_child_layout(&outgoing.widget, outgoing_layout.child);
+
+        layout
+    }
+}
+
+/// An animated transition between the previous and new contents of a
+/// [`Switcher`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SwitcherTransition {
+    /// The previous contents fade out, revealing the new contents.
+    CrossFade,
+    /// The new contents slide in from the right while the previous contents
+    /// slide out to the left.
+    SlideLeft,
+    /// The new contents slide in from the left while the previous contents
+    /// slide out to the right.
+    SlideRight,
+}
+
+#[derive(Debug)]
+struct Outgoing {
+    widget: MountedWidget,
+    kind: SwitcherTransition,
+    progress: Dynamic<ZeroToOne>,
+    _animation: AnimationHandle,
 }
@@ -0,0 +1,217 @@
+//! A widget that shows one of several widgets, chosen by a row of tabs.
+
+use kludgine::app::winit::keyboard::{ModifiersState, NamedKey};
+
+use crate::reactive::value::{Destination, Dynamic, IntoDynamic, IntoValue, MapEach, Source};
+use crate::widget::{
+    MakeWidget, MakeWidgetWithTag, SharedCallback, WidgetInstance, WidgetList, WidgetTag, HANDLED,
+};
+use crate::widgets::button::ButtonKind;
+use crate::widgets::{Scroll, Select, Space, Stack, Switcher};
+
+/// A widget that shows the contents of one of its tabs, chosen by clicking on
+/// the tab's header.
+///
+/// The index of the tab being shown is stored in
+/// [`selected`](Self::selected). The header of the selected tab is drawn using
+/// the same style as a selected [`Select`].
+///
+/// While focus is within this widget, Ctrl+Tab selects the next tab and
+/// Ctrl+Shift+Tab selects the previous tab.
+///
+/// ```rust
+/// use cushy::widget::MakeWidget;
+/// use cushy::widgets::tabs::Tab;
+/// use cushy::widgets::Tabs;
+///
+/// let tabs = Tabs::new(vec![
+///     Tab::new("General", "General settings"),
+///     Tab::new("Advanced", "Advanced settings").closeable(),
+/// ])
+/// .on_close(|index| println!("closing tab {index}"));
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct Tabs {
+    /// The tabs that can be selected.
+    pub tabs: Dynamic<Vec<Tab>>,
+    /// The index of the selected tab.
+    pub selected: Dynamic<usize>,
+    on_close: Option<SharedCallback<usize>>,
+    scrollable: bool,
+}
+
+impl Tabs {
+    /// Returns a new widget that shows one of `tabs` at a time, starting with
+    /// the first tab.
+    pub fn new(tabs: impl IntoValue<Vec<Tab>>) -> Self {
+        Self {
+            tabs: tabs.into_value().into_dynamic(),
+            selected: Dynamic::new(0),
+            on_close: None,
+            scrollable: false,
+        }
+    }
+
+    /// Adds a tab with `label` in its header that shows `contents` when
+    /// selected, and returns self.
+    pub fn with_tab(self, label: impl MakeWidget, contents: impl MakeWidget) -> Self {
+        self.tabs.lock().push(Tab::new(label, contents));
+        self
+    }
+
+    /// Sets the index of the selected tab and returns self.
+    pub fn selected(mut self, selected: impl IntoDynamic<usize>) -> Self {
+        self.selected = selected.into_dynamic();
+        self
+    }
+
+    /// Invokes `on_close` with the index of a [closeable](Tab::closeable) tab
+    /// when its close button is clicked, and returns self.
+    ///
+    /// This widget does not remove the tab itself. The callback is expected to
+    /// remove the tab from [`tabs`](Self::tabs) if the tab should be closed.
+    pub fn on_close<F>(mut self, on_close: F) -> Self
+    where
+        F: FnMut(usize) + Send + 'static,
+    {
+        self.on_close = Some(SharedCallback::new(on_close));
+        self
+    }
+
+    /// Allows the tab headers to be scrolled horizontally when they do not fit
+    /// in the available space, and returns self.
+    pub fn scrollable(mut self) -> Self {
+        self.scrollable = true;
+        self
+    }
+}
+
+impl MakeWidgetWithTag for Tabs {
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        // Keep the selection valid when tabs are removed.
+        self.tabs
+            .for_each({
+                let selected = self.selected.clone();
+                move |tabs: &Vec<Tab>| {
+                    let last = tabs.len().saturating_sub(1);
+                    if selected.get() > last {
+                        selected.set(last);
+                    }
+                }
+            })
+            .persist();
+
+        let headers = self.tabs.map_each({
+            let selected = self.selected.clone();
+            move |tabs: &Vec<Tab>| {
+                tabs.iter()
+                    .enumerate()
+                    .map(|(index, tab)| tab_header(index, tab, &selected, self.on_close.as_ref()))
+                    .collect::<WidgetList>()
+            }
+        });
+        let headers = Stack::columns(headers);
+        let headers = if self.scrollable {
+            Scroll::horizontal(headers).make_widget()
+        } else {
+            headers.make_widget()
+        };
+
+        let body = Switcher::new((&self.tabs, &self.selected).map_each(|(tabs, selected)| {
+            tabs.get(*selected)
+                .map_or_else(|| Space::clear().make_widget(), |tab| tab.contents.clone())
+        }));
+
+        headers
+            .and(body.expand())
+            .into_rows()
+            .with_shortcut(NamedKey::Tab, ModifiersState::CONTROL, {
+                let tabs = self.tabs.clone();
+                let selected = self.selected.clone();
+                move |_| {
+                    cycle_selection(&tabs, &selected, true);
+                    HANDLED
+                }
+            })
+            .with_shortcut(
+                NamedKey::Tab,
+                ModifiersState::CONTROL | ModifiersState::SHIFT,
+                move |_| {
+                    cycle_selection(&self.tabs, &self.selected, false);
+                    HANDLED
+                },
+            )
+            .make_with_tag(tag)
+    }
+}
+
+fn tab_header(
+    index: usize,
+    tab: &Tab,
+    selected: &Dynamic<usize>,
+    on_close: Option<&SharedCallback<usize>>,
+) -> WidgetInstance {
+    let label = match on_close {
+        Some(on_close) if tab.closeable => {
+            let on_close = on_close.clone();
+            tab.label
+                .clone()
+                .and(
+                    "\u{d7}"
+                        .into_button()
+                        .kind(ButtonKind::Transparent)
+                        .on_click(move |_| on_close.invoke(index)),
+                )
+                .into_columns()
+                .make_widget()
+        }
+        _ => tab.label.clone(),
+    };
+    Select::new(index, selected.clone(), label).make_widget()
+}
+
+fn cycle_selection(tabs: &Dynamic<Vec<Tab>>, selected: &Dynamic<usize>, forward: bool) {
+    let count = tabs.map_ref(Vec::len);
+    if count == 0 {
+        return;
+    }
+    selected.map_mut(|mut selected| {
+        *selected = if forward {
+            (*selected + 1) % count
+        } else {
+            (*selected + count - 1) % count
+        };
+    });
+}
+
+/// A tab shown in a [`Tabs`] widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    /// The widget shown in this tab's header.
+    pub label: WidgetInstance,
+    /// The widget shown when this tab is selected.
+    pub contents: WidgetInstance,
+    /// When true, a button that closes this tab is shown in its header.
+    pub closeable: bool,
+}
+
+impl Tab {
+    /// Returns a new tab with `label` in its header that shows `contents` when
+    /// selected.
+    pub fn new(label: impl MakeWidget, contents: impl MakeWidget) -> Self {
+        Self {
+            label: label.make_widget(),
+            contents: contents.make_widget(),
+            closeable: false,
+        }
+    }
+
+    /// Shows a button in this tab's header that reports the tab being closed
+    /// using [`Tabs::on_close`], and returns self.
+    #[must_use]
+    pub fn closeable(mut self) -> Self {
+        self.closeable = true;
+        self
+    }
+}
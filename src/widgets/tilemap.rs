@@ -1,28 +1,66 @@
 use std::fmt::Debug;
 
 use figures::units::{Px, UPx};
-use figures::{Point, Size};
+use figures::{FloatConversion, Fraction, IntoSigned, Point, Rect, Size, Zero};
 use intentional::Cast;
-use kludgine::app::winit::event::{ElementState, MouseScrollDelta, TouchPhase};
+use kludgine::app::winit::event::{ElementState, MouseButton, MouseScrollDelta, TouchPhase};
 use kludgine::app::winit::window::CursorIcon;
 use kludgine::tilemap;
-use kludgine::tilemap::TileMapFocus;
+use kludgine::tilemap::{TileMapFocus, TILE_SIZE};
 
 use crate::context::{EventContext, GraphicsContext, LayoutContext, Trackable};
-use crate::reactive::value::{Dynamic, IntoValue, Value};
+use crate::reactive::value::{Destination, Dynamic, IntoDynamic, IntoValue, Value};
 use crate::tick::Tick;
-use crate::widget::{EventHandling, Widget, HANDLED, IGNORED};
+use crate::widget::{Callback, EventHandling, Widget, HANDLED, IGNORED};
 use crate::window::{DeviceId, KeyEvent};
 use crate::ConstraintLimit;
 
 /// A layered tile-based 2d game surface.
+///
+/// Layers are drawn in order, with each layer drawn on top of the layers
+/// before it. Any type that implements [`tilemap::Layers`] can be used,
+/// including tuples of layers.
+///
+/// This widget does not control the visibility or opacity of individual
+/// layers, because [`tilemap::Layer`] does not provide a way to skip or fade
+/// a layer while it is rendered. Instead, a layer type can check its own
+/// visibility and opacity while rendering, and the layers should be provided
+/// as a [`Dynamic`] so that the map is redrawn when these settings change.
+///
+/// # Mapping the cursor to tiles
+///
+/// Locations within this widget can be converted to world coordinates, which
+/// take into account the camera's focus and zoom. [`TileCoord::from_world`]
+/// converts a world coordinate to the coordinate of the tile containing it.
+///
+/// The tile beneath the cursor can be tracked using
+/// [`TileMap::hovered_tile`], and [`TileMap::on_tile_pressed`] is invoked with
+/// the tile beneath the cursor when a mouse button is pressed.
+///
+/// # Camera
+///
+/// By default, the camera is centered on the [focus](Self::focus_on) and the
+/// mouse wheel zooms in and out. A [`TileMapCamera`] can be used instead to
+/// control the camera's position and zoom using [`Dynamic`]s:
+///
+/// - The mouse wheel zooms in and out, keeping the world coordinate beneath
+///   the cursor in place.
+/// - Dragging with the middle mouse button pans the camera.
+/// - When [bounds](Self::bounds) are set, the camera is kept from showing
+///   anything outside of them.
 #[derive(Debug)]
 #[must_use]
 pub struct TileMap<Layers> {
     layers: Value<Layers>,
     focus: Value<TileMapFocus>,
-    zoom: f32,
+    camera: Option<Dynamic<Point<Px>>>,
+    zoom: Dynamic<f32>,
+    bounds: Value<Option<Rect<Px>>>,
     tick: Option<Tick>,
+    hovered_tile: Option<Dynamic<Option<TileCoord>>>,
+    on_tile_pressed: Option<Callback<TilePress>>,
+    cursor: Option<Point<Px>>,
+    pan_from: Option<Point<Px>>,
 }
 
 impl<Layers> TileMap<Layers> {
@@ -30,8 +68,14 @@ impl<Layers> TileMap<Layers> {
         Self {
             layers,
             focus: Value::default(),
-            zoom: 1.,
+            camera: None,
+            zoom: Dynamic::new(1.),
+            bounds: Value::Constant(None),
             tick: None,
+            hovered_tile: None,
+            on_tile_pressed: None,
+            cursor: None,
+            pan_from: None,
         }
     }
 
@@ -54,11 +98,147 @@ impl<Layers> TileMap<Layers> {
         self
     }
 
+    /// Controls this tilemap's view using `camera`, and returns self.
+    ///
+    /// The camera's position replaces the [focus](Self::focus_on) of this
+    /// tilemap.
+    pub fn camera(mut self, camera: TileMapCamera) -> Self {
+        self.camera = Some(camera.position);
+        self.zoom = camera.zoom;
+        self
+    }
+
+    /// Sets the zoom level of this tilemap and returns self.
+    ///
+    /// A zoom of `1.0` draws each tile at [`TILE_SIZE`].
+    pub fn zoom(mut self, zoom: impl IntoDynamic<f32>) -> Self {
+        self.zoom = zoom.into_dynamic();
+        self
+    }
+
+    /// Restricts the [camera](Self::camera) to only show world coordinates
+    /// within `bounds`, and returns self.
+    ///
+    /// When `bounds` is smaller than the visible area, the camera is centered
+    /// on `bounds`.
+    pub fn bounds(mut self, bounds: impl IntoValue<Option<Rect<Px>>>) -> Self {
+        self.bounds = bounds.into_value();
+        self
+    }
+
     /// Associates a [`Tick`] with this widget and returns self.
     pub fn tick(mut self, tick: Tick) -> Self {
         self.tick = Some(tick);
         self
     }
+
+    /// Updates `hovered` with the coordinate of the tile beneath the cursor,
+    /// and returns self.
+    ///
+    /// When the cursor is not above this widget, `hovered` is set to `None`.
+    pub fn hovered_tile(mut self, hovered: impl IntoDynamic<Option<TileCoord>>) -> Self {
+        self.hovered_tile = Some(hovered.into_dynamic());
+        self
+    }
+
+    /// Invokes `on_pressed` when a mouse button is pressed above this widget,
+    /// and returns self.
+    pub fn on_tile_pressed<F>(mut self, on_pressed: F) -> Self
+    where
+        F: FnMut(TilePress) + Send + 'static,
+    {
+        self.on_tile_pressed = Some(Callback::new(on_pressed));
+        self
+    }
+}
+
+impl<Layers> TileMap<Layers>
+where
+    Layers: tilemap::Layers,
+{
+    /// Returns the world coordinate that is displayed at `local`, a location
+    /// relative to this widget's origin.
+    ///
+    /// Returns `None` if this widget has not been laid out.
+    fn world_coordinate(&self, local: Point<Px>, context: &EventContext<'_>) -> Option<Point<Px>> {
+        let size = context.last_layout()?.size;
+        let scale = context.kludgine.scale();
+        Some(self.world_coordinate_in(local, size, scale, self.zoom.get()))
+    }
+
+    fn world_coordinate_in(
+        &self,
+        local: Point<Px>,
+        size: Size<Px>,
+        scale: Fraction,
+        zoom: f32,
+    ) -> Point<Px> {
+        let world = tilemap::translate_coordinates(local, scale, zoom, size);
+        let focus = self.focus_in(size, scale, zoom);
+        let offset = self.layers.map(|layers| focus.world_coordinate(layers));
+        world + offset
+    }
+
+    fn focus_in(&self, size: Size<Px>, scale: Fraction, zoom: f32) -> TileMapFocus {
+        self.camera_position(size, scale, zoom)
+            .map_or_else(|| self.focus.get(), TileMapFocus::Point)
+    }
+
+    /// Returns the camera's position after clamping it to the bounds, or
+    /// `None` if no camera is being used.
+    fn camera_position(&self, size: Size<Px>, scale: Fraction, zoom: f32) -> Option<Point<Px>> {
+        let position = self.camera.as_ref()?.get();
+        Some(self.clamp_camera(position, size, scale, zoom))
+    }
+
+    /// Returns `position` clamped so that the camera stays within the bounds.
+    fn clamp_camera(
+        &self,
+        position: Point<Px>,
+        size: Size<Px>,
+        scale: Fraction,
+        zoom: f32,
+    ) -> Point<Px> {
+        match self.bounds.get() {
+            Some(bounds) => {
+                let zoom = scale.into_f32() * zoom;
+                let visible = Size::new(
+                    Px::from(size.width.into_float() / zoom),
+                    Px::from(size.height.into_float() / zoom),
+                );
+                Point::new(
+                    clamp_camera_axis(
+                        position.x,
+                        bounds.origin.x,
+                        bounds.size.width,
+                        visible.width,
+                    ),
+                    clamp_camera_axis(
+                        position.y,
+                        bounds.origin.y,
+                        bounds.size.height,
+                        visible.height,
+                    ),
+                )
+            }
+            None => position,
+        }
+    }
+
+    /// Moves the camera to `position`, clamped to the bounds.
+    fn move_camera(&self, position: Point<Px>, size: Size<Px>, scale: Fraction) {
+        if let Some(camera) = &self.camera {
+            camera.set(self.clamp_camera(position, size, scale, self.zoom.get()));
+        }
+    }
+}
+
+fn clamp_camera_axis(position: Px, start: Px, length: Px, visible: Px) -> Px {
+    if visible >= length {
+        start + length / 2
+    } else {
+        position.clamp(start + visible / 2, start + length - visible / 2)
+    }
 }
 
 impl<Layers> Widget for TileMap<Layers>
@@ -66,13 +246,14 @@ where
     Layers: tilemap::Layers,
 {
     fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
-        let focus = self.focus.get();
+        let zoom = self.zoom.get();
+        let focus = self.focus_in(context.gfx.size().into_signed(), context.gfx.scale(), zoom);
         // TODO this needs to be updated to support being placed in side of a scroll view.
         let redraw_after = match &mut self.layers {
             Value::Constant(layers) => tilemap::draw(
                 layers,
                 focus,
-                self.zoom,
+                zoom,
                 context.elapsed(),
                 context.gfx.inner_graphics(),
             ),
@@ -82,7 +263,7 @@ where
                 tilemap::draw(
                     &mut *layers,
                     focus,
-                    self.zoom,
+                    zoom,
                     context.elapsed(),
                     context.gfx.inner_graphics(),
                 )
@@ -101,6 +282,11 @@ where
             }
             self.focus.redraw_when_changed(context);
             self.layers.redraw_when_changed(context);
+            self.zoom.redraw_when_changed(context);
+            self.bounds.redraw_when_changed(context);
+            if let Some(camera) = &self.camera {
+                camera.redraw_when_changed(context);
+            }
         }
     }
 
@@ -136,32 +322,50 @@ where
             MouseScrollDelta::PixelDelta(px) => px.y.cast::<f32>() / 16.0,
         };
 
-        self.zoom += self.zoom * 0.1 * amount;
+        let zoom = self.zoom.get();
+        let new_zoom = zoom + zoom * 0.1 * amount;
+
+        // Keep the world coordinate beneath the cursor in place by moving the
+        // camera by the distance that coordinate would have moved.
+        if let (Some(cursor), Some(camera), Some(layout)) =
+            (self.cursor, &self.camera, context.last_layout())
+        {
+            let scale = context.kludgine.scale();
+            let before = self.world_coordinate_in(cursor, layout.size, scale, zoom);
+            let after = self.world_coordinate_in(cursor, layout.size, scale, new_zoom);
+            let position = camera.get() + before - after;
+            self.zoom.set(new_zoom);
+            self.move_camera(position, layout.size, scale);
+        } else {
+            self.zoom.set(new_zoom);
+        }
 
         context.set_needs_redraw();
         HANDLED
     }
 
     fn hover(&mut self, local: Point<Px>, context: &mut EventContext<'_>) -> Option<CursorIcon> {
-        if let Some(tick) = &self.tick {
-            let size = context.last_layout().map(|rect| rect.size)?;
+        self.cursor = Some(local);
+        let world = self.world_coordinate(local, context)?;
 
-            let world =
-                tilemap::translate_coordinates(local, context.kludgine.scale(), self.zoom, size);
-            let offset = self
-                .layers
-                .map(|layers| self.focus.get().world_coordinate(layers));
-
-            tick.set_cursor_position(Some(world + offset));
+        if let Some(tick) = &self.tick {
+            tick.set_cursor_position(Some(world));
+        }
+        if let Some(hovered) = &self.hovered_tile {
+            hovered.set(Some(TileCoord::from_world(world)));
         }
 
         None
     }
 
     fn unhover(&mut self, _context: &mut EventContext<'_>) {
+        self.cursor = None;
         if let Some(tick) = &self.tick {
             tick.set_cursor_position(None);
         }
+        if let Some(hovered) = &self.hovered_tile {
+            hovered.set(None);
+        }
     }
 
     fn keyboard_input(
@@ -180,13 +384,34 @@ where
 
     fn mouse_down(
         &mut self,
-        _location: Point<Px>,
+        location: Point<Px>,
         _device_id: DeviceId,
-        button: kludgine::app::winit::event::MouseButton,
+        button: MouseButton,
         context: &mut EventContext<'_>,
     ) -> EventHandling {
+        if button == MouseButton::Middle && self.camera.is_some() {
+            self.pan_from = Some(location);
+            return HANDLED;
+        }
+
+        let mut handled = false;
         if let Some(tick) = &self.tick {
             tick.mouse_button(button, ElementState::Pressed);
+            handled = true;
+        }
+        if let (Some(world), Some(on_pressed)) = (
+            self.world_coordinate(location, context),
+            &mut self.on_tile_pressed,
+        ) {
+            on_pressed.invoke(TilePress {
+                tile: TileCoord::from_world(world),
+                world,
+                button,
+            });
+            handled = true;
+        }
+
+        if handled {
             context.focus();
             HANDLED
         } else {
@@ -194,15 +419,133 @@ where
         }
     }
 
+    fn mouse_drag(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) {
+        let (Some(pan_from), Some(camera), Some(layout)) =
+            (self.pan_from, &self.camera, context.last_layout())
+        else {
+            return;
+        };
+
+        let zoom = context.kludgine.scale().into_f32() * self.zoom.get();
+        let delta = pan_from - location;
+        let position = camera.get()
+            + Point::new(
+                Px::from(delta.x.into_float() / zoom),
+                Px::from(delta.y.into_float() / zoom),
+            );
+        self.pan_from = Some(location);
+        self.move_camera(position, layout.size, context.kludgine.scale());
+    }
+
     fn mouse_up(
         &mut self,
         _location: Option<Point<Px>>,
         _device_id: DeviceId,
-        button: kludgine::app::winit::event::MouseButton,
+        button: MouseButton,
         _context: &mut EventContext<'_>,
     ) {
+        if button == MouseButton::Middle && self.pan_from.take().is_some() {
+            return;
+        }
+
         if let Some(tick) = &self.tick {
             tick.mouse_button(button, ElementState::Released);
         }
     }
 }
+
+/// The coordinate of a tile in a [`TileMap`].
+///
+/// Tile `(0, 0)` covers the world coordinates from `(0, 0)` up to
+/// [`TILE_SIZE`] in each direction.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TileCoord {
+    /// The column of the tile.
+    pub x: i32,
+    /// The row of the tile.
+    pub y: i32,
+}
+
+impl TileCoord {
+    /// Returns a new tile coordinate.
+    #[must_use]
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the coordinate of the tile that contains `world`.
+    #[must_use]
+    pub fn from_world(world: Point<Px>) -> Self {
+        Self {
+            x: world.x.get().div_euclid(TILE_SIZE.get()),
+            y: world.y.get().div_euclid(TILE_SIZE.get()),
+        }
+    }
+
+    /// Returns the world coordinate of this tile's top-left corner.
+    #[must_use]
+    pub fn origin(self) -> Point<Px> {
+        Point::new(TILE_SIZE * self.x, TILE_SIZE * self.y)
+    }
+
+    /// Returns the world coordinate of this tile's center.
+    #[must_use]
+    pub fn center(self) -> Point<Px> {
+        self.origin() + Point::new(TILE_SIZE / 2, TILE_SIZE / 2)
+    }
+}
+
+/// A mouse button press above a [`TileMap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TilePress {
+    /// The tile beneath the cursor.
+    pub tile: TileCoord,
+    /// The world coordinate beneath the cursor.
+    pub world: Point<Px>,
+    /// The button that was pressed.
+    pub button: MouseButton,
+}
+
+/// The position and zoom of a [`TileMap`]'s camera.
+#[derive(Debug, Clone)]
+pub struct TileMapCamera {
+    /// The world coordinate shown at the center of the tilemap.
+    pub position: Dynamic<Point<Px>>,
+    /// The zoom level of the tilemap.
+    pub zoom: Dynamic<f32>,
+}
+
+impl TileMapCamera {
+    /// Returns a new camera that shows `position` at the center of the
+    /// tilemap using `zoom`.
+    #[must_use]
+    pub fn new(position: impl IntoDynamic<Point<Px>>, zoom: impl IntoDynamic<f32>) -> Self {
+        Self {
+            position: position.into_dynamic(),
+            zoom: zoom.into_dynamic(),
+        }
+    }
+
+    /// Moves the camera so that `world` is shown at the center of the tilemap.
+    pub fn center_on(&self, world: Point<Px>) {
+        self.position.set(world);
+    }
+
+    /// Moves the camera so that the center of `tile` is shown at the center
+    /// of the tilemap.
+    pub fn center_on_tile(&self, tile: TileCoord) {
+        self.center_on(tile.center());
+    }
+}
+
+impl Default for TileMapCamera {
+    fn default() -> Self {
+        Self::new(Dynamic::new(Point::ZERO), Dynamic::new(1.))
+    }
+}
@@ -0,0 +1,95 @@
+use figures::units::Px;
+use figures::{Angle, Point, Zero};
+
+use crate::context::GraphicsContext;
+use crate::graphics::DrawTransform;
+use crate::reactive::value::{IntoValue, Value};
+use crate::widget::{MakeWidget, WidgetRef, WrapperWidget};
+
+/// A widget that rotates, scales, and translates its child when drawing.
+///
+/// The transformation is performed around the center of this widget and does
+/// not affect layout: the child is measured and positioned as if it were not
+/// transformed. Mouse and touch locations given to the child and its
+/// descendants are transformed back into the child's coordinates, so hit
+/// testing continues to work as expected.
+///
+/// Drawing remains clipped to the bounds of this widget. Wrapping the child
+/// with padding allows a rotated or enlarged child to be fully visible.
+///
+/// Custom [`RenderOperation`](crate::graphics::RenderOperation)s and textures
+/// drawn using [`Graphics::draw_texture`](crate::graphics::Graphics::draw_texture)
+/// are not rotated.
+#[derive(Debug)]
+pub struct Transform {
+    child: WidgetRef,
+    rotation: Value<Angle>,
+    scale: Value<f32>,
+    translation: Value<Point<Px>>,
+}
+
+impl Transform {
+    /// Returns a widget that draws `child` without any transformation.
+    #[must_use]
+    pub fn new(child: impl MakeWidget) -> Self {
+        Self {
+            child: WidgetRef::new(child),
+            rotation: Value::Constant(Angle::degrees(0)),
+            scale: Value::Constant(1.),
+            translation: Value::Constant(Point::ZERO),
+        }
+    }
+
+    /// Rotates the child clockwise by `rotation` and returns self.
+    #[must_use]
+    pub fn rotation(mut self, rotation: impl IntoValue<Angle>) -> Self {
+        self.rotation = rotation.into_value();
+        self
+    }
+
+    /// Scales the child by `scale` and returns self.
+    ///
+    /// A scale of `1.0` draws the child at its normal size. Children scaled to
+    /// `0.0` or less are not drawn and can not be interacted with.
+    #[must_use]
+    pub fn scale(mut self, scale: impl IntoValue<f32>) -> Self {
+        self.scale = scale.into_value();
+        self
+    }
+
+    /// Offsets the child by `translation` and returns self.
+    #[must_use]
+    pub fn translation(mut self, translation: impl IntoValue<Point<Px>>) -> Self {
+        self.translation = translation.into_value();
+        self
+    }
+}
+
+impl WrapperWidget for Transform {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn redraw_background(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        let rotation = self.rotation.get_tracking_redraw(context);
+        let scale = self.scale.get_tracking_redraw(context).max(0.);
+        let translation = self.translation.get_tracking_redraw(context);
+
+        let region = context.gfx.region();
+        let transform = DrawTransform::around(
+            region.origin + region.size / 2,
+            translation,
+            rotation,
+            scale,
+        );
+        // The child's graphics context inherits the transformation of this
+        // context, and transformations nest when this widget is itself being
+        // transformed.
+        context.gfx.transform = Some(
+            context
+                .gfx
+                .transform
+                .map_or(transform, |outer| transform.then(outer)),
+        );
+    }
+}
@@ -22,6 +22,10 @@ use crate::widgets::label::Displayable;
 /// Additionally, a message may be shown below the content widget. If there is a
 /// validation error, it is shown. Otherwise, an optional hint message is
 /// supported.
+///
+/// While the validation is [pending](Validation::Pending), the hint is shown
+/// using [`PendingTextColor`]. A theme or parent widget can override this
+/// component, e.g. `validated.with(&PendingTextColor, Color::GRAY)`.
 #[derive(Debug)]
 pub struct Validated {
     hint: Value<MaybeLocalized>,
@@ -54,16 +58,23 @@ impl MakeWidgetWithTag for Validated {
         let message: Dynamic<MaybeLocalized> = self.validation.map_each_cloned(move |validation| validation.message(&self.hint).get());
 
         let error_color = Dynamic::new(Color::CLEAR_BLACK);
+        let pending_color = Dynamic::new(Color::CLEAR_BLACK);
         let default_color = Dynamic::new(Color::CLEAR_BLACK);
-        let color = (&self.validation, &error_color, &default_color).map_each(
-            |(validation, error, default)| {
+        let color = (
+            &self.validation,
+            &error_color,
+            &pending_color,
+            &default_color,
+        )
+            .map_each(|(validation, error, pending, default)| {
                 if validation.is_error() {
                     *error
+                } else if validation.is_pending() {
+                    *pending
                 } else {
                     *default
                 }
-            },
-        );
+            });
 
         ValidatedWidget {
             contents: WidgetRef::new(
@@ -91,6 +102,7 @@ impl MakeWidgetWithTag for Validated {
                     .into_rows(),
             ),
             error_color,
+            pending_color,
             default_color,
         }
         .make_with_tag(id)
@@ -101,6 +113,7 @@ impl MakeWidgetWithTag for Validated {
 struct ValidatedWidget {
     contents: WidgetRef,
     error_color: Dynamic<Color>,
+    pending_color: Dynamic<Color>,
     default_color: Dynamic<Color>,
 }
 
@@ -111,6 +124,7 @@ impl WrapperWidget for ValidatedWidget {
 
     fn redraw_background(&mut self, context: &mut crate::context::GraphicsContext<'_, '_, '_, '_>) {
         self.error_color.set(context.get(&InvalidTextColor));
+        self.pending_color.set(context.get(&PendingTextColor));
         self.default_color.set(context.get(&HintTextColor));
     }
 }
@@ -121,6 +135,9 @@ define_components! {
         HintTextColor(Color, "hint_color", @OutlineColor)
         /// The color of invalid text.
         InvalidTextColor(Color, "invalid_color", @ErrorColor)
+        /// The color of the hint text while a validation is
+        /// [pending](crate::reactive::value::Validation::Pending).
+        PendingTextColor(Color, "pending_color", |context| context.get(&HintTextColor))
         /// The text size for the validation message in a [`Validated`] widget.
         ValidatedTextSize(Dimension, "text_size", @TextSize2)
         /// The line hgiht for the validation message in a [`Validated`] widget.
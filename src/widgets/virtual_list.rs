@@ -19,6 +19,7 @@ use crate::widget::{
     Callback, EventHandling, MakeWidget, MountedWidget, Widget, WidgetInstance, HANDLED, IGNORED,
 };
 use crate::widgets::scroll::ScrollBar;
+use crate::widgets::Space;
 use crate::window::DeviceId;
 
 #[derive(Debug)]
@@ -144,6 +145,36 @@ impl VirtualList {
         }
     }
 
+    /// Creates a new [`VirtualList`] that displays a row for each element of
+    /// `items`, loading each row as needed by invoking `make_row` with the
+    /// row's index and element.
+    ///
+    /// When `items` is changed, the number of rows is updated and the visible
+    /// rows are reloaded. Rows are created, sized, and freed the same way as
+    /// [`VirtualList::new`].
+    pub fn from_items<T, MakeRow, Row>(items: impl IntoValue<Vec<T>>, mut make_row: MakeRow) -> Self
+    where
+        T: Send + 'static,
+        MakeRow: FnMut(usize, &T) -> Row + Send + 'static,
+        Row: MakeWidget,
+    {
+        let items = items.into_value().into_dynamic();
+        let list = Self::new(items.map_each(Vec::len), {
+            let items = items.clone();
+            move |index| {
+                items
+                    .map_ref(|items| {
+                        items
+                            .get(index)
+                            .map(|item| make_row(index, item).make_widget())
+                    })
+                    .unwrap_or_else(|| Space::clear().make_widget())
+            }
+        });
+        list.contents.watch(&items);
+        list
+    }
+
     /// Returns a [`Watcher`] that when notified will force this list to reload
     /// its contents, including the currently visible rows.
     pub const fn content_watcher(&self) -> &Watcher {
@@ -17,12 +17,25 @@ use crate::ConstraintLimit;
 ///
 /// This widget is designed to mimic how text layout occurs for words within a
 /// paragraph.
+///
+/// The alignment of widgets within each row is controlled by
+/// [`align`](Self::align), similar to CSS's `justify-content`. When this widget
+/// is given more vertical space than its rows need, the rows are positioned
+/// using [`line_align`](Self::line_align), similar to CSS's `align-content`.
+/// The width of [`spacing`](Self::spacing) is placed between widgets on the
+/// same row, and its height is placed between rows.
+///
+/// A widget that is wider than the available space is placed on its own row
+/// and overflows this widget's bounds.
 #[derive(Debug)]
 pub struct Wrap {
     /// The children to wrap.
     pub children: Value<WidgetList>,
     /// The horizontal alignment for widgets on the same row.
     pub align: Value<WrapAlign>,
+    /// The vertical alignment of the rows when there is more space available
+    /// than is needed.
+    pub line_align: Value<WrapAlign>,
     /// The spacing to place between widgets. When [`FlexibleDimension::Auto`]
     /// is set, [`IntrinsicPadding`] will be used.
     pub spacing: Value<Size<FlexibleDimension>>,
@@ -36,6 +49,7 @@ impl Wrap {
         Self {
             children: children.into_value(),
             align: Value::default(),
+            line_align: Value::default(),
             spacing: Value::Constant(Size::squared(FlexibleDimension::Auto)),
             mounted: MountedChildren::default(),
         }
@@ -55,7 +69,19 @@ impl Wrap {
         self
     }
 
-    fn horizontal_alignment(
+    /// Sets the vertical alignment of the rows and returns self.
+    ///
+    /// [`WrapAlign::Start`] places the rows at the top, and
+    /// [`WrapAlign::End`] places the rows at the bottom. When any alignment
+    /// other than [`WrapAlign::Start`] is used, this widget fills the vertical
+    /// space it is given.
+    #[must_use]
+    pub fn line_align(mut self, align: impl IntoValue<WrapAlign>) -> Self {
+        self.line_align = align.into_value();
+        self
+    }
+
+    fn alignment(
         align: WrapAlign,
         order: HorizontalOrder,
         remaining: Px,
@@ -88,9 +114,7 @@ impl Wrap {
 
 impl Widget for Wrap {
     fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
-        for child in self.mounted.children() {
-            context.for_other(child).redraw();
-        }
+        context.redraw_children(self.mounted.children());
     }
 
     #[allow(clippy::too_many_lines)]
@@ -105,10 +129,17 @@ impl Widget for Wrap {
             size: Size<Px>,
         }
 
+        struct Row {
+            children: Vec<RowChild>,
+            width: Px,
+            height: Px,
+        }
+
         let order = context.get(&LayoutOrder).horizontal;
 
         self.children.invalidate_when_changed(context);
         let align = self.align.get_tracking_invalidate(context);
+        let line_align = self.line_align.get_tracking_invalidate(context);
         let vertical_align = context.get(&VerticalAlignment);
         let spacing = self
             .spacing
@@ -122,17 +153,14 @@ impl Widget for Wrap {
         self.mounted
             .synchronize_with(&self.children, &mut context.as_event_context());
 
-        let mut y = Px::ZERO;
-        let mut row_children = Vec::new();
+        let mut rows = Vec::new();
         let mut index = 0;
         let width = available_space.width.max().into_signed();
         let child_constraints =
             available_space.map(|limit| ConstraintLimit::SizeToFit(limit.max()));
         while index < self.mounted.children().len() {
-            if y != Px::ZERO {
-                y += spacing.height;
-            }
             // Find all children that can fit on this next row.
+            let mut row_children = Vec::new();
             let mut x = Px::ZERO;
             let mut max_height = Px::ZERO;
             while let Some(child) = self.mounted.children().get(index) {
@@ -163,25 +191,63 @@ impl Widget for Wrap {
                 index += 1;
             }
 
+            rows.push(Row {
+                children: row_children,
+                width: x,
+                height: max_height,
+            });
+        }
+
+        let content_height = rows
+            .iter()
+            .fold(Px::ZERO, |height, row| height + row.height)
+            + spacing.height * rows.len().saturating_sub(1).cast::<i32>();
+        // Only fill the available height when the rows need to be positioned
+        // within it, so that the default layout is unchanged.
+        let height = match available_space.height {
+            ConstraintLimit::Fill(height) if line_align != WrapAlign::Start => {
+                height.into_signed().max(content_height)
+            }
+            ConstraintLimit::Fill(_) | ConstraintLimit::SizeToFit(_) => content_height,
+        };
+
+        // Calculate the vertical alignment of the rows.
+        let remaining = height - content_height;
+        let (mut y, line_space_between) = if remaining > 0 && !rows.is_empty() {
+            Self::alignment(
+                line_align,
+                HorizontalOrder::LeftToRight,
+                remaining,
+                rows.len(),
+            )
+        } else {
+            (Px::ZERO, Px::ZERO)
+        };
+
+        for (row_index, row) in rows.into_iter().enumerate() {
+            if row_index > 0 {
+                y += spacing.height + line_space_between;
+            }
+
             // Calculate the horizontal alignment.
-            let remaining = (width - x).max(Px::ZERO);
+            let remaining = (width - row.width).max(Px::ZERO);
             let (x, space_between) = if remaining > 0 {
-                Self::horizontal_alignment(align, order, remaining, row_children.len())
+                Self::alignment(align, order, remaining, row.children.len())
             } else {
                 (Px::ZERO, Px::ZERO)
             };
 
             // Position the children
             let mut additional_x = x;
-            for (child_index, child) in row_children.drain(..).enumerate() {
+            for (child_index, child) in row.children.into_iter().enumerate() {
                 if child_index > 0 {
                     additional_x += space_between;
                 }
                 let child_x = additional_x + child.x;
                 let child_y = y + match vertical_align {
                     VerticalAlign::Top => Px::ZERO,
-                    VerticalAlign::Center => (max_height - child.size.height) / 2,
-                    VerticalAlign::Bottom => max_height - child.size.height,
+                    VerticalAlign::Center => (row.height - child.size.height) / 2,
+                    VerticalAlign::Bottom => row.height - child.size.height,
                 };
 
                 context.set_child_layout(
@@ -190,14 +256,14 @@ impl Widget for Wrap {
                 );
             }
 
-            y += max_height;
+            y += row.height;
         }
 
-        Size::new(width, y).into_unsigned()
+        Size::new(width, height).into_unsigned()
     }
 }
 
-/// The horizontal alignment to apply to widgets inside of a [`Wrap`].
+/// The alignment to apply to widgets or rows inside of a [`Wrap`].
 #[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
 pub enum WrapAlign {
     /// Position the widgets at the start of the line, honoring [`LayoutOrder`].
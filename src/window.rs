@@ -4,9 +4,10 @@
 use std::cell::RefCell;
 use std::collections::hash_map;
 use std::ffi::OsStr;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 use std::io;
 use std::marker::PhantomData;
+use std::mem;
 use std::num::{NonZeroU32, TryFromIntError};
 use std::ops::{Deref, DerefMut, Not};
 use std::path::{Path, PathBuf};
@@ -17,7 +18,7 @@ use std::time::{Duration, Instant};
 use ahash::AHashMap;
 use alot::LotId;
 use arboard::Clipboard;
-use figures::units::{Px, UPx};
+use figures::units::{Lp, Px, UPx};
 use figures::{
     FloatConversion, Fraction, IntoSigned, IntoUnsigned, Point, Ranged, Rect, Round, ScreenScale,
     Size, UPx2D, Zero,
@@ -26,16 +27,18 @@ use image::{DynamicImage, RgbImage, RgbaImage};
 use intentional::{Assert, Cast};
 use kludgine::app::winit::dpi::{PhysicalPosition, PhysicalSize};
 use kludgine::app::winit::event::{
-    ElementState, Ime, Modifiers, MouseButton, MouseScrollDelta, TouchPhase,
+    ElementState, Force, Ime, Modifiers, MouseButton, MouseScrollDelta, TouchPhase,
 };
 use kludgine::app::winit::keyboard::{
     Key, KeyLocation, ModifiersState, NamedKey, NativeKeyCode, PhysicalKey, SmolStr,
 };
-use kludgine::app::winit::window::{Cursor, Fullscreen, Icon, WindowButtons, WindowLevel};
+use kludgine::app::winit::window::{
+    Cursor, Fullscreen, Icon, ResizeDirection, WindowButtons, WindowLevel,
+};
 use kludgine::app::{winit, WindowAttributes, WindowBehavior as _};
 use kludgine::cosmic_text::{fontdb, Family, FamilyOwned};
 use kludgine::drawing::Drawing;
-use kludgine::shapes::Shape;
+use kludgine::shapes::{Shape, StrokeOptions};
 use kludgine::wgpu::{self, CompositeAlphaMode, COPY_BYTES_PER_ROW_ALIGNMENT};
 use kludgine::{Color, DrawableExt, Kludgine, KludgineId, Origin, Texture};
 use parking_lot::{Mutex, MutexGuard};
@@ -44,7 +47,7 @@ use tracing::Level;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::animation::{
-    AnimationTarget, Easing, LinearInterpolate, PercentBetween, Spawn, ZeroToOne,
+    self, AnimationTarget, Easing, LinearInterpolate, PercentBetween, Spawn, ZeroToOne,
 };
 use crate::app::{Application, Cushy, Open, PendingApp, Run};
 use crate::context::sealed::{InvalidationStatus, Trackable as _};
@@ -57,7 +60,7 @@ use crate::graphics::{FontState, Graphics};
 use crate::reactive::value::{
     Destination, Dynamic, DynamicReader, IntoDynamic, IntoValue, Source, Tracked, Value,
 };
-use crate::styles::{Edges, FontFamilyList, ThemePair};
+use crate::styles::{ColorSource, Edges, FontFamilyList, ThemePair};
 use crate::tree::Tree;
 use crate::utils::ModifiersExt;
 use crate::widget::{
@@ -68,6 +71,11 @@ use crate::widgets::shortcuts::{ShortcutKey, ShortcutMap};
 use crate::window::sealed::WindowCommand;
 use crate::{App, ConstraintLimit, MaybeLocalized};
 
+mod geometry;
+
+use self::geometry::PersistentGeometry;
+pub use self::geometry::{FileGeometryStore, GeometryStore, WindowGeometry};
+
 /// A platform-dependent window implementation.
 pub trait PlatformWindowImplementation {
     /// Marks the window to close as soon as possible.
@@ -362,6 +370,31 @@ where
         self.handle().request_close();
     }
 
+    /// Advances the focus from the currently focused widget to the next widget
+    /// in the window's focus order.
+    ///
+    /// This is the same behavior as pressing the Tab key. See
+    /// [`WindowHandle::advance_focus`] for more information.
+    pub fn advance_focus(&self) {
+        self.handle().advance_focus();
+    }
+
+    /// Returns the focus from the currently focused widget to the previous
+    /// widget in the window's focus order.
+    ///
+    /// This is the same behavior as pressing Shift+Tab. See
+    /// [`WindowHandle::return_focus`] for more information.
+    pub fn return_focus(&self) {
+        self.handle().return_focus();
+    }
+
+    /// Focuses the widget with `id`, if it is mounted in this window.
+    ///
+    /// See [`WindowHandle::focus_widget`] for more information.
+    pub fn focus_widget(&self, id: WidgetId) {
+        self.handle().focus_widget(id);
+    }
+
     /// Returns a handle to this window.
     #[must_use]
     pub fn handle(&self) -> WindowHandle {
@@ -385,6 +418,119 @@ where
     pub fn clipboard_guard(&self) -> Option<MutexGuard<'_, Clipboard>> {
         self.app.cushy().clipboard_guard()
     }
+
+    /// Returns the text contained in the OS's clipboard.
+    ///
+    /// Returns `None` if the clipboard is unavailable or does not currently
+    /// contain text.
+    #[must_use]
+    pub fn clipboard_text(&self) -> Option<String> {
+        self.app.cushy().clipboard_text()
+    }
+
+    /// Replaces the contents of the OS's clipboard with `text`.
+    ///
+    /// Returns true if the clipboard was updated successfully.
+    #[must_use]
+    pub fn set_clipboard_text(&self, text: &str) -> bool {
+        self.app.cushy().set_clipboard_text(text)
+    }
+
+    /// Sets the fullscreen mode of this window.
+    ///
+    /// If the window's fullscreen mode is synchronized with a [`Dynamic`],
+    /// the dynamic will be updated after the window is resized into its new
+    /// mode.
+    pub fn set_fullscreen(&self, fullscreen: Option<Fullscreen>) {
+        if let Some(winit) = self.window.winit() {
+            winit.set_fullscreen(fullscreen);
+        }
+    }
+
+    /// Returns the current fullscreen mode of this window.
+    #[must_use]
+    pub fn fullscreen(&self) -> Option<Fullscreen> {
+        self.window.winit().and_then(|winit| winit.fullscreen())
+    }
+
+    /// Returns an exclusive fullscreen mode for the monitor this window is
+    /// currently on.
+    ///
+    /// The video mode with the largest resolution is chosen, preferring higher
+    /// refresh rates and bit depths when multiple modes have the same
+    /// resolution. Returns `None` if the current monitor cannot be determined.
+    #[must_use]
+    pub fn exclusive_fullscreen_mode(&self) -> Option<Fullscreen> {
+        self.window
+            .winit()?
+            .current_monitor()?
+            .video_modes()
+            .max_by_key(|mode| {
+                let size = mode.size();
+                (
+                    u64::from(size.width) * u64::from(size.height),
+                    mode.refresh_rate_millihertz(),
+                    mode.bit_depth(),
+                )
+            })
+            .map(Fullscreen::Exclusive)
+    }
+
+    /// Minimizes or restores this window.
+    ///
+    /// The dynamic provided to [`Window::minimized`] will be updated once the
+    /// window's state changes.
+    pub fn set_minimized(&self, minimized: bool) {
+        if let Some(winit) = self.window.winit() {
+            winit.set_minimized(minimized);
+        }
+    }
+
+    /// Maximizes or restores this window.
+    ///
+    /// The dynamic provided to [`Window::maximized`] will be updated once the
+    /// window's state changes.
+    pub fn set_maximized(&self, maximized: bool) {
+        if let Some(winit) = self.window.winit() {
+            winit.set_maximized(maximized);
+        }
+    }
+
+    /// Begins resizing this window by dragging the border or corner identified
+    /// by `direction`.
+    ///
+    /// This function should be called in response to a mouse button being
+    /// pressed, and is intended for implementing custom resize grips for
+    /// windows that have [decorations](Window::decorated) disabled. If the
+    /// platform does not support this operation, this function does nothing.
+    pub fn drag_resize_window(&self, direction: ResizeDirection) {
+        if let Some(winit) = self.window.winit() {
+            if let Err(err) = winit.drag_resize_window(direction) {
+                tracing::debug!("unable to drag resize window: {err}");
+            }
+        }
+    }
+
+    /// Sets the level of this window, allowing it to be shown above or below
+    /// other windows.
+    ///
+    /// To bind the window's level to a [`Dynamic`], use
+    /// [`Window::window_level`].
+    pub fn set_window_level(&self, level: WindowLevel) {
+        if let Some(winit) = self.window.winit() {
+            winit.set_window_level(level);
+        }
+    }
+
+    /// Opens `window` in the same application as this window, returning a
+    /// handle to the newly opened window.
+    ///
+    /// All windows opened within an application share the same runtime, which
+    /// allows reactive values such as [`Dynamic`]s to be shared between
+    /// windows.
+    pub fn open_window(&self, window: impl Open) -> crate::Result<WindowHandle> {
+        window.open(&mut self.app.clone())
+    }
 }
 
 impl<W> Deref for RunningWindow<W>
@@ -554,6 +700,10 @@ where
     occluded: Option<Dynamic<bool>>,
     focused: Option<Dynamic<bool>>,
     theme_mode: Option<Value<ThemeMode>>,
+    theme_transition: Duration,
+    debug_overlay: Option<Value<bool>>,
+    content_minimum_size: bool,
+    sized_to_content: bool,
     content_protected: Option<Value<bool>>,
     cursor_hittest: Option<Value<bool>>,
     cursor_visible: Option<Value<bool>>,
@@ -574,7 +724,9 @@ where
     enabled_buttons: Option<Value<WindowButtons>>,
     fullscreen: Option<Value<Option<Fullscreen>>>,
     shortcuts: Value<ShortcutMap>,
+    accelerators: Value<ShortcutMap>,
     on_file_drop: Option<Notify<FileDrop>>,
+    persistent_geometry: Option<PersistentGeometry>,
 }
 
 impl<Behavior> Default for Window<Behavior>
@@ -635,6 +787,10 @@ where
             occluded: None,
             focused: None,
             theme_mode: None,
+            theme_transition: Duration::ZERO,
+            debug_overlay: None,
+            content_minimum_size: false,
+            sized_to_content: false,
             inner_size: None,
             serif_font_family: FontFamilyList::default(),
             sans_serif_font_family: FontFamilyList::default(),
@@ -671,8 +827,10 @@ where
             enabled_buttons: None,
             fullscreen: None,
             shortcuts: Value::default(),
+            accelerators: Value::default(),
             on_init: None,
             on_file_drop: None,
+            persistent_geometry: None,
         }
     }
 
@@ -766,6 +924,9 @@ where
     }
 
     /// Sets the full screen mode for this window.
+    ///
+    /// If `fullscreen` is a [`Dynamic`], it will be updated when the operating
+    /// system changes the window's fullscreen mode.
     pub fn fullscreen(mut self, fullscreen: impl IntoValue<Option<Fullscreen>>) -> Self {
         let fullscreen = fullscreen.into_value();
         self.attributes.fullscreen = fullscreen.get();
@@ -773,6 +934,20 @@ where
         self
     }
 
+    /// Sets whether this window is shown in borderless fullscreen on its
+    /// current monitor.
+    ///
+    /// `fullscreen` is updated when the operating system enters or exits
+    /// fullscreen for this window. To choose a specific monitor or an
+    /// exclusive video mode, use [`fullscreen`](Self::fullscreen).
+    pub fn borderless_fullscreen(self, fullscreen: impl IntoDynamic<bool>) -> Self {
+        let mode = fullscreen.into_dynamic().linked(
+            |fullscreen| Some(fullscreen.then_some(Fullscreen::Borderless(None))),
+            |mode: &Option<Fullscreen>| mode.is_some(),
+        );
+        self.fullscreen(mode)
+    }
+
     /// Sets `inner_size` to be the dynamic synchronized with this window's
     /// inner size.
     ///
@@ -846,6 +1021,27 @@ where
         self
     }
 
+    /// Prevents this window from being resized smaller than the minimum size
+    /// of its contents, and returns self.
+    ///
+    /// The minimum size is measured by laying out the contents with no space
+    /// available. If the contents specify a minimum size using
+    /// [`Resize`](crate::widgets::Resize), the larger of the two sizes is used.
+    pub fn with_content_minimum_size(mut self) -> Self {
+        self.content_minimum_size = true;
+        self
+    }
+
+    /// Resizes this window to the preferred size of its contents when it is
+    /// opened, and returns self.
+    ///
+    /// The window is not shown until its contents have been measured. The size
+    /// is limited to the size of the monitor the window opens on.
+    pub fn sized_to_content(mut self) -> Self {
+        self.sized_to_content = true;
+        self
+    }
+
     /// Prevents the window contents from being captured by other apps.
     pub fn content_protected(mut self, protected: impl IntoValue<bool>) -> Self {
         let protected = protected.into_value();
@@ -893,6 +1089,10 @@ where
     }
 
     /// Controls the level of this window.
+    ///
+    /// When `window_level` is a [`Dynamic`], the window's level is updated
+    /// each time the dynamic changes. This allows binding a window's
+    /// always-on-top state to a widget such as a checkbox.
     pub fn window_level(mut self, window_level: impl IntoValue<WindowLevel>) -> Self {
         let window_level = window_level.into_value();
         self.attributes.window_level = window_level.get();
@@ -900,15 +1100,21 @@ where
         self
     }
 
-    /// Provides a dynamic that is updated with the minimized status of this
-    /// window.
+    /// Provides a dynamic that is synchronized with the minimized status of
+    /// this window.
+    ///
+    /// When the window is minimized or restored, the dynamic will be updated.
+    /// Setting the dynamic will minimize or restore the window.
     pub fn minimized(mut self, minimized: impl IntoDynamic<bool>) -> Self {
         self.minimized = Some(minimized.into_dynamic());
         self
     }
 
-    /// Provides a dynamic that is updated with the maximized status of this
-    /// window.
+    /// Provides a dynamic that is synchronized with the maximized status of
+    /// this window.
+    ///
+    /// When the window is maximized or restored, the dynamic will be updated.
+    /// Setting the dynamic will maximize or restore the window.
     pub fn maximized(mut self, maximized: impl IntoDynamic<bool>) -> Self {
         let maximized = maximized.into_dynamic();
         self.attributes.maximized = maximized.get();
@@ -955,6 +1161,9 @@ where
 
     /// Sets the [`ThemeMode`] for this window.
     ///
+    /// By default, windows follow the operating system's light or dark mode
+    /// preference, switching modes whenever the preference changes.
+    ///
     /// If a [`ThemeMode`] is provided, the window will be set to this theme
     /// mode upon creation and will not be updated while the window is running.
     ///
@@ -977,6 +1186,29 @@ where
         self
     }
 
+    /// Gradually transitions between the old and new colors over `duration`
+    /// when this window's theme or theme mode changes, and returns self.
+    ///
+    /// By default, theme changes are applied immediately.
+    pub fn theme_transition(mut self, duration: Duration) -> Self {
+        self.theme_transition = duration;
+        self
+    }
+
+    /// Draws the layout of every widget on top of this window's contents
+    /// while `enabled` is true, and returns self.
+    ///
+    /// Each widget's layout is outlined in a color chosen by its type. The
+    /// hovered widget is shaded and the focused widget's outline is drawn
+    /// thicker.
+    ///
+    /// When this is not set, the overlay is shown if the `CUSHY_DEBUG_OVERLAY`
+    /// environment variable is set.
+    pub fn debug_overlay(mut self, enabled: impl IntoValue<bool>) -> Self {
+        self.debug_overlay = Some(enabled.into_value());
+        self
+    }
+
     /// Adds `font_data` to the list of fonts to load for availability when
     /// rendering.
     ///
@@ -1017,7 +1249,9 @@ where
     /// Invokes `on_close_requested` when the window is requested to be closed.
     ///
     /// If the function returns true, the window is allowed to be closed,
-    /// otherwise the window remains open.
+    /// otherwise the window remains open. To close the window after rejecting
+    /// a close request, such as after asking the user to confirm discarding
+    /// unsaved changes, use [`WindowHandle::close`].
     pub fn on_close_requested<Function>(mut self, on_close_requested: Function) -> Self
     where
         Function: FnMut(()) -> bool + Send + 'static,
@@ -1026,6 +1260,36 @@ where
         self
     }
 
+    /// Saves this window's position and size when it is closed, and restores
+    /// them the next time a window with the same `key` is opened.
+    ///
+    /// If the window is minimized, maximized, or fullscreen when it is closed,
+    /// the previously saved geometry is kept. The geometry is saved using the
+    /// [`FileGeometryStore`]'s default location. If the restored position is
+    /// not on any connected monitor, the window is moved onto the primary
+    /// monitor.
+    pub fn persistent_geometry(self, key: impl Into<String>) -> Self {
+        self.persistent_geometry_in(key, FileGeometryStore::default())
+    }
+
+    /// Saves this window's position and size in `store` when it is closed, and
+    /// restores them the next time a window with the same `key` is opened.
+    ///
+    /// If the window is minimized, maximized, or fullscreen when it is closed,
+    /// the previously saved geometry is kept. If the restored position is not
+    /// on any connected monitor, the window is moved onto the primary monitor.
+    pub fn persistent_geometry_in(
+        mut self,
+        key: impl Into<String>,
+        store: impl GeometryStore,
+    ) -> Self {
+        self.persistent_geometry = Some(PersistentGeometry {
+            key: key.into(),
+            store: Arc::new(store),
+        });
+        self
+    }
+
     /// Invokes `on_file_drop` when a file is hovered or dropped on this window.
     pub fn on_file_drop<Function>(self, on_file_drop: Function) -> Self
     where
@@ -1052,6 +1316,26 @@ where
         self
     }
 
+    /// Sets the window's icon from a buffer of 8-bit RGBA pixels that is
+    /// `width` pixels wide and `height` pixels tall.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the length of `rgba` is not exactly `width *
+    /// height * 4`.
+    pub fn icon_from_rgba(self, rgba: impl Into<Vec<u8>>, width: u32, height: u32) -> Self {
+        let rgba = rgba.into();
+        let expected_length = u64::from(width) * u64::from(height) * 4;
+        assert!(
+            u64::try_from(rgba.len()).is_ok_and(|length| length == expected_length),
+            "icon must contain {expected_length} bytes for a {width}x{height} RGBA image, but \
+             {} bytes were provided",
+            rgba.len()
+        );
+        let icon = RgbaImage::from_raw(width, height, rgba).expect("length checked");
+        self.icon(Some(icon))
+    }
+
     /// Sets `modifiers` to contain the state of the keyboard modifiers when
     /// this window has keyboard focus.
     pub fn modifiers(mut self, modifiers: impl IntoDynamic<Modifiers>) -> Self {
@@ -1087,6 +1371,9 @@ where
     }
 
     /// Invokes `shortcuts` when keyboard input is unhandled in this window.
+    ///
+    /// When a [`Dynamic`] is provided, shortcuts can be registered and
+    /// unregistered while the window is open.
     pub fn with_shortcuts(mut self, shortcuts: impl IntoValue<ShortcutMap>) -> Self {
         self.shortcuts = shortcuts.into_value();
         self
@@ -1109,6 +1396,38 @@ where
             .map_mut(|mut shortcuts| shortcuts.insert_repeating(key, modifiers, callback));
         self
     }
+
+    /// Invokes `callback` when `key` is pressed while `modifiers` are pressed,
+    /// before the focused widget receives the keyboard input.
+    ///
+    /// Unlike [`with_shortcut()`](Self::with_shortcut), accelerators cannot be
+    /// handled by widgets first. An accelerator for Ctrl+S will be invoked even
+    /// while a text input is focused. To only handle a key combination when
+    /// the focused widget does not, such as when no text input is focused,
+    /// use a shortcut instead.
+    pub fn with_accelerator<F>(
+        mut self,
+        key: impl Into<ShortcutKey>,
+        modifiers: ModifiersState,
+        callback: F,
+    ) -> Self
+    where
+        F: FnMut(KeyEvent) -> EventHandling + Send + 'static,
+    {
+        self.accelerators
+            .map_mut(|mut accelerators| accelerators.insert(key, modifiers, callback));
+        self
+    }
+
+    /// Invokes `accelerators` before the focused widget receives keyboard
+    /// input in this window.
+    ///
+    /// When a [`Dynamic`] is provided, accelerators can be registered and
+    /// unregistered while the window is open.
+    pub fn with_accelerators(mut self, accelerators: impl IntoValue<ShortcutMap>) -> Self {
+        self.accelerators = accelerators.into_value();
+        self
+    }
 }
 
 impl<Behavior> Run for Window<Behavior>
@@ -1130,7 +1449,11 @@ where
     where
         App: Application + ?Sized,
     {
-        let this = self.make_window();
+        let mut this = self.make_window();
+        // Windows sized to their contents are shown once they are measured.
+        let sized_to_content = this
+            .sized_to_content
+            .then(|| mem::replace(&mut this.attributes.visible, false));
         let app_app = app.as_app();
         let handle = this.pending.handle();
         OpenWindow::<T::Behavior>::open_with(
@@ -1151,6 +1474,12 @@ where
                     inner_size: this.inner_size.unwrap_or_default(),
                     theme: Some(this.theme),
                     theme_mode: this.theme_mode,
+                    theme_transition: this.theme_transition,
+                    debug_overlay: this.debug_overlay.unwrap_or_else(|| {
+                        Value::Constant(std::env::var_os("CUSHY_DEBUG_OVERLAY").is_some())
+                    }),
+                    content_minimum_size: this.content_minimum_size,
+                    sized_to_content,
                     font_data_to_load: this.fonts,
                     serif_font_family: this.serif_font_family,
                     sans_serif_font_family: this.sans_serif_font_family,
@@ -1183,7 +1512,9 @@ where
                         .unwrap_or(Value::Constant(WindowButtons::all())),
                     fullscreen: this.fullscreen.unwrap_or_default(),
                     shortcuts: this.shortcuts,
+                    accelerators: this.accelerators,
                     on_file_drop: this.on_file_drop,
+                    persistent_geometry: this.persistent_geometry,
                 }),
                 pending: this.pending,
             },
@@ -1344,6 +1675,8 @@ struct OpenWindow<T> {
     contents: Drawing,
     cursor: CursorState,
     mouse_buttons: AHashMap<DeviceId, AHashMap<MouseButton, WidgetId>>,
+    last_clicks: AHashMap<(DeviceId, MouseButton), LastClick>,
+    touches: AHashMap<(DeviceId, u64), WidgetId>,
     redraw_status: InvalidationStatus,
     initial_frame: bool,
     occluded: Dynamic<bool>,
@@ -1356,13 +1689,23 @@ struct OpenWindow<T> {
     resize_to_fit: Value<bool>,
     theme: Option<DynamicReader<ThemePair>>,
     current_theme: ThemePair,
+    target_theme: ThemePair,
+    theme_transition_duration: Duration,
+    theme_transition: Option<ThemeTransition>,
+    debug_overlay: Value<bool>,
+    content_minimum_size: bool,
+    applied_content_minimum: Option<Size<UPx>>,
+    sized_to_content: Option<bool>,
     theme_mode: Value<ThemeMode>,
+    last_theme_mode: ThemeMode,
+    notify_theme_changed: bool,
     transparent: bool,
     fonts: FontState,
     app: App,
     on_closed: Option<OnceCallback>,
     vsync: bool,
-    dpi_scale: Dynamic<Fraction>,
+    dpi_scale: Tracked<Dynamic<Fraction>>,
+    notify_resized: bool,
     zoom: Tracked<Dynamic<Fraction>>,
     close_requested: Option<SharedCallback<(), bool>>,
     content_protected: Tracked<Value<bool>>,
@@ -1383,7 +1726,10 @@ struct OpenWindow<T> {
     fullscreen: Tracked<Value<Option<Fullscreen>>>,
     modifiers: Dynamic<Modifiers>,
     shortcuts: Value<ShortcutMap>,
+    accelerators: Value<ShortcutMap>,
     on_file_drop: Option<Notify<FileDrop>>,
+    persistent_geometry: Option<PersistentGeometry>,
+    file_hover_target: Option<WidgetId>,
     disabled_resize_automatically: bool,
 }
 
@@ -1391,10 +1737,10 @@ impl<T> OpenWindow<T>
 where
     T: WindowBehavior,
 {
-    fn request_close(
-        behavior: &mut T,
-        window: &mut RunningWindow<kludgine::app::Window<'_, WindowCommand>>,
-    ) -> bool {
+    fn request_close<W>(behavior: &mut T, window: &mut RunningWindow<W>) -> bool
+    where
+        W: PlatformWindowImplementation,
+    {
         behavior.close_requested(window)
             && window
                 .close_requested
@@ -1546,6 +1892,7 @@ where
                             if new_min_size != self.min_inner_size && resizable {
                                 context.set_min_inner_size(new_min_size);
                                 self.min_inner_size = new_min_size;
+                                self.applied_content_minimum = None;
                             }
                             let new_max_size = (max_width > 0 || max_height > 0)
                                 .then_some(Size::new(max_width, max_height).into_unsigned());
@@ -1621,18 +1968,18 @@ where
 
     fn handle_window_keyboard_input<W>(
         &mut self,
-        window: &mut W,
+        window: &mut RunningWindow<W>,
         kludgine: &mut Kludgine,
         input: KeyEvent,
     ) -> EventHandling
     where
-        W: PlatformWindow,
+        W: PlatformWindowImplementation,
     {
         match input.logical_key {
             Key::Character(ch) if ch == "w" && window.modifiers().primary() => {
                 if !input.repeat
                     && input.state.is_pressed()
-                    && self.behavior.close_requested(window)
+                    && Self::request_close(&mut self.behavior, window)
                 {
                     window.close();
                     window.set_needs_redraw();
@@ -1792,8 +2139,11 @@ where
             cursor: CursorState {
                 location: None,
                 widget: None,
+                click_count: 0,
             },
             mouse_buttons: AHashMap::default(),
+            last_clicks: AHashMap::default(),
+            touches: AHashMap::default(),
             redraw_status,
             initial_frame: true,
             occluded: settings.occluded,
@@ -1803,16 +2153,26 @@ where
             min_inner_size: None,
             max_inner_size: None,
             resize_to_fit: settings.resize_to_fit,
+            target_theme: current_theme.clone(),
             current_theme,
             theme,
+            theme_transition_duration: settings.theme_transition,
+            theme_transition: None,
+            debug_overlay: settings.debug_overlay,
+            content_minimum_size: settings.content_minimum_size,
+            applied_content_minimum: None,
+            sized_to_content: settings.sized_to_content,
+            last_theme_mode: theme_mode.get(),
             theme_mode,
+            notify_theme_changed: false,
             transparent: settings.transparent,
             fonts,
             app,
             on_closed: settings.on_closed,
             vsync: settings.vsync,
             close_requested: settings.close_requested,
-            dpi_scale,
+            dpi_scale: Tracked::from(dpi_scale).ignoring_first(),
+            notify_resized: false,
             zoom: Tracked::from(settings.zoom),
             content_protected: Tracked::from(settings.content_protected).ignoring_first(),
             cursor_hittest: Tracked::from(settings.cursor_hittest),
@@ -1820,8 +2180,8 @@ where
             cursor_position: Tracked::from(settings.cursor_position),
             window_level: Tracked::from(settings.window_level).ignoring_first(),
             decorated: Tracked::from(settings.decorated).ignoring_first(),
-            maximized: Tracked::from(settings.maximized),
-            minimized: Tracked::from(settings.minimized),
+            maximized: Tracked::from(settings.maximized).ignoring_first(),
+            minimized: Tracked::from(settings.minimized).ignoring_first(),
             resizable: Tracked::from(settings.resizable).ignoring_first(),
             resize_increments: Tracked::from(settings.resize_increments),
             visible: Tracked::from(settings.visible).ignoring_first(),
@@ -1833,7 +2193,10 @@ where
             enabled_buttons: Tracked::from(settings.enabled_buttons).ignoring_first(),
             fullscreen: Tracked::from(settings.fullscreen).ignoring_first(),
             shortcuts: settings.shortcuts,
+            accelerators: settings.accelerators,
             on_file_drop: settings.on_file_drop,
+            persistent_geometry: settings.persistent_geometry,
+            file_hover_target: None,
             disabled_resize_automatically: false,
         };
 
@@ -1846,12 +2209,61 @@ where
     }
 
     fn new_frame(&mut self, graphics: &mut kludgine::Graphics<'_>) {
+        let mut theme_updated = false;
         if let Some(theme) = &mut self.theme {
             if theme.has_updated() {
-                self.current_theme = theme.get();
+                self.target_theme = theme.get();
+                theme_updated = true;
+                self.notify_theme_changed = true;
                 self.root.invalidate();
             }
         }
+        let theme_mode = self.theme_mode.get();
+        let mode_updated = theme_mode != self.last_theme_mode;
+        if theme_updated || mode_updated {
+            if self.theme_transition_duration.is_zero() {
+                self.current_theme = self.target_theme.clone();
+            } else {
+                // While the mode is changing, both variants of the theme
+                // display the blend between the old and new modes' themes.
+                let resolve_mode = mode_updated
+                    || self
+                        .theme_transition
+                        .as_ref()
+                        .is_some_and(|transition| transition.resolve_mode);
+                let from = if mode_updated {
+                    ThemeTransition::resolved(&self.current_theme, self.last_theme_mode)
+                } else {
+                    self.current_theme.clone()
+                };
+                self.theme_transition = Some(ThemeTransition {
+                    from,
+                    resolve_mode,
+                    started: animation::now(),
+                });
+            }
+        }
+        if mode_updated {
+            self.last_theme_mode = theme_mode;
+            self.notify_theme_changed = true;
+        }
+        if let Some(transition) = &self.theme_transition {
+            let elapsed = animation::now().saturating_duration_since(transition.started);
+            if elapsed >= self.theme_transition_duration {
+                self.current_theme = self.target_theme.clone();
+                self.theme_transition = None;
+            } else {
+                let target = if transition.resolve_mode {
+                    ThemeTransition::resolved(&self.target_theme, theme_mode)
+                } else {
+                    self.target_theme.clone()
+                };
+                self.current_theme = transition.from.lerp(
+                    &target,
+                    elapsed.as_secs_f32() / self.theme_transition_duration.as_secs_f32(),
+                );
+            }
+        }
 
         self.redraw_status.refresh_received();
         graphics.reset_text_attributes();
@@ -1873,6 +2285,11 @@ where
 
         self.synchronize_platform_window(&mut window);
         self.new_frame(graphics);
+        if self.theme_transition.is_some() {
+            // Themes only affect how widgets are drawn, so redrawing is enough
+            // to display the next step of the transition.
+            window.set_needs_redraw();
+        }
 
         let resize_to_fit = self.resize_to_fit.get();
         let resizable = *self.resizable.peek() || resize_to_fit;
@@ -1907,8 +2324,23 @@ where
             gfx: Exclusive::Owned(Graphics::new(graphics)),
         };
         self.theme_mode.redraw_when_changed(&context);
+        self.debug_overlay.redraw_when_changed(&context);
         self.inner_size.invalidate_when_changed(&context);
         self.resize_to_fit.invalidate_when_changed(&context);
+        if !self.initial_frame {
+            let new_scale = self.dpi_scale.updated().copied();
+            let new_size = mem::take(&mut self.notify_resized).then(|| *self.inner_size.peek());
+            let theme_changed = mem::take(&mut self.notify_theme_changed);
+            if new_scale.is_some() || new_size.is_some() || theme_changed {
+                Self::notify_window_changes(
+                    &self.tree,
+                    new_size,
+                    new_scale,
+                    theme_changed,
+                    &mut context.as_event_context(),
+                );
+            }
+        }
         let mut layout_context = LayoutContext::new(&mut context);
         let window_size = layout_context.gfx.size();
 
@@ -1917,6 +2349,35 @@ where
             layout_context.graphics.gfx.fill(background_color);
         }
 
+        // Measuring the minimum size lays out the contents in hypothetical
+        // conditions, so it is only done when the contents are about to be laid
+        // out again regardless.
+        let content_minimum = (self.content_minimum_size && self.root.last_layout().is_none())
+            .then(|| {
+                layout_context
+                    .for_other(&self.root)
+                    .as_temporary()
+                    .layout(Size::new(
+                        ConstraintLimit::SizeToFit(UPx::ZERO),
+                        ConstraintLimit::SizeToFit(UPx::ZERO),
+                    ))
+            });
+
+        let content_size = self.sized_to_content.is_some().then(|| {
+            let available = layout_context
+                .window()
+                .winit()
+                .and_then(|winit| winit.current_monitor())
+                .map_or(Size::new(UPx::MAX, UPx::MAX), |monitor| {
+                    Size::from(monitor.size())
+                });
+            layout_context
+                .for_other(&self.root)
+                .as_temporary()
+                .layout(available.map(ConstraintLimit::SizeToFit))
+                .min(available)
+        });
+
         let layout_size =
             layout_context.layout(if matches!(root_mode, RootMode::Expand | RootMode::Align) {
                 window_size.map(ConstraintLimit::Fill)
@@ -1945,6 +2406,10 @@ where
             layout_context.redraw();
         }
 
+        if self.debug_overlay.get() {
+            Self::draw_debug_overlay(&self.tree, &mut layout_context);
+        }
+
         let resizable = resizable
             && !Self::enforce_fixed_size(
                 self.min_inner_size,
@@ -1954,8 +2419,20 @@ where
                 &mut layout_context,
             );
 
+        if let Some(content_minimum) = content_minimum.filter(|_| resizable) {
+            let minimum = self
+                .min_inner_size
+                .map_or(content_minimum, |minimum| minimum.max(content_minimum));
+            if self.applied_content_minimum != Some(minimum) {
+                self.applied_content_minimum = Some(minimum);
+                layout_context.set_min_inner_size(Some(minimum));
+            }
+        }
+
         let new_size = if let Some(new_size) = self.inner_size.updated() {
             layout_context.request_inner_size(*new_size)
+        } else if let Some(content_size) = content_size.filter(|size| *size != window_size) {
+            layout_context.request_inner_size(content_size)
         } else if actual_size != window_size && !resizable {
             let mut new_size = actual_size;
             if let Some(min_size) = self.min_inner_size {
@@ -1977,15 +2454,84 @@ where
             self.root.invalidate();
         }
 
+        if self.sized_to_content.take() == Some(true) {
+            if let Some(winit) = layout_context.window().winit() {
+                winit.set_visible(true);
+            }
+        }
+
         layout_context.as_event_context().update_hovered_widget();
     }
 
+    fn draw_debug_overlay(tree: &Tree, context: &mut LayoutContext<'_, '_, '_, '_>) {
+        let hovered = tree.hovered_widget();
+        let focused = tree.focused_widget();
+        let line_width = Lp::points(1)
+            .into_px(context.gfx.scale())
+            .ceil()
+            .max(Px::new(1));
+        for widget in tree.widgets() {
+            let Some(layout) = widget.last_layout() else {
+                continue;
+            };
+            // Hashing the widget's type gives each kind of widget a stable
+            // color.
+            let mut hasher = hash_map::DefaultHasher::new();
+            widget
+                .lock()
+                .as_widget()
+                .as_any()
+                .type_id()
+                .hash(&mut hasher);
+            let hue = (hasher.finish() % 360).cast::<f32>();
+            let color = ColorSource::new(hue, 0.8).color(0.6);
+
+            if hovered == Some(widget.node_id) {
+                context
+                    .gfx
+                    .draw_shape(&Shape::filled_rect(layout, color.with_alpha(64)));
+            }
+            let width = if focused == Some(widget.node_id) {
+                line_width * 3
+            } else {
+                line_width
+            };
+            context.gfx.draw_shape(&Shape::stroked_rect(
+                layout,
+                StrokeOptions::px_wide(width).colored(color),
+            ));
+        }
+    }
+
+    fn notify_window_changes(
+        tree: &Tree,
+        new_size: Option<Size<UPx>>,
+        new_scale: Option<Fraction>,
+        theme_changed: bool,
+        context: &mut EventContext<'_>,
+    ) {
+        for widget in tree.widgets() {
+            let mut context = context.for_other(&widget);
+            if theme_changed {
+                context.theme_changed();
+            }
+            if let Some(new_scale) = new_scale {
+                context.scale_factor_changed(new_scale);
+            }
+            if let Some(new_size) = new_size {
+                context.window_resized(new_size);
+            }
+        }
+    }
+
     fn mount_and_focus_root(root: &MountedWidget, context: &mut LayoutContext<'_, '_, '_, '_>) {
-        root.lock()
-            .as_widget()
-            .mounted(&mut context.as_event_context());
+        let mut context = context.as_event_context();
+        let mut widget = root.lock();
+        widget.as_widget().mounted(&mut context);
+        widget.as_widget().theme_changed(&mut context);
+        drop(widget);
         context.focus();
-        context.as_event_context().apply_pending_state();
+        context.apply_pending_state();
     }
 
     fn enforce_fixed_size(
@@ -2029,7 +2575,7 @@ where
             self.inner_size.source(),
             &self.close_requested,
         );
-        if self.behavior.close_requested(&mut window) {
+        if Self::request_close(&mut self.behavior, &mut window) {
             window.close();
             true
         } else {
@@ -2044,6 +2590,14 @@ where
         self.inner_size.set_and_read(new_size);
         self.outer_size.set(window.outer_size());
         self.update_ized(window);
+        if let Some(winit) = window.winit() {
+            // Fullscreen changes initiated by the operating system result in a
+            // resize. Avoid overwriting a change that has yet to be applied.
+            if !self.fullscreen.update() {
+                self.fullscreen.set_and_read(winit.fullscreen());
+            }
+        }
+        self.notify_resized = true;
         self.root.invalidate();
     }
 
@@ -2059,11 +2613,19 @@ where
         if let Some(winit) = window.winit() {
             // TODO should these be supported outside of winit? Put in a feature
             // request if you read this and need them.
-            self.maximized.set_and_read(winit.is_maximized());
-            if let Some(minimized) = winit.is_minimized() {
-                self.minimized.set_and_read(minimized);
+            // Values changed by the application that haven't been applied to
+            // the window yet must not be overwritten.
+            if !self.maximized.update() {
+                self.maximized.set_and_read(winit.is_maximized());
+            }
+            if !self.minimized.update() {
+                if let Some(minimized) = winit.is_minimized() {
+                    self.minimized.set_and_read(minimized);
+                }
+            }
+            if !self.decorated.update() {
+                self.decorated.set_and_read(winit.is_decorated());
             }
-            self.decorated.set_and_read(winit.is_decorated());
         }
     }
 
@@ -2135,6 +2697,12 @@ where
             when_updated!(fullscreen, handle, {
                 winit.set_fullscreen(fullscreen.clone());
             });
+            when_updated!(maximized, handle, {
+                winit.set_maximized(*maximized);
+            });
+            when_updated!(minimized, handle, {
+                winit.set_minimized(*minimized);
+            });
 
             if redraw {
                 window.set_needs_redraw();
@@ -2177,6 +2745,16 @@ where
             self.inner_size.source(),
             &self.close_requested,
         );
+        if input.state.is_pressed() && self.tree.note_pointer_interaction(false) {
+            window.set_needs_redraw();
+        }
+        if self
+            .accelerators
+            .map(|accelerators| accelerators.input(input.clone()))
+            .is_break()
+        {
+            return HANDLED;
+        }
         let target = self.tree.focused_widget().unwrap_or(self.root.node_id);
         let Some(target) = self.tree.widget_from_node(target) else {
             return IGNORED;
@@ -2370,10 +2948,10 @@ where
                     ),
                     kludgine,
                 );
-                let Some(last_rendered_at) = context.last_layout() else {
+                let Some(relative) = context.location_in_widget(location) else {
                     continue;
                 };
-                context.mouse_drag(location - last_rendered_at.origin, device_id, *button);
+                context.mouse_drag(relative, device_id, *button);
             }
         }
     }
@@ -2384,26 +2962,38 @@ where
     {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        // While a mouse button is held, the widget handling the button keeps
+        // receiving the pointer's events when it is outside of the window. The
+        // cursor is considered to have left once all buttons are released.
+        if !self.mouse_buttons.is_empty() {
+            return;
+        }
+        let mut window = RunningWindow::new(
+            window,
+            kludgine.id(),
+            &self.redraw_status,
+            &self.app,
+            &self.focused,
+            &self.occluded,
+            self.inner_size.source(),
+            &self.close_requested,
+        );
+        self.clear_cursor_location(&mut window, kludgine);
+    }
+
+    fn clear_cursor_location<W>(&mut self, window: &mut RunningWindow<W>, kludgine: &mut Kludgine)
+    where
+        W: PlatformWindowImplementation,
+    {
         self.cursor.location = None;
         self.cursor_position
             .set_and_read(Point::squared(Px::new(-1)));
         if self.cursor.widget.take().is_some() {
-            let mut window = RunningWindow::new(
-                window,
-                kludgine.id(),
-                &self.redraw_status,
-                &self.app,
-                &self.focused,
-                &self.occluded,
-                self.inner_size.source(),
-                &self.close_requested,
-            );
-
             let mut context = EventContext::new(
                 WidgetContext::new(
                     self.root.clone(),
                     &self.current_theme,
-                    &mut window,
+                    window,
                     &mut self.fonts,
                     self.theme_mode.get(),
                     &mut self.cursor,
@@ -2436,6 +3026,9 @@ where
             self.inner_size.source(),
             &self.close_requested,
         );
+        if self.tree.note_pointer_interaction(true) {
+            window.set_needs_redraw();
+        }
         if let (Some(location), Some(hovered)) = (
             self.cursor.location,
             self.cursor
@@ -2443,6 +3036,8 @@ where
                 .as_ref()
                 .and_then(|hover| self.tree.widget(hover.id)),
         ) {
+            self.cursor.click_count =
+                self.count_click(device_id, button, location, kludgine.scale());
             if let Some(handler) = recursively_handle_event(
                 &mut EventContext::new(
                     WidgetContext::new(
@@ -2458,10 +3053,9 @@ where
                     kludgine,
                 ),
                 |context| {
-                    let Some(layout) = context.last_layout() else {
+                    let Some(relative) = context.location_in_widget(location) else {
                         return IGNORED;
                     };
-                    let relative = location - layout.origin;
                     context.mouse_down(relative, device_id, button)
                 },
             ) {
@@ -2490,6 +3084,39 @@ where
         IGNORED
     }
 
+    /// Records a click of `button` at `location`, returning the number of
+    /// consecutive clicks of `button` including this one.
+    fn count_click(
+        &mut self,
+        device_id: DeviceId,
+        button: MouseButton,
+        location: Point<Px>,
+        scale: Fraction,
+    ) -> usize {
+        let now = animation::now();
+        let threshold = self.app.cushy().multi_click_threshold();
+        let max_distance = Lp::points(4).into_px(scale).into_float();
+        let count = match self.last_clicks.get(&(device_id, button)) {
+            Some(last)
+                if now.saturating_duration_since(last.at) < threshold
+                    && (last.location.x - location.x).into_float().abs() <= max_distance
+                    && (last.location.y - location.y).into_float().abs() <= max_distance =>
+            {
+                last.count + 1
+            }
+            _ => 1,
+        };
+        self.last_clicks.insert(
+            (device_id, button),
+            LastClick {
+                at: now,
+                location,
+                count,
+            },
+        );
+        count
+    }
+
     fn mouse_up<W>(
         &mut self,
         window: W,
@@ -2537,15 +3164,20 @@ where
             kludgine,
         );
 
-        let relative = if let (Some(last_rendered), Some(location)) =
-            (context.last_layout(), cursor_location)
-        {
-            Some(location - last_rendered.origin)
-        } else {
-            None
-        };
+        let relative = cursor_location.and_then(|location| context.location_in_widget(location));
 
         context.mouse_up(relative, device_id, button);
+        drop(context);
+
+        // If the drag ended outside of the window, the cursor left the window
+        // while the button was held.
+        if self.mouse_buttons.is_empty()
+            && cursor_location.is_some_and(|location| {
+                !Rect::from(self.inner_size.peek().into_signed()).contains(location)
+            })
+        {
+            self.clear_cursor_location(&mut window, kludgine);
+        }
         HANDLED
     }
 
@@ -2568,20 +3200,143 @@ where
         }
     }
 
-    fn handle_drop(
-        &mut self,
-        drop: DropEvent<PathBuf>,
-        window: &kludgine::app::Window<'_, WindowCommand>,
-    ) {
+    fn touch<W>(&mut self, window: W, kludgine: &mut Kludgine, touch: Touch) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
+        let cushy = self.app.cushy().clone();
+        let _guard = cushy.enter_runtime();
+        let mut window = RunningWindow::new(
+            window,
+            kludgine.id(),
+            &self.redraw_status,
+            &self.app,
+            &self.focused,
+            &self.occluded,
+            self.inner_size.source(),
+            &self.close_requested,
+        );
+        let mut context = EventContext::new(
+            WidgetContext::new(
+                self.root.clone(),
+                &self.current_theme,
+                &mut window,
+                &mut self.fonts,
+                self.theme_mode.get(),
+                &mut self.cursor,
+                #[cfg(feature = "localization")]
+                &self.app.cushy().data.localizations,
+            ),
+            kludgine,
+        );
+
+        let finger = (touch.device_id, touch.finger_id);
+        if touch.phase == TouchPhase::Started {
+            let handler = context
+                .hit_test_widget_at(touch.location)
+                .and_then(|target| {
+                    recursively_handle_event(&mut context.for_other(&target), |context| {
+                        deliver_touch(context, touch)
+                    })
+                });
+            if let Some(handler) = handler {
+                self.touches.insert(finger, handler.id());
+                HANDLED
+            } else {
+                IGNORED
+            }
+        } else {
+            let handler = if matches!(touch.phase, TouchPhase::Ended | TouchPhase::Cancelled) {
+                self.touches.remove(&finger)
+            } else {
+                self.touches.get(&finger).copied()
+            };
+            let Some(handler) = handler.and_then(|handler| self.tree.widget(handler)) else {
+                return IGNORED;
+            };
+            deliver_touch(&mut context.for_other(&handler), touch)
+        }
+    }
+
+    fn handle_drop<W>(&mut self, window: W, kludgine: &mut Kludgine, drop: DropEvent<PathBuf>)
+    where
+        W: PlatformWindowImplementation,
+    {
+        let cushy = self.app.cushy().clone();
+        let _guard = cushy.enter_runtime();
         if let Some(on_file_drop) = &mut self.on_file_drop {
             on_file_drop.notify(FileDrop {
-                window: WindowHandle::new(window.handle(), self.redraw_status.clone()),
-                drop,
+                window: window.handle(self.redraw_status.clone()),
+                drop: drop.clone(),
             });
         }
+
+        let mut window = RunningWindow::new(
+            window,
+            kludgine.id(),
+            &self.redraw_status,
+            &self.app,
+            &self.focused,
+            &self.occluded,
+            self.inner_size.source(),
+            &self.close_requested,
+        );
+        let location = self.cursor.location;
+        let mut context = EventContext::new(
+            WidgetContext::new(
+                self.root.clone(),
+                &self.current_theme,
+                &mut window,
+                &mut self.fonts,
+                self.theme_mode.get(),
+                &mut self.cursor,
+                #[cfg(feature = "localization")]
+                &self.app.cushy().data.localizations,
+            ),
+            kludgine,
+        );
+
+        let previous_target = self.file_hover_target.take();
+        let is_hover = matches!(drop, DropEvent::Hover(_));
+        let handler = match drop {
+            DropEvent::Hover(path) | DropEvent::Dropped(path) => {
+                // Deliver the event to the widget beneath the cursor, falling
+                // back to the root widget.
+                let target = location
+                    .and_then(|location| context.hit_test_widget_at(location))
+                    .unwrap_or_else(|| self.root.clone());
+                recursively_handle_event(&mut context.for_other(&target), |context| {
+                    if is_hover {
+                        context.hovered_file(path.clone())
+                    } else {
+                        context.dropped_file(path.clone())
+                    }
+                })
+                .map(|handler| handler.id())
+            }
+            DropEvent::Cancelled => None,
+        };
+
+        if let Some(previous) = previous_target
+            .filter(|previous| Some(*previous) != handler)
+            .and_then(|previous| self.tree.widget(previous))
+        {
+            context.for_other(&previous).hovered_file_cancelled();
+        }
+        if is_hover {
+            self.file_hover_target = handler;
+        }
     }
 }
 
+fn deliver_touch(context: &mut EventContext<'_>, mut touch: Touch) -> EventHandling {
+    let Some(location) = context.location_in_widget(touch.location) else {
+        return IGNORED;
+    };
+    touch.location = location;
+    context.touch(touch)
+}
+
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 enum RootMode {
     Fit,
@@ -2713,6 +3468,22 @@ where
         attrs.title = settings
             .title
             .map(|title| title.localize_for_cushy(settings.app.cushy()));
+        if let Some(geometry) = settings
+            .persistent_geometry
+            .as_ref()
+            .and_then(PersistentGeometry::load)
+        {
+            let geometry = match settings.app.monitors() {
+                Some(monitors) => geometry.clamped_to(&monitors),
+                None => geometry,
+            };
+            attrs.position = Some(winit::dpi::Position::Physical(
+                PhysicalPosition::<i32>::from(geometry.outer_position),
+            ));
+            if !geometry.inner_size.is_zero() {
+                attrs.inner_size = Some(winit::dpi::Size::Physical(geometry.inner_size.into()));
+            }
+        }
         if attrs.inner_size.is_none() {
             let dynamic_inner = settings.inner_size.get();
             if !dynamic_inner.is_zero() {
@@ -2800,27 +3571,27 @@ where
     fn dropped_file(
         &mut self,
         window: kludgine::app::Window<'_, WindowCommand>,
-        _kludgine: &mut Kludgine,
+        kludgine: &mut Kludgine,
         path: PathBuf,
     ) {
-        self.handle_drop(DropEvent::Dropped(path), &window);
+        self.handle_drop(window, kludgine, DropEvent::Dropped(path));
     }
 
     fn hovered_file(
         &mut self,
         window: kludgine::app::Window<'_, WindowCommand>,
-        _kludgine: &mut Kludgine,
+        kludgine: &mut Kludgine,
         path: PathBuf,
     ) {
-        self.handle_drop(DropEvent::Hover(path), &window);
+        self.handle_drop(window, kludgine, DropEvent::Hover(path));
     }
 
     fn hovered_file_cancelled(
         &mut self,
         window: kludgine::app::Window<'_, WindowCommand>,
-        _kludgine: &mut Kludgine,
+        kludgine: &mut Kludgine,
     ) {
-        self.handle_drop(DropEvent::Cancelled, &window);
+        self.handle_drop(window, kludgine, DropEvent::Cancelled);
     }
 
     // fn received_character(&mut self, window: kludgine::app::Window<'_, ()>, char: char) {}
@@ -2895,6 +3666,15 @@ where
         self.mouse_input(window, kludgine, device_id.into(), state, button);
     }
 
+    fn touch(
+        &mut self,
+        window: kludgine::app::Window<'_, WindowCommand>,
+        kludgine: &mut Kludgine,
+        touch: winit::event::Touch,
+    ) {
+        self.touch(window, kludgine, touch.into());
+    }
+
     fn theme_changed(
         &mut self,
         window: kludgine::app::Window<'_, WindowCommand>,
@@ -2929,10 +3709,13 @@ where
                     self.inner_size.source(),
                     &self.close_requested,
                 );
-                if self.behavior.close_requested(&mut window) {
+                if Self::request_close(&mut self.behavior, &mut window) {
                     window.close();
                 }
             }
+            WindowCommand::Close => {
+                window.close();
+            }
             WindowCommand::SetTitle(new_title) => {
                 let new_title = new_title.localize_for_cushy(self.app.cushy());
                 window.set_title(&new_title);
@@ -2992,29 +3775,6 @@ where
         }
     }
 
-    // fn dropped_file(
-    //     &mut self,
-    //     window: kludgine::app::Window<'_, WindowCommand>,
-    //     kludgine: &mut Kludgine,
-    //     path: std::path::PathBuf,
-    // ) {
-    // }
-
-    // fn hovered_file(
-    //     &mut self,
-    //     window: kludgine::app::Window<'_, WindowCommand>,
-    //     kludgine: &mut Kludgine,
-    //     path: std::path::PathBuf,
-    // ) {
-    // }
-
-    // fn hovered_file_cancelled(
-    //     &mut self,
-    //     window: kludgine::app::Window<'_, WindowCommand>,
-    //     kludgine: &mut Kludgine,
-    // ) {
-    // }
-
     // fn received_character(
     //     &mut self,
     //     window: kludgine::app::Window<'_, WindowCommand>,
@@ -3033,6 +3793,16 @@ where
 
 impl<Behavior> Drop for OpenWindow<Behavior> {
     fn drop(&mut self) {
+        // The geometry of a minimized, maximized, or fullscreen window isn't
+        // the geometry the window should be restored to.
+        if let Some(persistent_geometry) = self.persistent_geometry.as_ref().filter(|_| {
+            !*self.minimized.peek() && !*self.maximized.peek() && self.fullscreen.peek().is_none()
+        }) {
+            persistent_geometry.save(WindowGeometry {
+                outer_position: *self.outer_position.peek(),
+                inner_size: *self.inner_size.peek(),
+            });
+        }
         if let Some(on_closed) = self.on_closed.take() {
             on_closed.invoke(());
         }
@@ -3055,6 +3825,13 @@ fn recursively_handle_event(
 pub(crate) struct CursorState {
     pub(crate) location: Option<Point<Px>>,
     pub(crate) widget: Option<WidgetCursorState>,
+    pub(crate) click_count: usize,
+}
+
+struct LastClick {
+    at: Instant,
+    location: Point<Px>,
+    count: usize,
 }
 
 #[derive(Eq, PartialEq)]
@@ -3083,7 +3860,9 @@ pub(crate) mod sealed {
     use crate::styles::{FontFamilyList, ThemePair};
     use crate::widget::{Notify, OnceCallback, SharedCallback};
     use crate::widgets::shortcuts::ShortcutMap;
-    use crate::window::{FileDrop, PendingWindow, ThemeMode, WindowAttributes, WindowHandle};
+    use crate::window::{
+        FileDrop, PendingWindow, PersistentGeometry, ThemeMode, WindowAttributes, WindowHandle,
+    };
     use crate::{App, MaybeLocalized};
 
     pub struct Context<C> {
@@ -3103,6 +3882,12 @@ pub(crate) mod sealed {
         pub zoom: Dynamic<Fraction>,
         pub theme: Option<Value<ThemePair>>,
         pub theme_mode: Option<Value<ThemeMode>>,
+        pub theme_transition: Duration,
+        pub debug_overlay: Value<bool>,
+        pub content_minimum_size: bool,
+        /// When the window should be sized to its contents, whether it should
+        /// be shown afterwards.
+        pub sized_to_content: Option<bool>,
         pub transparent: bool,
         pub serif_font_family: FontFamilyList,
         pub sans_serif_font_family: FontFamilyList,
@@ -3136,7 +3921,9 @@ pub(crate) mod sealed {
         pub enabled_buttons: Value<WindowButtons>,
         pub fullscreen: Value<Option<Fullscreen>>,
         pub shortcuts: Value<ShortcutMap>,
+        pub accelerators: Value<ShortcutMap>,
         pub on_file_drop: Option<Notify<FileDrop>>,
+        pub persistent_geometry: Option<PersistentGeometry>,
     }
 
     pub struct WindowExecute(Box<dyn ExecuteFunc>);
@@ -3179,6 +3966,7 @@ pub(crate) mod sealed {
         Redraw,
         Sync,
         RequestClose,
+        Close,
         ResetDeadKeys,
         RequestUserAttention(Option<UserAttentionType>),
         Focus,
@@ -3218,6 +4006,28 @@ pub(crate) mod sealed {
     }
 }
 
+struct ThemeTransition {
+    from: ThemePair,
+    resolve_mode: bool,
+    started: Instant,
+}
+
+impl ThemeTransition {
+    /// Returns a copy of `theme` whose light and dark variants are both the
+    /// variant for `mode`.
+    fn resolved(theme: &ThemePair, mode: ThemeMode) -> ThemePair {
+        let variant = match mode {
+            ThemeMode::Light => theme.light,
+            ThemeMode::Dark => theme.dark,
+        };
+        ThemePair {
+            light: variant,
+            dark: variant,
+            ..theme.clone()
+        }
+    }
+}
+
 /// Controls whether the light or dark theme is applied.
 #[derive(Default, Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, LinearInterpolate)]
 pub enum ThemeMode {
@@ -3344,11 +4154,27 @@ impl WindowHandle {
     /// Request that the window closes.
     ///
     /// A window may disallow itself from being closed by customizing
-    /// [`WindowBehavior::close_requested`].
+    /// [`WindowBehavior::close_requested`] or by using
+    /// [`Window::on_close_requested`].
     pub fn request_close(&self) {
         self.inner.send(sealed::WindowCommand::RequestClose);
     }
 
+    /// Closes the window without asking whether it should close.
+    ///
+    /// Unlike [`request_close()`](Self::request_close), neither
+    /// [`WindowBehavior::close_requested`] nor the
+    /// [`Window::on_close_requested`] callback are invoked. This enables
+    /// deferring the decision to close a window: the close request can be
+    /// rejected while the user is asked to confirm, and once confirmed, this
+    /// function can be called to close the window.
+    ///
+    /// On virtual windows, this sets [`WindowDynamicState::closed`], which is
+    /// reflected by [`VirtualWindow::closed()`].
+    pub fn close(&self) {
+        self.inner.send(sealed::WindowCommand::Close);
+    }
+
     /// Requests that the window redraws.
     pub fn redraw(&self) {
         if self.redraw_status.should_send_refresh() {
@@ -3377,6 +4203,51 @@ impl WindowHandle {
         self.inner
             .send(WindowCommand::Execute(WindowExecute::new(func)));
     }
+
+    /// Advances the focus from the currently focused widget to the next widget
+    /// in the window's focus order. If no widget is focused, the first
+    /// focusable widget will be focused.
+    ///
+    /// This is the same behavior as pressing the Tab key. The focus change is
+    /// performed on the window thread.
+    pub fn advance_focus(&self) {
+        self.execute(|context| {
+            let focused = focused_or_root(context);
+            context.for_other(&focused).advance_focus();
+        });
+    }
+
+    /// Returns the focus from the currently focused widget to the previous
+    /// widget in the window's focus order. If no widget is focused, the last
+    /// focusable widget will be focused.
+    ///
+    /// This is the same behavior as pressing Shift+Tab. The focus change is
+    /// performed on the window thread.
+    pub fn return_focus(&self) {
+        self.execute(|context| {
+            let focused = focused_or_root(context);
+            context.for_other(&focused).return_focus();
+        });
+    }
+
+    /// Focuses the widget with `id`, if it is mounted in this window.
+    ///
+    /// The focus change is performed on the window thread.
+    pub fn focus_widget(&self, id: WidgetId) {
+        self.execute(move |context| {
+            if let Some(widget) = context.tree.widget(id) {
+                context.for_other(&widget).focus();
+            }
+        });
+    }
+}
+
+fn focused_or_root(context: &EventContext<'_>) -> MountedWidget {
+    context
+        .tree
+        .focused_widget()
+        .and_then(|focused| context.tree.widget_from_node(focused))
+        .unwrap_or_else(|| context.widget().clone())
 }
 
 impl Eq for WindowHandle {}
@@ -3416,6 +4287,7 @@ impl InnerWindowHandle {
             InnerWindowHandle::Virtual(state) => match message {
                 WindowCommand::Redraw => state.redraw_target.set(RedrawTarget::Now),
                 WindowCommand::RequestClose => state.close_requested.set(true),
+                WindowCommand::Close => state.closed.set(true),
                 WindowCommand::SetTitle(title) => *state.title.lock() = title,
                 WindowCommand::Execute(_func) => {
                     tracing::error!("ignoring execution of window function on virtual window");
@@ -3612,6 +4484,8 @@ pub struct WindowDynamicState {
     /// functionality, upon detecting this, [`VirtualWindow::request_close`]
     /// should be invoked.
     pub close_requested: Dynamic<bool>,
+    /// When true, the window has been closed using [`WindowHandle::close`].
+    pub closed: Dynamic<bool>,
     /// The current title of the window.
     pub title: Dynamic<MaybeLocalized>,
 }
@@ -3696,6 +4570,7 @@ pub struct StandaloneWindowBuilder {
     transparent: bool,
     zoom: Dynamic<Fraction>,
     resize_to_fit: Value<bool>,
+    close_requested: Option<SharedCallback<(), bool>>,
 }
 
 impl StandaloneWindowBuilder {
@@ -3710,6 +4585,7 @@ impl StandaloneWindowBuilder {
             zoom: Dynamic::new(Fraction::ONE),
             transparent: false,
             resize_to_fit: Value::Constant(false),
+            close_requested: None,
         }
     }
 
@@ -3754,6 +4630,19 @@ impl StandaloneWindowBuilder {
         self
     }
 
+    /// Invokes `on_close_requested` when the window is requested to be closed.
+    ///
+    /// If the function returns true, the window is allowed to be closed,
+    /// otherwise the window remains open.
+    #[must_use]
+    pub fn on_close_requested<Function>(mut self, on_close_requested: Function) -> Self
+    where
+        Function: FnMut(()) -> bool + Send + 'static,
+    {
+        self.close_requested = Some(SharedCallback::new(on_close_requested));
+        self
+    }
+
     /// Returns the initialized window.
     #[must_use]
     pub fn finish<W>(self, window: W, device: &wgpu::Device, queue: &wgpu::Queue) -> CushyWindow
@@ -3785,6 +4674,10 @@ impl StandaloneWindowBuilder {
                 inner_size: Dynamic::default(),
                 theme: None,
                 theme_mode: None,
+                theme_transition: Duration::ZERO,
+                debug_overlay: Value::Constant(false),
+                content_minimum_size: false,
+                sized_to_content: None,
                 transparent: self.transparent,
                 serif_font_family: FontFamilyList::default(),
                 sans_serif_font_family: FontFamilyList::default(),
@@ -3796,7 +4689,7 @@ impl StandaloneWindowBuilder {
                 on_closed: None,
                 vsync: false,
                 multisample_count: self.multisample_count,
-                close_requested: None,
+                close_requested: self.close_requested,
                 zoom: self.zoom,
                 resize_to_fit: self.resize_to_fit,
                 content_protected: Value::Constant(false),
@@ -3818,8 +4711,10 @@ impl StandaloneWindowBuilder {
                 enabled_buttons: Value::dynamic(WindowButtons::all()),
                 fullscreen: Value::default(),
                 shortcuts: Value::default(),
+                accelerators: Value::default(),
                 on_init: None,
                 on_file_drop: None,
+                persistent_geometry: None,
             },
         );
 
@@ -3842,6 +4737,35 @@ impl StandaloneWindowBuilder {
     }
 }
 
+/// The layout of a widget and its children from the most recent frame.
+///
+/// The [`Debug`] implementation of this type can be used to inspect the
+/// layout of an entire widget hierarchy, especially when using the alternate
+/// (`{:#?}`) format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WidgetLayout {
+    /// The id of the widget.
+    pub id: WidgetId,
+    /// The window-relative region the widget was laid out in, or `None` if
+    /// the widget has not been laid out.
+    pub layout: Option<Rect<Px>>,
+    /// The layouts of the widget's children.
+    pub children: Vec<WidgetLayout>,
+}
+
+impl WidgetLayout {
+    /// Returns the layout of the widget with `id` if it is this widget or one
+    /// of its descendants.
+    #[must_use]
+    pub fn find(&self, id: WidgetId) -> Option<&WidgetLayout> {
+        if self.id == id {
+            Some(self)
+        } else {
+            self.children.iter().find_map(|child| child.find(id))
+        }
+    }
+}
+
 /// A standalone Cushy window.
 ///
 /// This type allows rendering Cushy applications directly into any wgpu
@@ -3931,6 +4855,33 @@ impl CushyWindow {
         self.window.set_focused(focused);
     }
 
+    /// Returns the id of the widget that currently has keyboard focus, if any.
+    #[must_use]
+    pub fn focused_widget(&self) -> Option<WidgetId> {
+        let tree = &self.window.tree;
+        tree.focused_widget()
+            .and_then(|node| tree.widget_from_node(node))
+            .map(|widget| widget.id())
+    }
+
+    /// Returns the window-relative layout of the widget with `id` from the
+    /// most recent frame, if the widget is mounted in this window and has been
+    /// laid out.
+    #[must_use]
+    pub fn widget_layout(&self, id: WidgetId) -> Option<Rect<Px>> {
+        self.window.tree.widget(id)?.last_layout()
+    }
+
+    /// Returns the layouts of every widget in this window from the most recent
+    /// frame.
+    #[must_use]
+    pub fn layout_tree(&self) -> WidgetLayout {
+        self.window
+            .tree
+            .layout_tree(self.window.root.node_id)
+            .assert("root is always mounted")
+    }
+
     /// Sets the window's occlusion status.
     ///
     /// This should only be set to true if the window is not visible at all to
@@ -4069,6 +5020,16 @@ impl CushyWindow {
         self.window
             .mouse_input(window, &mut self.kludgine, device_id, state, button)
     }
+
+    /// Provides touch events to this window.
+    ///
+    /// Returns whether the event was [`HANDLED`] or [`IGNORED`].
+    pub fn touch<W>(&mut self, window: W, touch: Touch) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
+        self.window.touch(window, &mut self.kludgine, touch)
+    }
 }
 
 /// A virtual Cushy window.
@@ -4171,6 +5132,37 @@ impl VirtualWindow {
         self.cushy.set_focused(focused);
     }
 
+    /// Sets the keyboard modifiers that are currently pressed.
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) {
+        self.state.modifiers = modifiers;
+    }
+
+    /// Returns the id of the widget that currently has keyboard focus, if any.
+    ///
+    /// Combined with [`cursor_moved()`](Self::cursor_moved),
+    /// [`mouse_input()`](Self::mouse_input), and
+    /// [`keyboard_input()`](Self::keyboard_input), this allows tests to verify
+    /// how widgets respond to synthesized input.
+    #[must_use]
+    pub fn focused_widget(&self) -> Option<WidgetId> {
+        self.cushy.focused_widget()
+    }
+
+    /// Returns the window-relative layout of the widget with `id` from the
+    /// most recent frame, if the widget is mounted in this window and has been
+    /// laid out.
+    #[must_use]
+    pub fn widget_layout(&self, id: WidgetId) -> Option<Rect<Px>> {
+        self.cushy.widget_layout(id)
+    }
+
+    /// Returns the layouts of every widget in this window from the most recent
+    /// frame.
+    #[must_use]
+    pub fn layout_tree(&self) -> WidgetLayout {
+        self.cushy.layout_tree()
+    }
+
     /// Sets the window's occlusion status.
     ///
     /// This should only be set to true if the window is not visible at all to
@@ -4183,7 +5175,16 @@ impl VirtualWindow {
     /// Returns true if this window should no longer be open.
     #[must_use]
     pub fn closed(&self) -> bool {
-        self.state.closed
+        self.state.closed || self.state.dynamic.closed.get()
+    }
+
+    /// Returns a handle to this window.
+    #[must_use]
+    pub fn handle(&self) -> WindowHandle {
+        WindowHandle {
+            inner: InnerWindowHandle::Virtual(self.state.dynamic.clone()),
+            redraw_status: self.cushy.window.redraw_status.clone(),
+        }
     }
 
     /// Returns a reference to the window's state.
@@ -4279,6 +5280,13 @@ impl VirtualWindow {
         self.cushy
             .mouse_input(&mut self.state, device_id, state, button)
     }
+
+    /// Provides touch events to this window.
+    ///
+    /// Returns whether the event was [`HANDLED`] or [`IGNORED`].
+    pub fn touch(&mut self, touch: Touch) -> EventHandling {
+        self.cushy.touch(&mut self.state, touch)
+    }
 }
 
 /// A color format containing 8-bit red, green, and blue channels.
@@ -4375,6 +5383,7 @@ pub struct VirtualRecorderBuilder<Format> {
     scale: f32,
     format: PhantomData<Format>,
     resize_to_fit: bool,
+    close_requested: Option<SharedCallback<(), bool>>,
 }
 
 impl VirtualRecorderBuilder<Rgb8> {
@@ -4386,6 +5395,7 @@ impl VirtualRecorderBuilder<Rgb8> {
             scale: 1.0,
             format: PhantomData,
             resize_to_fit: false,
+            close_requested: None,
         }
     }
 
@@ -4398,6 +5408,7 @@ impl VirtualRecorderBuilder<Rgb8> {
             size: self.size,
             scale: self.scale,
             resize_to_fit: self.resize_to_fit,
+            close_requested: self.close_requested,
             format: PhantomData,
         }
     }
@@ -4437,9 +5448,31 @@ where
         self
     }
 
+    /// Invokes `on_close_requested` when the virtual window is requested to be
+    /// closed.
+    ///
+    /// If the function returns true, the window is allowed to be closed,
+    /// otherwise the window remains open.
+    #[must_use]
+    pub fn on_close_requested<Function>(mut self, on_close_requested: Function) -> Self
+    where
+        Function: FnMut(()) -> bool + Send + 'static,
+    {
+        self.close_requested = Some(SharedCallback::new(on_close_requested));
+        self
+    }
+
     /// Returns an initialized [`VirtualRecorder`].
     pub fn finish(self) -> Result<VirtualRecorder<Format>, VirtualRecorderError> {
-        VirtualRecorder::new(self.size, self.scale, self.resize_to_fit, self.contents)
+        let mut window = self
+            .contents
+            .build_standalone_window()
+            .size(self.size)
+            .scale(self.scale)
+            .transparent()
+            .resize_to_fit(self.resize_to_fit);
+        window.close_requested = self.close_requested;
+        VirtualRecorder::from_standalone(window)
     }
 }
 
@@ -4536,6 +5569,17 @@ where
         resize_to_fit: bool,
         contents: impl MakeWidget,
     ) -> Result<Self, VirtualRecorderError> {
+        Self::from_standalone(
+            contents
+                .build_standalone_window()
+                .size(size)
+                .scale(scale)
+                .transparent()
+                .resize_to_fit(resize_to_fit),
+        )
+    }
+
+    fn from_standalone(window: StandaloneWindowBuilder) -> Result<Self, VirtualRecorderError> {
         let wgpu = wgpu::Instance::default();
         let adapter =
             pollster::block_on(wgpu.request_adapter(&wgpu::RequestAdapterOptions::default()))
@@ -4550,13 +5594,7 @@ where
             None,
         ))?;
 
-        let window = contents
-            .build_standalone_window()
-            .size(size)
-            .scale(scale)
-            .transparent()
-            .resize_to_fit(resize_to_fit)
-            .finish_virtual(&device, &queue);
+        let window = window.finish_virtual(&device, &queue);
 
         let mut recorder = Self {
             window,
@@ -4730,6 +5768,17 @@ where
         Ok(())
     }
 
+    /// Advances all running animations by `elapsed` and redraws the contents.
+    ///
+    /// To step through animations deterministically, hold the guard returned
+    /// from [`use_manual_clock()`](crate::animation::use_manual_clock) while
+    /// the animations are running. Otherwise, animations will also advance
+    /// using the system clock.
+    pub fn advance_time(&mut self, elapsed: Duration) -> Result<(), wgpu::BufferAsyncError> {
+        crate::animation::advance_animations(elapsed);
+        self.refresh()
+    }
+
     /// Sets the cursor position immediately.
     pub fn set_cursor_position(&self, position: Point<Px>) {
         self.cursor.set(position);
@@ -5073,6 +6122,36 @@ impl From<winit::event::DeviceId> for DeviceId {
     }
 }
 
+/// A touch input event.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Touch {
+    /// The device that produced this event.
+    pub device_id: DeviceId,
+    /// A unique identifier of the finger for the duration of the touch.
+    pub finger_id: u64,
+    /// The phase of the touch.
+    pub phase: TouchPhase,
+    /// The location of the touch.
+    ///
+    /// When delivered to a widget, this location is relative to the widget's
+    /// origin.
+    pub location: Point<Px>,
+    /// The force of the touch, if the device supports measuring it.
+    pub force: Option<Force>,
+}
+
+impl From<winit::event::Touch> for Touch {
+    fn from(touch: winit::event::Touch) -> Self {
+        Self {
+            device_id: touch.device_id.into(),
+            finger_id: touch.id,
+            phase: touch.phase,
+            location: touch.location.into(),
+            force: touch.force,
+        }
+    }
+}
+
 struct FrameAssembler {
     sender: mpsc::SyncSender<(Box<Capture>, Duration)>,
     result: mpsc::Receiver<Result<Vec<Frame>, VirtualRecorderError>>,
@@ -0,0 +1,283 @@
+use std::ffi::OsStr;
+use std::fmt::Debug;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use figures::units::{Px, UPx};
+use figures::{IntoSigned, IntoUnsigned, Point, Rect, Size};
+use kludgine::app::{Monitor, Monitors};
+
+/// The position and size of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowGeometry {
+    /// The position of the window, including its decorations.
+    pub outer_position: Point<Px>,
+    /// The size of the window's contents, excluding its decorations.
+    pub inner_size: Size<UPx>,
+}
+
+impl WindowGeometry {
+    /// Returns this geometry adjusted to ensure the window is visible on one
+    /// of `monitors`.
+    ///
+    /// If no monitor contains [`outer_position`](Self::outer_position), the
+    /// window is centered on the primary monitor and its size is reduced to
+    /// fit, if needed.
+    #[must_use]
+    pub fn clamped_to(self, monitors: &Monitors) -> Self {
+        self.clamped_to_regions(
+            monitors.available.iter().map(Monitor::region),
+            monitors
+                .primary
+                .as_ref()
+                .or_else(|| monitors.available.first())
+                .map(Monitor::region),
+        )
+    }
+
+    fn clamped_to_regions(
+        mut self,
+        mut available: impl Iterator<Item = Rect<Px>>,
+        primary: Option<Rect<Px>>,
+    ) -> Self {
+        if available.any(|region| region.contains(self.outer_position)) {
+            return self;
+        }
+
+        let Some(region) = primary else {
+            return self;
+        };
+
+        let size = self.inner_size.into_signed().min(region.size);
+        self.inner_size = size.into_unsigned();
+        self.outer_position = region.origin + (region.size - size) / 2;
+        self
+    }
+
+    fn parse(contents: &str) -> Option<Self> {
+        let mut parts = contents.split_whitespace();
+        let x = parts.next()?.parse().ok()?;
+        let y = parts.next()?.parse().ok()?;
+        let width = parts.next()?.parse().ok()?;
+        let height = parts.next()?.parse().ok()?;
+        Some(Self {
+            outer_position: Point::new(Px::new(x), Px::new(y)),
+            inner_size: Size::new(UPx::new(width), UPx::new(height)),
+        })
+    }
+
+    fn serialize(&self) -> String {
+        format!(
+            "{} {} {} {}\n",
+            self.outer_position.x.get(),
+            self.outer_position.y.get(),
+            self.inner_size.width.get(),
+            self.inner_size.height.get()
+        )
+    }
+}
+
+/// A storage location for persisting [`WindowGeometry`] between runs of an
+/// application.
+///
+/// See [`Window::persistent_geometry`](crate::window::Window::persistent_geometry).
+pub trait GeometryStore: Debug + Send + Sync + 'static {
+    /// Returns the geometry previously saved for `key`, if present.
+    fn load(&self, key: &str) -> Option<WindowGeometry>;
+
+    /// Saves `geometry` for `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the geometry could not be saved.
+    fn save(&self, key: &str, geometry: WindowGeometry) -> io::Result<()>;
+}
+
+/// A [`GeometryStore`] that saves each window's geometry to a file in a
+/// directory.
+#[derive(Debug, Clone)]
+pub struct FileGeometryStore {
+    directory: PathBuf,
+}
+
+impl FileGeometryStore {
+    /// Returns a store that saves files within `directory`.
+    ///
+    /// `directory` will be created when geometry is first saved.
+    #[must_use]
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Returns the directory this store saves files within.
+    #[must_use]
+    pub fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.directory
+            .join(format!("{}.geometry", encode_file_name(key)))
+    }
+}
+
+impl Default for FileGeometryStore {
+    /// Returns a store that saves files within a directory named after the
+    /// current executable inside of the platform's configuration directory.
+    fn default() -> Self {
+        let config_dir = if cfg!(windows) {
+            std::env::var_os("APPDATA").map(PathBuf::from)
+        } else if cfg!(target_os = "macos") {
+            std::env::var_os("HOME").map(|home| {
+                PathBuf::from(home)
+                    .join("Library")
+                    .join("Application Support")
+            })
+        } else {
+            std::env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| {
+                    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config"))
+                })
+        };
+        let app_name = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.file_stem().map(OsStr::to_os_string))
+            .unwrap_or_else(|| "cushy".into());
+
+        Self::new(
+            config_dir
+                .unwrap_or_else(std::env::temp_dir)
+                .join(app_name)
+                .join("window-geometry"),
+        )
+    }
+}
+
+impl GeometryStore for FileGeometryStore {
+    fn load(&self, key: &str) -> Option<WindowGeometry> {
+        let contents = std::fs::read_to_string(self.path_for(key)).ok()?;
+        WindowGeometry::parse(&contents)
+    }
+
+    fn save(&self, key: &str, geometry: WindowGeometry) -> io::Result<()> {
+        std::fs::create_dir_all(&self.directory)?;
+        std::fs::write(self.path_for(key), geometry.serialize())
+    }
+}
+
+/// Encodes `key` as a file name that is unique even on case-insensitive file
+/// systems.
+///
+/// Lowercase ASCII letters, digits, and `-` are kept as-is. Every other byte is
+/// written as `_` followed by two lowercase hex digits.
+fn encode_file_name(key: &str) -> String {
+    let mut file_name = String::with_capacity(key.len());
+    for byte in key.bytes() {
+        if byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-' {
+            file_name.push(char::from(byte));
+        } else {
+            file_name.push_str(&format!("_{byte:02x}"));
+        }
+    }
+    file_name
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct PersistentGeometry {
+    pub key: String,
+    pub store: Arc<dyn GeometryStore>,
+}
+
+impl PersistentGeometry {
+    pub fn load(&self) -> Option<WindowGeometry> {
+        self.store.load(&self.key)
+    }
+
+    pub fn save(&self, geometry: WindowGeometry) {
+        if let Err(err) = self.store.save(&self.key, geometry) {
+            tracing::warn!("error saving window geometry for {}: {err}", self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Px, UPx};
+    use figures::{Point, Rect, Size};
+
+    use super::{encode_file_name, WindowGeometry};
+
+    fn geometry(x: i32, y: i32, width: u32, height: u32) -> WindowGeometry {
+        WindowGeometry {
+            outer_position: Point::new(Px::new(x), Px::new(y)),
+            inner_size: Size::new(UPx::new(width), UPx::new(height)),
+        }
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let original = geometry(-1920, 40, 800, 600);
+        assert_eq!(original.serialize(), "-1920 40 800 600\n");
+        assert_eq!(WindowGeometry::parse(&original.serialize()), Some(original));
+    }
+
+    #[test]
+    fn parse_rejects_invalid() {
+        assert_eq!(WindowGeometry::parse(""), None);
+        assert_eq!(WindowGeometry::parse("1 2 3"), None);
+        assert_eq!(WindowGeometry::parse("1 2 -3 4"), None);
+        assert_eq!(WindowGeometry::parse("a 2 3 4"), None);
+    }
+
+    #[test]
+    fn clamped_to_regions() {
+        let primary = Rect::new(
+            Point::new(Px::new(0), Px::new(0)),
+            Size::new(Px::new(1920), Px::new(1080)),
+        );
+        let secondary = Rect::new(
+            Point::new(Px::new(1920), Px::new(0)),
+            Size::new(Px::new(1280), Px::new(1024)),
+        );
+        let regions = [primary, secondary];
+
+        // Positions on any monitor are kept.
+        let on_secondary = geometry(2000, 100, 800, 600);
+        assert_eq!(
+            on_secondary.clamped_to_regions(regions.into_iter(), Some(primary)),
+            on_secondary
+        );
+
+        // Positions off every monitor are centered on the primary monitor.
+        assert_eq!(
+            geometry(5000, 100, 800, 600).clamped_to_regions(regions.into_iter(), Some(primary)),
+            geometry(560, 240, 800, 600)
+        );
+
+        // Windows larger than the primary monitor are shrunk to fit.
+        assert_eq!(
+            geometry(-5000, 0, 4000, 600).clamped_to_regions(regions.into_iter(), Some(primary)),
+            geometry(0, 240, 1920, 600)
+        );
+
+        // Without any monitors, the geometry is unchanged.
+        let off_screen = geometry(5000, 100, 800, 600);
+        assert_eq!(
+            off_screen.clamped_to_regions(std::iter::empty(), None),
+            off_screen
+        );
+    }
+
+    #[test]
+    fn file_names_are_unique() {
+        assert_eq!(encode_file_name("main-window2"), "main-window2");
+        assert_eq!(encode_file_name("Main"), "_4dain");
+        assert_ne!(encode_file_name("a b"), encode_file_name("a_b"));
+        assert_ne!(encode_file_name("a/b"), encode_file_name("a_2fb"));
+        assert_ne!(encode_file_name("A"), encode_file_name("a"));
+    }
+}